@@ -1,7 +1,10 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::{
+    Amount, Memo, PrefixedCoin, PrefixedDenom, TransferFlow, DEFAULT_MAX_MEMO_LENGTH,
+    DEFAULT_MAX_TRACE_HOPS,
+};
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
@@ -59,11 +62,77 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
+    /// Checks that sending `coin` over the given channel stays within the
+    /// outbound rate limit set by the host, if any.
+    ///
+    /// Called before escrowing or burning the tokens of a transfer. Defaults
+    /// to no limit.
+    fn check_send_rate_limit(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Returns the amount of `denom` held in escrow for the given channel, if
+    /// the host keeps track of it.
+    ///
+    /// Unescrowing more than this amount fails with
+    /// [`TokenTransferError::InsufficientEscrow`]. Defaults to `None`, which
+    /// skips the check.
+    fn escrowed_amount(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns whether `denom` may be sent from, or received on, the host
+    /// chain, e.g. to block a compromised asset.
+    ///
+    /// On receive, `denom` is the denomination as traced on the host chain.
+    /// Blocked sends fail with [`TokenTransferError::DenomNotAllowed`], and
+    /// blocked receives are answered with an error acknowledgement. Defaults
+    /// to allowing every denom.
+    fn is_denom_allowed(&self, _denom: &PrefixedDenom, _flow: TransferFlow) -> bool {
+        true
+    }
+
     /// Returns a hash of the prefixed denom.
     /// Implement only if the host chain supports hashed denominations.
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the human-readable part (bech32 prefix) of the host chain's
+    /// account addresses, e.g. `cosmos`.
+    ///
+    /// Used when deriving escrow addresses and validating receivers.
+    fn address_hrp(&self) -> String {
+        "cosmos".to_string()
+    }
+
+    /// Returns the maximum number of hops allowed in the trace path of a
+    /// received token, once prefixed with the receiving channel.
+    ///
+    /// Packets carrying tokens over this limit are rejected with an error
+    /// acknowledgement.
+    fn max_trace_hops(&self) -> u64 {
+        DEFAULT_MAX_TRACE_HOPS
+    }
+
+    /// Returns the maximum length, in bytes, of the memo of an outgoing
+    /// transfer.
+    ///
+    /// Transfers with a longer memo fail validation before any tokens are
+    /// escrowed or burned.
+    fn max_memo_length(&self) -> usize {
+        DEFAULT_MAX_MEMO_LENGTH
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
@@ -3,30 +3,37 @@ mod on_recv_packet;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
 use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::{classify_transfer, PrefixedCoin, TransferDirection};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Refunds the tokens of a packet to its sender, by either unescrowing or
+/// minting them back.
+///
+/// The bank operations of the refund are all validated before any of them is
+/// applied, so that a refund either fully succeeds or leaves the state
+/// untouched, failing with [`TokenTransferError::RefundInconsistent`].
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
     data: &PacketData,
 ) -> Result<(), TokenTransferError> {
+    refund_packet_token_validate(ctx_a, packet, data)
+        .map_err(|e| TokenTransferError::RefundInconsistent(Box::new(e)))?;
+
     let sender = data
         .sender
         .clone()
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
+    if classify_transfer(packet, &data.token.denom) == TransferDirection::FromSource {
         ctx_a.unescrow_coins_execute(
             &sender,
             &packet.port_id_on_a,
@@ -51,11 +58,13 @@ pub fn refund_packet_token_validate(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
+    if classify_transfer(packet, &data.token.denom) == TransferDirection::FromSource {
+        verify_escrow_balance(
+            ctx_a,
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &data.token,
+        )?;
         ctx_a.unescrow_coins_validate(
             &sender,
             &packet.port_id_on_a,
@@ -66,3 +75,24 @@ pub fn refund_packet_token_validate(
         ctx_a.mint_coins_validate(&sender, &data.token)
     }
 }
+
+/// Checks that the escrow of the given channel holds enough tokens to
+/// unescrow `coin`, for hosts that track escrowed amounts.
+pub(crate) fn verify_escrow_balance(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    if let Some(available) = ctx.escrowed_amount(port_id, channel_id, &coin.denom)? {
+        if coin.amount > available {
+            return Err(TokenTransferError::InsufficientEscrow {
+                denom: coin.denom.clone(),
+                requested: coin.amount,
+                available,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
 use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::{
+    classify_transfer, PrefixedDenom, TracePrefix, TransferDirection, TransferFlow,
+};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::verify_escrow_balance;
 use crate::context::TokenTransferExecutionContext;
 
 /// This function handles the transfer receiving logic.
@@ -30,11 +33,7 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
+    let extras = if classify_transfer(packet, &data.token.denom) == TransferDirection::ToSource {
         // sender chain is not the source, unescrow tokens
         let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
         let coin = {
@@ -43,6 +42,8 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
             c
         };
 
+        verify_denom_allowed(ctx_b, &coin.denom).map_err(|err| (ModuleExtras::empty(), err))?;
+
         // Note: it is correct to do the validation here because `recv_packet()`
         // works slightly differently. We do not have a
         // `on_recv_packet_validate()` callback because regardless of whether or
@@ -53,6 +54,8 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         // a `TokenTransferAcknowledgement::Error` acknowledgement, which
         // gets relayed back to the sender so that the escrowed tokens
         // can be refunded.
+        verify_escrow_balance(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b, &coin)
+            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
         ctx_b
             .unescrow_coins_validate(
                 &receiver_account,
@@ -80,6 +83,20 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
             c
         };
 
+        verify_denom_allowed(ctx_b, &coin.denom).map_err(|err| (ModuleExtras::empty(), err))?;
+
+        let hops = coin.denom.trace_path.len() as u64;
+        let max_hops = ctx_b.max_trace_hops();
+        if hops > max_hops {
+            return Err((
+                ModuleExtras::empty(),
+                TokenTransferError::TraceHopsExceeded {
+                    hops,
+                    max: max_hops,
+                },
+            ));
+        }
+
         let extras = {
             let denom_trace_event = DenomTraceEvent {
                 trace_hash: ctx_b.denom_hash_string(&coin.denom),
@@ -114,3 +131,19 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
 
     Ok(extras)
 }
+
+/// Checks that the host chain allows receiving `denom`, as traced on the host
+/// chain.
+fn verify_denom_allowed<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &Ctx,
+    denom: &PrefixedDenom,
+) -> Result<(), TokenTransferError> {
+    if !ctx_b.is_denom_allowed(denom, TransferFlow::Receive) {
+        return Err(TokenTransferError::DenomNotAllowed {
+            denom: denom.clone(),
+            flow: TransferFlow::Receive,
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
 use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
-use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
+use ibc_app_transfer_types::{is_sender_chain_source, TransferFlow, MODULE_ID_STR};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
 use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
 use ibc_core::channel::types::packet::Packet;
@@ -38,6 +38,10 @@ where
 {
     token_ctx_a.can_send_coins()?;
 
+    msg.packet_data
+        .memo
+        .verify_length(token_ctx_a.max_memo_length())?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -56,6 +60,13 @@ where
 
     let token = &msg.packet_data.token;
 
+    if !token_ctx_a.is_denom_allowed(&token.denom, TransferFlow::Send) {
+        return Err(TokenTransferError::DenomNotAllowed {
+            denom: token.denom.clone(),
+            flow: TransferFlow::Send,
+        });
+    }
+
     let sender: TokenCtx::AccountId = msg
         .packet_data
         .sender
@@ -63,6 +74,8 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
+    token_ctx_a.check_send_rate_limit(&msg.port_id_on_a, &msg.chan_id_on_a, token)?;
+
     if is_sender_chain_source(
         msg.port_id_on_a.clone(),
         msg.chan_id_on_a.clone(),
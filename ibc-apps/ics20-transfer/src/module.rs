@@ -171,14 +171,13 @@ pub fn on_recv_packet_execute(
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
     let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
+        let ack = TokenTransferError::PacketDataDeserialization.ack_status();
         return (ModuleExtras::empty(), ack.into());
     };
 
     let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
         Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
-        Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
+        Err((extras, error)) => (extras, error.ack_status()),
     };
 
     let recv_event = RecvEvent {
@@ -298,6 +297,7 @@ pub fn on_timeout_packet_execute(
         refund_denom: data.token.denom,
         refund_amount: data.token.amount,
         memo: data.memo,
+        packet_sequence: packet.seq_on_a,
     };
 
     let extras = ModuleExtras {
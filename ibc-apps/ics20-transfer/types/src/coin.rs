@@ -192,6 +192,45 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::native("uatom", "1000", "uatom")]
+    #[case::voucher(
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        "42",
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+    )]
+    #[case::prefixed("transfer/channel-0/uatom", "7", "uatom")]
+    fn test_proto_coin_round_trip(
+        #[case] denom: &str,
+        #[case] amount: &str,
+        #[case] base_denom: &str,
+    ) -> Result<(), TokenTransferError> {
+        let proto = ProtoCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        };
+
+        let coin = PrefixedCoin::try_from(proto.clone())?;
+
+        assert_eq!(coin.denom.base_denom.as_str(), base_denom);
+        assert_eq!(coin.amount, amount.parse::<Amount>()?);
+        assert_eq!(ProtoCoin::from(coin), proto);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty_denom("", "1")]
+    #[case::invalid_amount("uatom", "-1")]
+    fn test_failed_proto_coin_conversion(#[case] denom: &str, #[case] amount: &str) {
+        let proto = ProtoCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        };
+
+        assert!(PrefixedCoin::try_from(proto).is_err());
+    }
+
     #[rstest]
     #[case::semicolon_delimiter("123stake;1a1;999den0m")]
     #[case::mixed_delimiter("123stake,1a1;999den0m")]
@@ -3,6 +3,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
 use derive_more::{Display, From};
+use ibc_core::channel::types::packet::Packet;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
@@ -163,6 +164,11 @@ impl TracePath {
         self.0.is_empty()
     }
 
+    /// Returns the number of hops, i.e. of [`TracePrefix`]es, in the path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     /// Return empty trace path
     pub fn empty() -> Self {
         Self(vec![])
@@ -210,16 +216,50 @@ impl TracePath {
 impl FromStr for TracePath {
     type Err = TokenTransferError;
 
+    /// Strictly parses a [`TracePath`] of the form
+    /// `{nth-port-id/channel-<index>}/.../{1st-port-id/channel-<index>}`.
+    ///
+    /// Unlike [`TracePath::trim`], which stops at the first segment pair that
+    /// is not a valid [`TracePrefix`], every `/`-delimited segment must belong
+    /// to a hop, and each hop must consist of a valid [`PortId`] followed by
+    /// a valid [`ChannelId`]. The error reports the position of the first
+    /// offending hop, counted from the right-most (i.e. the first) one.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
             return Ok(TracePath::empty());
         }
 
-        let (trace_path, remaining_parts) = TracePath::trim(s);
-        remaining_parts
-            .is_none()
-            .then_some(trace_path)
-            .ok_or_else(|| TokenTransferError::MalformedTrace(s.to_string()))
+        let segments: Vec<&str> = s.split('/').collect();
+
+        if segments.len() % 2 != 0 {
+            return Err(TokenTransferError::InvalidTraceLength {
+                len: segments.len() as u64,
+            });
+        }
+
+        let trace_prefixes = segments
+            .chunks_exact(2)
+            .rev()
+            .enumerate()
+            .map(|(pos, hop)| {
+                let port_id = PortId::from_str(hop[0]).map_err(|e| {
+                    TokenTransferError::InvalidTracePortId {
+                        pos: pos as u64,
+                        validation_error: e,
+                    }
+                })?;
+                let channel_id = ChannelId::from_str(hop[1]).map_err(|e| {
+                    TokenTransferError::InvalidTraceChannelId {
+                        pos: pos as u64,
+                        validation_error: e,
+                    }
+                })?;
+
+                Ok(TracePrefix::new(port_id, channel_id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(trace_prefixes))
     }
 }
 
@@ -322,6 +362,45 @@ pub fn is_receiver_chain_source(
     denom.trace_path.starts_with(&prefix)
 }
 
+/// The direction of a transfer relative to the source of its tokens, which
+/// decides the bank operations performed at each end of the channel.
+///
+/// See [`is_sender_chain_source`] for what being the "source" of a token
+/// means.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// The sending chain is the source: it escrows the tokens, and unescrows
+    /// them on refund, while the receiving chain mints vouchers.
+    FromSource,
+    /// The receiving chain is the source: the sending chain burns its
+    /// vouchers, and mints them back on refund, while the receiving chain
+    /// unescrows the tokens.
+    ToSource,
+}
+
+/// Whether a token leaves or enters the host chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransferFlow {
+    /// The token is sent from the host chain.
+    Send,
+    /// The token is received on the host chain.
+    Receive,
+}
+
+/// Classifies the transfer of `denom` in `packet`, where `denom` is the
+/// denomination as traced on the sending chain.
+pub fn classify_transfer(packet: &Packet, denom: &PrefixedDenom) -> TransferDirection {
+    if is_receiver_chain_source(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        denom,
+    ) {
+        TransferDirection::ToSource
+    } else {
+        TransferDirection::FromSource
+    }
+}
+
 impl FromStr for PrefixedDenom {
     type Err = TokenTransferError;
 
@@ -403,6 +482,8 @@ impl Display for PrefixedDenom {
 
 #[cfg(test)]
 mod tests {
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::primitives::Timestamp;
     use rstest::rstest;
 
     use super::*;
@@ -655,4 +736,102 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_trace_path_rejects_invalid_hop() {
+        assert!(matches!(
+            TracePath::from_str("transfer/channel-0/transfer/chan-1"),
+            Err(TokenTransferError::InvalidTraceChannelId { pos: 0, .. })
+        ));
+        assert!(matches!(
+            TracePath::from_str("transfer/channel-0/tr@nsfer/channel-1"),
+            Err(TokenTransferError::InvalidTracePortId { pos: 0, .. })
+        ));
+        assert!(matches!(
+            TracePath::from_str("transfer/channel-0/transfer/channel-1/transfer"),
+            Err(TokenTransferError::InvalidTraceLength { len: 5 })
+        ));
+
+        let raw_denom_trace = RawDenomTrace {
+            path: "transfer/channel-0/transfer/chan-1".to_string(),
+            base_denom: "uatom".to_string(),
+        };
+        assert!(matches!(
+            PrefixedDenom::try_from(raw_denom_trace),
+            Err(TokenTransferError::InvalidTraceChannelId { pos: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_deep_trace_path() -> Result<(), TokenTransferError> {
+        let hops = 32;
+        let trace_path_s = (0..hops)
+            .map(|i| format!("transfer/channel-{i}"))
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let trace_path = TracePath::from_str(&trace_path_s)?;
+        assert_eq!(trace_path.len(), hops);
+        assert!(trace_path.starts_with(&TracePrefix::new(PortId::transfer(), ChannelId::new(0))));
+        assert_eq!(trace_path.to_string(), trace_path_s);
+
+        let raw_denom_trace = RawDenomTrace {
+            path: trace_path_s,
+            base_denom: "uatom".to_string(),
+        };
+        let prefixed_denom = PrefixedDenom::try_from(raw_denom_trace.clone())?;
+        assert_eq!(prefixed_denom.trace_path, trace_path);
+        assert_eq!(RawDenomTrace::from(prefixed_denom), raw_denom_trace);
+
+        Ok(())
+    }
+
+    fn dummy_packet(port_id_on_a: &str, chan_id_on_a: &str) -> Packet {
+        Packet {
+            seq_on_a: 1u64.into(),
+            port_id_on_a: port_id_on_a.parse().unwrap(),
+            chan_id_on_a: chan_id_on_a.parse().unwrap(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: vec![],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[rstest]
+    // A sends its native tokens: A escrows, B mints vouchers.
+    #[case::native_send("transfer", "channel-0", "uatom", TransferDirection::FromSource)]
+    // A sends back vouchers it received from B: A burns, B unescrows.
+    #[case::voucher_send_back(
+        "transfer",
+        "channel-0",
+        "transfer/channel-0/uatom",
+        TransferDirection::ToSource
+    )]
+    // B receives tokens A got from a third chain: B mints vouchers.
+    #[case::voucher_recv(
+        "transfer",
+        "channel-3",
+        "transfer/channel-5/uatom",
+        TransferDirection::FromSource
+    )]
+    // B receives back its native tokens: B unescrows.
+    #[case::native_recv_home(
+        "transfer",
+        "channel-3",
+        "transfer/channel-3/uatom",
+        TransferDirection::ToSource
+    )]
+    fn test_classify_transfer(
+        #[case] port_id_on_a: &str,
+        #[case] chan_id_on_a: &str,
+        #[case] denom: &str,
+        #[case] expected: TransferDirection,
+    ) {
+        let packet = dummy_packet(port_id_on_a, chan_id_on_a);
+        let denom = PrefixedDenom::from_str(denom).unwrap();
+
+        assert_eq!(classify_transfer(&packet, &denom), expected);
+    }
 }
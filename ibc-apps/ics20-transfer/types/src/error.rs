@@ -3,7 +3,9 @@ use core::convert::Infallible;
 use core::str::Utf8Error;
 
 use displaydoc::Display;
-use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
 use ibc_core::channel::types::channel::Order;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
@@ -11,6 +13,8 @@ use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use uint::FromDecStrErr;
 
+use crate::{Amount, PrefixedDenom, TransferFlow};
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -22,6 +26,12 @@ pub enum TokenTransferError {
         send_attempt: String,
         available_funds: String,
     },
+    /// insufficient escrow: tried to unescrow `{requested}` `{denom}`, only `{available}` escrowed
+    InsufficientEscrow {
+        denom: PrefixedDenom,
+        requested: Amount,
+        available: Amount,
+    },
     /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
     DestinationChannelNotFound {
         port_id: PortId,
@@ -39,10 +49,12 @@ pub enum TokenTransferError {
         pos: u64,
         validation_error: IdentifierError,
     },
-    /// malformed trace: `{0}`
-    MalformedTrace(String),
     /// trace length must be even but got: `{len}`
     InvalidTraceLength { len: u64 },
+    /// trace path has `{hops}` hops, exceeding the limit of `{max}`
+    TraceHopsExceeded { hops: u64, max: u64 },
+    /// memo of `{len}` bytes exceeds the limit of `{max}` bytes
+    MemoTooLong { len: usize, max: usize },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
     /// invalid token
@@ -62,6 +74,18 @@ pub enum TokenTransferError {
     ReceiveDisabled { reason: String },
     /// send is not enabled
     SendDisabled { reason: String },
+    /// `{flow:?}` of denom `{denom}` is not allowed
+    DenomNotAllowed {
+        denom: PrefixedDenom,
+        flow: TransferFlow,
+    },
+    /// send rate limit exceeded on port `{port_id}` and channel `{channel_id}`
+    SendRateLimitExceeded {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// refund cannot be applied without leaving a partial state: `{0}`
+    RefundInconsistent(Box<TokenTransferError>),
     /// failed to parse as AccountId
     ParseAccountFailure,
     /// invalid port: `{port_id}`, expected `{exp_port_id}`
@@ -81,6 +105,54 @@ pub enum TokenTransferError {
     Other(String),
 }
 
+impl TokenTransferError {
+    /// Returns the error string reported in error acknowledgements for this
+    /// error.
+    ///
+    /// Unlike the `Display` output, these strings carry no error details and
+    /// are kept stable, so that counterparties can tell failures apart. They
+    /// follow the Cosmos SDK and ibc-go error descriptions where one exists.
+    pub fn ack_error_str(&self) -> &'static str {
+        match self {
+            Self::ContextError(_) => "ibc core error",
+            Self::InvalidIdentifier(_) | Self::InvalidPort { .. } => "invalid identifier",
+            Self::InsufficientFunds { .. } | Self::InsufficientEscrow { .. } => {
+                "insufficient funds"
+            }
+            Self::DestinationChannelNotFound { .. } => "channel not found",
+            Self::EmptyBaseDenom
+            | Self::InvalidTracePortId { .. }
+            | Self::InvalidTraceChannelId { .. }
+            | Self::InvalidTraceLength { .. }
+            | Self::TraceHopsExceeded { .. } => "invalid denomination for cross-chain transfer",
+            Self::InvalidAmount(_) => "invalid token amount",
+            Self::MemoTooLong { .. } => "invalid memo",
+            Self::InvalidToken | Self::InvalidCoin { .. } => "invalid coins",
+            Self::ChannelNotUnordered { .. } => "invalid channel ordering",
+            Self::CantCloseChannel => "user cannot close channel",
+            Self::PacketDataDeserialization => "failed to deserialize packet data",
+            Self::AckDeserialization => "failed to deserialize acknowledgement",
+            Self::ReceiveDisabled { .. } => "fungible token transfers to this chain are disabled",
+            Self::SendDisabled { .. } => "fungible token transfers from this chain are disabled",
+            Self::DenomNotAllowed { .. } => "denomination not allowed",
+            Self::SendRateLimitExceeded { .. } => "rate limit exceeded",
+            Self::ParseAccountFailure => "invalid address",
+            Self::DecodeRawMsg { .. } | Self::UnknownMsgType { .. } | Self::Utf8Decode(_) => {
+                "failed to decode message"
+            }
+            Self::RefundInconsistent(_) | Self::Other(_) => "token transfer failed",
+        }
+    }
+
+    /// Returns the error acknowledgement status carrying
+    /// [`ack_error_str`](Self::ack_error_str).
+    pub fn ack_status(&self) -> AcknowledgementStatus {
+        AcknowledgementStatus::error(
+            StatusValue::new(self.ack_error_str()).expect("ack error string is never empty"),
+        )
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for TokenTransferError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -97,6 +169,7 @@ impl std::error::Error for TokenTransferError {
             } => Some(e),
             Self::InvalidAmount(e) => Some(e),
             Self::Utf8Decode(e) => Some(e),
+            Self::RefundInconsistent(e) => Some(e.as_ref()),
             _ => None,
         }
     }
@@ -125,3 +198,69 @@ impl From<TokenTransferError> for StatusValue {
         StatusValue::new(err.to_string()).expect("error message must not be empty")
     }
 }
+
+impl From<TokenTransferError> for Acknowledgement {
+    fn from(err: TokenTransferError) -> Self {
+        err.ack_status().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TokenTransferError::InsufficientFunds { send_attempt: "10".into(), available_funds: "5".into() }, "insufficient funds")]
+    #[case(TokenTransferError::InsufficientEscrow { denom: "uatom".parse().expect("valid denom"), requested: Amount::from(10), available: Amount::from(5) }, "insufficient funds")]
+    #[case(TokenTransferError::DestinationChannelNotFound { port_id: PortId::transfer(), channel_id: ChannelId::zero() }, "channel not found")]
+    #[case(
+        TokenTransferError::EmptyBaseDenom,
+        "invalid denomination for cross-chain transfer"
+    )]
+    #[case(TokenTransferError::InvalidTraceLength { len: 3 }, "invalid denomination for cross-chain transfer")]
+    #[case(TokenTransferError::TraceHopsExceeded { hops: 17, max: 16 }, "invalid denomination for cross-chain transfer")]
+    #[case(TokenTransferError::MemoTooLong { len: 40000, max: 32768 }, "invalid memo")]
+    #[case(TokenTransferError::DenomNotAllowed { denom: "uatom".parse().expect("valid denom"), flow: TransferFlow::Receive }, "denomination not allowed")]
+    #[case(
+        TokenTransferError::InvalidIdentifier(IdentifierError::RevisionNumberOverflow),
+        "invalid identifier"
+    )]
+    #[case(TokenTransferError::InvalidToken, "invalid coins")]
+    #[case(TokenTransferError::InvalidCoin { coin: "1".into() }, "invalid coins")]
+    #[case(TokenTransferError::CantCloseChannel, "user cannot close channel")]
+    #[case(
+        TokenTransferError::PacketDataDeserialization,
+        "failed to deserialize packet data"
+    )]
+    #[case(
+        TokenTransferError::AckDeserialization,
+        "failed to deserialize acknowledgement"
+    )]
+    #[case(TokenTransferError::ReceiveDisabled { reason: "paused".into() }, "fungible token transfers to this chain are disabled")]
+    #[case(TokenTransferError::SendDisabled { reason: "paused".into() }, "fungible token transfers from this chain are disabled")]
+    #[case(
+        TokenTransferError::SendRateLimitExceeded {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+        },
+        "rate limit exceeded"
+    )]
+    #[case(TokenTransferError::ParseAccountFailure, "invalid address")]
+    #[case(TokenTransferError::UnknownMsgType { msg_type: "foo".into() }, "failed to decode message")]
+    #[case(
+        TokenTransferError::RefundInconsistent(Box::new(TokenTransferError::ParseAccountFailure)),
+        "token transfer failed"
+    )]
+    #[case(TokenTransferError::Other("mint failed".into()), "token transfer failed")]
+    fn test_ack_error_str(#[case] err: TokenTransferError, #[case] expected: &str) {
+        assert_eq!(err.ack_error_str(), expected);
+
+        let ack = Acknowledgement::from(err);
+        assert_eq!(
+            ack.as_bytes(),
+            format!(r#"{{"error":"{expected}"}}"#).as_bytes()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Defines all token transfer event types
 use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::host::types::identifiers::Sequence;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::event::ModuleEvent;
@@ -114,12 +115,14 @@ impl From<AckStatusEvent> for ModuleEvent {
     }
 }
 
-/// Event emitted in the `onTimeoutPacket` module callback
+/// Event emitted in the `onTimeoutPacket` module callback, once the tokens of
+/// the timed out packet are refunded to its sender.
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
     pub refund_denom: PrefixedDenom,
     pub refund_amount: Amount,
     pub memo: Memo,
+    pub packet_sequence: Sequence,
 }
 
 impl From<TimeoutEvent> for ModuleEvent {
@@ -129,6 +132,7 @@ impl From<TimeoutEvent> for ModuleEvent {
             refund_denom,
             refund_amount,
             memo,
+            packet_sequence,
         } = ev;
         Self {
             kind: EVENT_TYPE_TIMEOUT.to_string(),
@@ -138,6 +142,7 @@ impl From<TimeoutEvent> for ModuleEvent {
                 ("refund_denom", refund_denom).into(),
                 ("refund_amount", refund_amount).into(),
                 ("memo", memo).into(),
+                ("packet_sequence", packet_sequence).into(),
             ],
         }
     }
@@ -46,6 +46,13 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// Default maximum number of hops, i.e. of `{port-id}/{channel-id}` prefixes,
+/// in the trace path of a received token.
+pub const DEFAULT_MAX_TRACE_HOPS: u64 = 16;
+
+/// Default maximum length, in bytes, of the memo of an outgoing transfer.
+pub const DEFAULT_MAX_MEMO_LENGTH: usize = 32 * 1024;
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
@@ -9,6 +9,8 @@ use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
 
+use crate::error::TokenTransferError;
+
 /// Represents the token transfer memo
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -27,6 +29,20 @@ use ibc_core::primitives::prelude::*;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Memo(String);
 
+impl Memo {
+    /// Checks that the memo is at most `max_len` bytes long.
+    pub fn verify_length(&self, max_len: usize) -> Result<(), TokenTransferError> {
+        if self.0.len() > max_len {
+            return Err(TokenTransferError::MemoTooLong {
+                len: self.0.len(),
+                max: max_len,
+            });
+        }
+
+        Ok(())
+    }
+}
+
 impl AsRef<str> for Memo {
     fn as_ref(&self) -> &str {
         &self.0
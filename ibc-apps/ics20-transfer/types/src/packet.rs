@@ -118,4 +118,22 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    /// Ensures the memo is carried over the `RawPacketData` conversions, an
+    /// absent memo being the empty string.
+    #[test]
+    fn test_packet_data_memo_round_trip() {
+        let packet_data = PacketData {
+            memo: r#"{"wasm":{"contract":"cosmos1contract"}}"#.into(),
+            ..PacketData::new_dummy()
+        };
+
+        let raw = RawPacketData::from(packet_data.clone());
+        assert_eq!(raw.memo, packet_data.memo.as_ref());
+        assert_eq!(PacketData::try_from(raw).unwrap(), packet_data);
+
+        let raw = RawPacketData::from(PacketData::new_dummy());
+        assert!(raw.memo.is_empty());
+        assert_eq!(PacketData::try_from(raw).unwrap().memo.as_ref(), "");
+    }
 }
@@ -0,0 +1,83 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{Fee, PacketFee};
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// Returns true if the fee middleware is enabled on the given channel.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool;
+
+    /// Validates that the coins of `fee` can be escrowed from the `payer`
+    /// account.
+    fn escrow_fee_validate(&self, payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError>;
+
+    /// Returns the fees recorded for the given packet, in the order they were
+    /// paid. Returns an empty list if the packet is not incentivized.
+    fn packet_fees(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Vec<PacketFee>, FeeError>;
+
+    /// Returns the counterparty payee registered by `relayer` on the given
+    /// channel, if any.
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Enables the fee middleware on the given channel, once the channel
+    /// negotiated a fee-wrapped version.
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError>;
+
+    /// Escrows the coins of `fee` from the `payer` account.
+    fn escrow_fee_execute(&mut self, payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError>;
+
+    /// Records the fees paid for the given packet, replacing any previous
+    /// record.
+    fn store_packet_fees(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError>;
+
+    /// Deletes the fees recorded for the given packet, once they are
+    /// distributed.
+    fn delete_packet_fees(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), FeeError>;
+
+    /// Transfers escrowed coins to the `receiver` account, either as a payment
+    /// to a relayer or as a refund to the payer of the fee.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+
+    /// Registers the address paid on the counterparty chain for the packets
+    /// delivered by `relayer` on the given channel.
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        counterparty_payee: &Signer,
+    ) -> Result<(), FeeError>;
+}
@@ -0,0 +1,106 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::IncentivizedPacketEvent;
+use ibc_app_fee_types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc_app_fee_types::{PacketFee, MODULE_ID_STR};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Pays a fee for the next packet sent on a channel. Equivalent to calling
+/// [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(send_packet_ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(send_packet_ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the fee payment. If this succeeds, then it is legal to escrow
+/// the fee with [`pay_packet_fee_execute`].
+pub fn pay_packet_fee_validate<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !fee_ctx_a.is_fee_enabled(&msg.port_id_on_a, &msg.chan_id_on_a) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: msg.port_id_on_a,
+            channel_id: msg.chan_id_on_a,
+        });
+    }
+
+    let payer: FeeCtx::AccountId = msg
+        .signer
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_validate(&payer, &msg.fee)
+}
+
+/// Escrows the fee and records it for the next packet sent on the channel. A
+/// prior call to [`pay_packet_fee_validate`] MUST have succeeded.
+pub fn pay_packet_fee_execute<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    // the fee incentivizes the packet that will be sent next on the channel
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let payer = msg
+        .signer
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_execute(&payer, &msg.fee)?;
+
+    let mut packet_fees = fee_ctx_a.packet_fees(&msg.port_id_on_a, &msg.chan_id_on_a, sequence)?;
+    packet_fees.push(PacketFee {
+        fee: msg.fee.clone(),
+        refund_address: msg.signer,
+    });
+    fee_ctx_a.store_packet_fees(&msg.port_id_on_a, &msg.chan_id_on_a, sequence, packet_fees)?;
+
+    {
+        send_packet_ctx_a.log_message(format!(
+            "IBC fee middleware: fee paid for packet {sequence} on {}/{}",
+            msg.port_id_on_a, msg.chan_id_on_a
+        ))?;
+
+        let incentivized_packet_event = IncentivizedPacketEvent {
+            port_id: msg.port_id_on_a,
+            channel_id: msg.chan_id_on_a,
+            sequence,
+            fee: msg.fee,
+        };
+        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(incentivized_packet_event).into())?;
+
+        send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
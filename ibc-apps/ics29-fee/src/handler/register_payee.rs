@@ -0,0 +1,76 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::RegisterCounterpartyPayeeEvent;
+use ibc_app_fee_types::msgs::register_payee::MsgRegisterCounterpartyPayee;
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers a counterparty payee. Equivalent to calling
+/// [`register_counterparty_payee_validate`], followed by
+/// [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee<SendPacketCtx, FeeCtx>(
+    send_packet_ctx: &mut SendPacketCtx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_counterparty_payee_validate(send_packet_ctx, fee_ctx, msg.clone())?;
+    register_counterparty_payee_execute(send_packet_ctx, fee_ctx, msg)
+}
+
+/// Validates the registration of a counterparty payee. If this succeeds, then
+/// it is legal to register it with [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee_validate<SendPacketCtx, FeeCtx>(
+    send_packet_ctx: &SendPacketCtx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let chan_end_path = ChannelEndPath::new(&msg.port_id, &msg.chan_id);
+    send_packet_ctx.channel_end(&chan_end_path)?;
+
+    if !fee_ctx.is_fee_enabled(&msg.port_id, &msg.chan_id) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: msg.port_id,
+            channel_id: msg.chan_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Registers the counterparty payee of the relayer. A prior call to
+/// [`register_counterparty_payee_validate`] MUST have succeeded.
+pub fn register_counterparty_payee_execute<SendPacketCtx, FeeCtx>(
+    send_packet_ctx: &mut SendPacketCtx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_counterparty_payee(&msg.chan_id, &msg.relayer, &msg.counterparty_payee)?;
+
+    let register_payee_event = RegisterCounterpartyPayeeEvent {
+        relayer: msg.relayer,
+        counterparty_payee: msg.counterparty_payee,
+        channel_id: msg.chan_id,
+    };
+    send_packet_ctx.emit_ibc_event(ModuleEvent::from(register_payee_event).into())?;
+
+    send_packet_ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+
+    Ok(())
+}
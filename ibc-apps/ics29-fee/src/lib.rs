@@ -0,0 +1,35 @@
+//! Implementation of the IBC [fee middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md) (ICS-29) logic.
+//!
+//! The middleware escrows the fees paid for the packets sent on fee-enabled
+//! channels, and distributes them to the relayers once the packets are
+//! acknowledged or timed out. Hosts wrap the underlying application with
+//! [`module::FeeMiddleware`], or call the callbacks in [`module`] from their
+//! own application module.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the implementation of the IBC [fee
+/// middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_fee_types::*;
+}
+
+pub mod context;
+pub mod handler;
+pub mod module;
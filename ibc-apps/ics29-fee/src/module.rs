@@ -0,0 +1,598 @@
+//! Implements the fee middleware callbacks wrapping the packet callbacks of
+//! the underlying application.
+//!
+//! On channels without the fee middleware enabled, the callbacks leave the
+//! acknowledgements untouched and distribute no fee.
+//!
+//! [`FeeMiddleware`] wires these callbacks around an application [`Module`],
+//! and negotiates the fee-wrapped channel versions during the handshake.
+use core::fmt::Debug;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::DistributeFeeEvent;
+use ibc_app_fee_types::{IncentivizedAcknowledgement, PacketFee, VersionMetadata, VERSION};
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Wraps the acknowledgement written by the underlying application into an
+/// [`IncentivizedAcknowledgement`], forwarding the counterparty payee of the
+/// relayer to the source chain.
+///
+/// `underlying_app_success` tells whether the underlying application
+/// processed the packet successfully, as only the application knows how to
+/// interpret its acknowledgement.
+///
+/// Fails if the counterparty payee of the relayer cannot be read from the
+/// host's store.
+pub fn on_recv_packet_execute(
+    ctx_b: &impl FeeValidationContext,
+    packet: &Packet,
+    relayer: &Signer,
+    app_acknowledgement: Acknowledgement,
+    underlying_app_success: bool,
+) -> Result<Acknowledgement, FeeError> {
+    if !ctx_b.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+        return Ok(app_acknowledgement);
+    }
+
+    // A relayer without a registered counterparty payee forwards an empty
+    // address, leaving the receive fee to be refunded on the source chain.
+    let forward_relayer_address = ctx_b
+        .counterparty_payee(&packet.chan_id_on_b, relayer)?
+        .unwrap_or_else(|| String::new().into());
+
+    Ok(IncentivizedAcknowledgement {
+        app_acknowledgement,
+        forward_relayer_address,
+        underlying_app_success,
+    }
+    .into())
+}
+
+/// Validates the acknowledgement of a packet, and returns the acknowledgement
+/// to hand over to the underlying application.
+pub fn on_acknowledgement_packet_validate(
+    ctx_a: &impl FeeValidationContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+) -> Result<Acknowledgement, FeeError> {
+    if !ctx_a.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return Ok(acknowledgement.clone());
+    }
+
+    let incentivized_ack = IncentivizedAcknowledgement::try_from(acknowledgement)?;
+
+    Ok(incentivized_ack.app_acknowledgement)
+}
+
+/// Distributes the fees recorded for an acknowledged packet, and returns the
+/// acknowledgement to hand over to the underlying application.
+///
+/// The receive fee is paid to the forward relayer address carried by the
+/// acknowledgement, the acknowledgement fee to the `relayer` delivering it,
+/// and the timeout fee is refunded.
+pub fn on_acknowledgement_packet_execute(
+    ctx_a: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<Acknowledgement, FeeError>) {
+    if !ctx_a.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return (ModuleExtras::empty(), Ok(acknowledgement.clone()));
+    }
+
+    let incentivized_ack = match IncentivizedAcknowledgement::try_from(acknowledgement) {
+        Ok(incentivized_ack) => incentivized_ack,
+        Err(e) => return (ModuleExtras::empty(), Err(e)),
+    };
+
+    let mut extras = ModuleExtras::empty();
+
+    let result = distribute_packet_fees(ctx_a, packet, &mut extras, |packet_fee| {
+        vec![
+            (
+                incentivized_ack.forward_relayer_address.clone(),
+                packet_fee.fee.recv_fee.clone(),
+            ),
+            (relayer.clone(), packet_fee.fee.ack_fee.clone()),
+            (
+                packet_fee.refund_address.clone(),
+                packet_fee.fee.timeout_fee.clone(),
+            ),
+        ]
+    });
+
+    (extras, result.map(|_| incentivized_ack.app_acknowledgement))
+}
+
+/// Distributes the fees recorded for a timed out packet.
+///
+/// The timeout fee is paid to the `relayer` delivering the timeout, and the
+/// receive and acknowledgement fees are refunded.
+pub fn on_timeout_packet_execute(
+    ctx_a: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), FeeError>) {
+    if !ctx_a.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return (ModuleExtras::empty(), Ok(()));
+    }
+
+    let mut extras = ModuleExtras::empty();
+
+    let result = distribute_packet_fees(ctx_a, packet, &mut extras, |packet_fee| {
+        let refund = packet_fee
+            .fee
+            .recv_fee
+            .iter()
+            .chain(packet_fee.fee.ack_fee.iter())
+            .cloned()
+            .collect();
+
+        vec![
+            (relayer.clone(), packet_fee.fee.timeout_fee.clone()),
+            (packet_fee.refund_address.clone(), refund),
+        ]
+    });
+
+    (extras, result)
+}
+
+/// Wraps the callbacks of the underlying application `App` with the ones of
+/// the fee middleware, backed by the host's fee context `Ctx`.
+///
+/// Channels negotiating a fee-wrapped version, e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`, get the fee
+/// middleware enabled, while the underlying application only ever sees its
+/// own version.
+#[derive(Debug)]
+pub struct FeeMiddleware<Ctx, App> {
+    ctx: Ctx,
+    app: App,
+}
+
+impl<Ctx, App> FeeMiddleware<Ctx, App> {
+    pub fn new(ctx: Ctx, app: App) -> Self {
+        Self { ctx, app }
+    }
+
+    /// Returns the fee context of the middleware.
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    /// Returns the underlying application.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the application version carried by the counterparty version
+    /// acknowledging the channel opening, which must be fee-wrapped if the
+    /// fee middleware was enabled when the channel was initialized.
+    fn counterparty_app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError>
+    where
+        Ctx: FeeValidationContext,
+    {
+        if !self.ctx.is_fee_enabled(port_id, channel_id) {
+            return Ok(counterparty_version.clone());
+        }
+
+        unwrap_app_version(counterparty_version)?.ok_or_else(|| ChannelError::AppModule {
+            description: format!(
+                "counterparty version `{counterparty_version}` is not fee-wrapped"
+            ),
+        })
+    }
+}
+
+impl<Ctx, App> Module for FeeMiddleware<Ctx, App>
+where
+    Ctx: FeeExecutionContext + Debug,
+    App: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(app_version) = unwrap_app_version(version)? else {
+            return self.app.on_chan_open_init_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            );
+        };
+
+        let app_version = self.app.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok(VersionMetadata::new(app_version.as_str()).into())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(app_version) = unwrap_app_version(version)? else {
+            return self.app.on_chan_open_init_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            );
+        };
+
+        let (extras, app_version) = self.app.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        self.ctx
+            .enable_fee(port_id, channel_id)
+            .map_err(channel_error)?;
+
+        Ok((extras, VersionMetadata::new(app_version.as_str()).into()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(app_version) = unwrap_app_version(counterparty_version)? else {
+            return self.app.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+
+        let app_version = self.app.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok(VersionMetadata::new(app_version.as_str()).into())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(app_version) = unwrap_app_version(counterparty_version)? else {
+            return self.app.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+
+        let (extras, app_version) = self.app.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        self.ctx
+            .enable_fee(port_id, channel_id)
+            .map_err(channel_error)?;
+
+        Ok((extras, VersionMetadata::new(app_version.as_str()).into()))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version =
+            self.counterparty_app_version(port_id, channel_id, counterparty_version)?;
+
+        self.app
+            .on_chan_open_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version =
+            self.counterparty_app_version(port_id, channel_id, counterparty_version)?;
+
+        self.app
+            .on_chan_open_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let (extras, app_acknowledgement) = self.app.on_recv_packet_execute(packet, relayer);
+        let underlying_app_success = is_app_ack_successful(&app_acknowledgement);
+
+        match on_recv_packet_execute(
+            &self.ctx,
+            packet,
+            relayer,
+            app_acknowledgement,
+            underlying_app_success,
+        ) {
+            Ok(acknowledgement) => (extras, acknowledgement),
+            Err(e) => (extras, AcknowledgementStatus::error(e.into()).into()),
+        }
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        let app_acknowledgement =
+            on_acknowledgement_packet_validate(&self.ctx, packet, acknowledgement)
+                .map_err(packet_error)?;
+
+        self.app
+            .on_acknowledgement_packet_validate(packet, &app_acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (fee_extras, result) =
+            on_acknowledgement_packet_execute(&mut self.ctx, packet, acknowledgement, relayer);
+
+        let app_acknowledgement = match result {
+            Ok(app_acknowledgement) => app_acknowledgement,
+            Err(e) => return (fee_extras, Err(packet_error(e))),
+        };
+
+        let (app_extras, result) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, &app_acknowledgement, relayer);
+
+        (merge_extras(fee_extras, app_extras), result)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.app.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (fee_extras, result) = on_timeout_packet_execute(&mut self.ctx, packet, relayer);
+
+        if let Err(e) = result {
+            return (fee_extras, Err(packet_error(e)));
+        }
+
+        let (app_extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        (merge_extras(fee_extras, app_extras), result)
+    }
+}
+
+/// Pays out every fee recorded for the packet according to `payouts`, which
+/// splits a fee into the coins owed to each receiver, then deletes the
+/// record.
+///
+/// Coins owed to a receiver that is not a valid account, such as the empty
+/// forward relayer address, are refunded instead.
+fn distribute_packet_fees<Ctx, F>(
+    ctx_a: &mut Ctx,
+    packet: &Packet,
+    extras: &mut ModuleExtras,
+    payouts: F,
+) -> Result<(), FeeError>
+where
+    Ctx: FeeExecutionContext,
+    F: Fn(&PacketFee) -> Vec<(Signer, Vec<PrefixedCoin>)>,
+{
+    let packet_fees =
+        ctx_a.packet_fees(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)?;
+
+    for packet_fee in &packet_fees {
+        for (receiver, coins) in payouts(packet_fee) {
+            if coins.is_empty() {
+                continue;
+            }
+
+            let account: Option<Ctx::AccountId> = match receiver.clone().try_into() {
+                Ok(account) if !receiver.as_ref().is_empty() => Some(account),
+                _ => None,
+            };
+
+            let (receiver, account) = match account {
+                Some(account) => (receiver, account),
+                None => (
+                    packet_fee.refund_address.clone(),
+                    packet_fee
+                        .refund_address
+                        .clone()
+                        .try_into()
+                        .map_err(|_| FeeError::ParseAccountFailure)?,
+                ),
+            };
+
+            ctx_a.distribute_fee_execute(&account, &coins)?;
+
+            extras.events.push(
+                DistributeFeeEvent {
+                    receiver,
+                    fee: coins,
+                }
+                .into(),
+            );
+        }
+    }
+
+    ctx_a.delete_packet_fees(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
+}
+
+/// Returns the application version carried by a fee-wrapped `version`, or
+/// `None` if `version` is not fee-wrapped.
+///
+/// Fails if the fee version is not the one of this middleware.
+fn unwrap_app_version(version: &Version) -> Result<Option<Version>, ChannelError> {
+    let Some(metadata) = VersionMetadata::from_version(version) else {
+        return Ok(None);
+    };
+
+    if !metadata.is_supported() {
+        return Err(ChannelError::VersionNotSupported {
+            expected: Version::new(VERSION.to_string()),
+            actual: Version::new(metadata.fee_version),
+        });
+    }
+
+    Ok(Some(metadata.app_version()))
+}
+
+/// Returns false if the acknowledgement written by the underlying application
+/// is an error acknowledgement, i.e. a JSON object with an `error` field.
+fn is_app_ack_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<BTreeMap<String, String>>(acknowledgement.as_bytes())
+        .map_or(true, |fields| !fields.contains_key("error"))
+}
+
+fn merge_extras(mut extras: ModuleExtras, other: ModuleExtras) -> ModuleExtras {
+    extras.events.extend(other.events);
+    extras.log.extend(other.log);
+    extras
+}
+
+fn channel_error(err: FeeError) -> ChannelError {
+    ChannelError::AppModule {
+        description: err.to_string(),
+    }
+}
+
+fn packet_error(err: FeeError) -> PacketError {
+    PacketError::AppModule {
+        description: err.to_string(),
+    }
+}
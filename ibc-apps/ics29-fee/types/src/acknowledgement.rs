@@ -0,0 +1,103 @@
+//! Defines the acknowledgement written by the fee middleware.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::IncentivizedAcknowledgement as RawIncentivizedAcknowledgement;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// Acknowledgement written on fee-enabled channels, embedding the
+/// acknowledgement of the underlying application along with the address to
+/// which the receive fee is paid on the source chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncentivizedAcknowledgement {
+    /// The acknowledgement written by the underlying application.
+    pub app_acknowledgement: Acknowledgement,
+    /// The counterparty payee of the relayer that delivered the packet. Empty
+    /// if the relayer registered none.
+    pub forward_relayer_address: Signer,
+    /// Whether the underlying application processed the packet successfully.
+    pub underlying_app_success: bool,
+}
+
+impl Protobuf<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {}
+
+impl TryFrom<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(raw_ack: RawIncentivizedAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app_acknowledgement: raw_ack
+                .app_acknowledgement
+                .try_into()
+                .map_err(FeeError::InvalidAcknowledgement)?,
+            forward_relayer_address: raw_ack.forward_relayer_address.into(),
+            underlying_app_success: raw_ack.underlying_app_success,
+        })
+    }
+}
+
+impl From<IncentivizedAcknowledgement> for RawIncentivizedAcknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        Self {
+            app_acknowledgement: ack.app_acknowledgement.as_ref().to_vec(),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        }
+    }
+}
+
+/// The proto3 JSON form of [`IncentivizedAcknowledgement`], written into the
+/// channel acknowledgement as ibc-go does, with the original field names, the
+/// default values emitted, and the application acknowledgement in base64.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonIncentivizedAcknowledgement {
+    app_acknowledgement: String,
+    forward_relayer_address: String,
+    underlying_app_success: bool,
+}
+
+impl From<IncentivizedAcknowledgement> for Acknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        let json_ack = JsonIncentivizedAcknowledgement {
+            app_acknowledgement: BASE64_STANDARD.encode(ack.app_acknowledgement.as_ref()),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        };
+
+        // A JSON object is never empty.
+        Acknowledgement::try_from(
+            serde_json::to_vec(&json_ack)
+                .expect("incentivized acknowledgement always serializes to JSON"),
+        )
+        .expect("incentivized acknowledgement encoding is never empty")
+    }
+}
+
+impl TryFrom<&Acknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, Self::Error> {
+        let json_ack: JsonIncentivizedAcknowledgement = serde_json::from_slice(ack.as_ref())
+            .map_err(|e| FeeError::DecodeIncentivizedAck {
+                reason: e.to_string(),
+            })?;
+
+        let app_acknowledgement = BASE64_STANDARD
+            .decode(json_ack.app_acknowledgement)
+            .map_err(|e| FeeError::DecodeIncentivizedAck {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self {
+            app_acknowledgement: app_acknowledgement
+                .try_into()
+                .map_err(FeeError::InvalidAcknowledgement)?,
+            forward_relayer_address: json_ack.forward_relayer_address.into(),
+            underlying_app_success: json_ack.underlying_app_success,
+        })
+    }
+}
@@ -0,0 +1,81 @@
+//! Defines the fee middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// invalid signer: `{reason}`
+    InvalidSigner { reason: String },
+    /// missing fee
+    MissingFee,
+    /// fee must contain at least one coin
+    EmptyFee,
+    /// paying fees to a specific set of relayers is not supported
+    RelayersNotSupported,
+    /// fee middleware is not enabled on port `{port_id}` and channel `{channel_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// invalid acknowledgement: `{0}`
+    InvalidAcknowledgement(PacketError),
+    /// decoding incentivized acknowledgement error: `{reason}`
+    DecodeIncentivizedAck { reason: String },
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            Self::InvalidAcknowledgement(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> Self {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for FeeError {
+    fn from(err: IdentifierError) -> Self {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TokenTransferError> for FeeError {
+    fn from(err: TokenTransferError) -> Self {
+        Self::InvalidCoin(err)
+    }
+}
+
+impl From<FeeError> for StatusValue {
+    fn from(err: FeeError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
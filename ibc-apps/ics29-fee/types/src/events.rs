@@ -0,0 +1,118 @@
+//! Defines all fee middleware event types
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::{Fee, MODULE_ID_STR};
+
+const EVENT_TYPE_INCENTIVIZED_PACKET: &str = "incentivized_ibc_packet";
+const EVENT_TYPE_REGISTER_PAYEE: &str = "register_counterparty_payee";
+const EVENT_TYPE_DISTRIBUTE_FEE: &str = "distribute_fee";
+
+/// Formats coins the way the Cosmos SDK formats `sdk.Coins`, e.g.
+/// `100stake,20atom`.
+fn format_coins(coins: &[PrefixedCoin]) -> String {
+    coins
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Contains all events variants that can be emitted from the fee middleware
+pub enum Event {
+    IncentivizedPacket(IncentivizedPacketEvent),
+    RegisterCounterpartyPayee(RegisterCounterpartyPayeeEvent),
+    DistributeFee(DistributeFeeEvent),
+}
+
+/// Event emitted when a fee is escrowed for a packet
+pub struct IncentivizedPacketEvent {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    /// The total fee escrowed for the packet
+    pub fee: Fee,
+}
+
+impl From<IncentivizedPacketEvent> for ModuleEvent {
+    fn from(ev: IncentivizedPacketEvent) -> Self {
+        let IncentivizedPacketEvent {
+            port_id,
+            channel_id,
+            sequence,
+            fee,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_INCENTIVIZED_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("port_id", port_id).into(),
+                ("channel_id", channel_id).into(),
+                ("packet_sequence", sequence).into(),
+                ("recv_fee", format_coins(&fee.recv_fee)).into(),
+                ("ack_fee", format_coins(&fee.ack_fee)).into(),
+                ("timeout_fee", format_coins(&fee.timeout_fee)).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when a relayer registers a counterparty payee
+pub struct RegisterCounterpartyPayeeEvent {
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+    pub channel_id: ChannelId,
+}
+
+impl From<RegisterCounterpartyPayeeEvent> for ModuleEvent {
+    fn from(ev: RegisterCounterpartyPayeeEvent) -> Self {
+        let RegisterCounterpartyPayeeEvent {
+            relayer,
+            counterparty_payee,
+            channel_id,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_REGISTER_PAYEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("relayer", relayer).into(),
+                ("counterparty_payee", counterparty_payee).into(),
+                ("channel_id", channel_id).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted for every part of an escrowed fee paid out to a relayer or
+/// refunded to the payer
+pub struct DistributeFeeEvent {
+    pub receiver: Signer,
+    pub fee: Vec<PrefixedCoin>,
+}
+
+impl From<DistributeFeeEvent> for ModuleEvent {
+    fn from(ev: DistributeFeeEvent) -> Self {
+        let DistributeFeeEvent { receiver, fee } = ev;
+        Self {
+            kind: EVENT_TYPE_DISTRIBUTE_FEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("receiver", receiver).into(),
+                ("fee", format_coins(&fee)).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::IncentivizedPacket(ev) => ev.into(),
+            Event::RegisterCounterpartyPayee(ev) => ev.into(),
+            Event::DistributeFee(ev) => ev.into(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Defines the fees paid to relayers for relaying packets.
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use ibc_proto::ibc::applications::fee::v1::{Fee as RawFee, PacketFee as RawPacketFee};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// Fees paid to the relayers of a packet, escrowed on the source chain until
+/// the packet is acknowledged or timed out.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    /// Paid to the relayer delivering the packet to the destination chain.
+    pub recv_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer delivering the acknowledgement back to the source
+    /// chain.
+    pub ack_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer delivering a timeout of the packet to the source
+    /// chain.
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl Fee {
+    /// Returns true if the fee carries no coins at all.
+    pub fn is_empty(&self) -> bool {
+        self.recv_fee.is_empty() && self.ack_fee.is_empty() && self.timeout_fee.is_empty()
+    }
+
+    /// Returns all the coins of the fee, i.e. the coins to escrow when the
+    /// fee is paid.
+    pub fn coins(&self) -> impl Iterator<Item = &PrefixedCoin> {
+        self.recv_fee
+            .iter()
+            .chain(self.ack_fee.iter())
+            .chain(self.timeout_fee.iter())
+    }
+}
+
+fn coins_from_raw(raw_coins: Vec<ProtoCoin>) -> Result<Vec<PrefixedCoin>, FeeError> {
+    raw_coins
+        .into_iter()
+        .map(|coin| PrefixedCoin::try_from(coin).map_err(FeeError::from))
+        .collect()
+}
+
+fn coins_into_raw(coins: Vec<PrefixedCoin>) -> Vec<ProtoCoin> {
+    coins.into_iter().map(ProtoCoin::from).collect()
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw_fee: RawFee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            recv_fee: coins_from_raw(raw_fee.recv_fee)?,
+            ack_fee: coins_from_raw(raw_fee.ack_fee)?,
+            timeout_fee: coins_from_raw(raw_fee.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        Self {
+            recv_fee: coins_into_raw(fee.recv_fee),
+            ack_fee: coins_into_raw(fee.ack_fee),
+            timeout_fee: coins_into_raw(fee.timeout_fee),
+        }
+    }
+}
+
+/// A fee recorded for a packet, along with the account refunded with the
+/// unused part of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_packet_fee: RawPacketFee) -> Result<Self, Self::Error> {
+        if !raw_packet_fee.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        Ok(Self {
+            fee: raw_packet_fee.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            refund_address: Signer::new_checked(raw_packet_fee.refund_address).map_err(|e| {
+                FeeError::InvalidSigner {
+                    reason: e.to_string(),
+                }
+            })?,
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        Self {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Defines the channel version negotiated on fee-enabled channels.
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+
+use crate::VERSION;
+
+/// The version of a fee-enabled channel, wrapping the version of the
+/// underlying application as
+/// `{"fee_version":"ics29-1","app_version":"<app version>"}`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VersionMetadata {
+    /// The version of the fee middleware.
+    pub fee_version: String,
+    /// The version of the underlying application.
+    pub app_version: String,
+}
+
+impl VersionMetadata {
+    /// Wraps the given application version with the current fee middleware
+    /// version.
+    pub fn new(app_version: impl Into<String>) -> Self {
+        Self {
+            fee_version: VERSION.to_string(),
+            app_version: app_version.into(),
+        }
+    }
+
+    /// Parses a fee-wrapped channel version, or returns `None` if `version`
+    /// is not one, i.e. belongs to the underlying application alone.
+    pub fn from_version(version: &Version) -> Option<Self> {
+        serde_json::from_str(version.as_str()).ok()
+    }
+
+    /// Returns true if the fee version is the one of this middleware.
+    pub fn is_supported(&self) -> bool {
+        self.fee_version == VERSION
+    }
+
+    /// Returns the version of the underlying application.
+    pub fn app_version(&self) -> Version {
+        Version::new(self.app_version.clone())
+    }
+}
+
+impl From<VersionMetadata> for Version {
+    fn from(metadata: VersionMetadata) -> Self {
+        Version::new(
+            serde_json::to_string(&metadata).expect("version metadata always serializes to JSON"),
+        )
+    }
+}
@@ -0,0 +1,87 @@
+//! Defines the message used to incentivize the next packet sent on a channel
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgPayPacketFee as RawMsgPayPacketFee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::Fee;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+
+/// Message used to escrow a fee for the next packet sent on the given port
+/// and channel.
+///
+/// The fee is escrowed from the signer's account, which is also refunded
+/// with the unused part of it once the packet is acknowledged or timed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgPayPacketFee {
+    /// the fee paid to the relayers of the packet
+    pub fee: Fee,
+    /// the port on which the incentivized packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel on which the incentivized packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, and refunded with its unused part
+    pub signer: Signer,
+}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        // Fees restricted to a set of relayers are reserved for future use.
+        if !raw_msg.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        let fee: Fee = raw_msg.fee.ok_or(FeeError::MissingFee)?.try_into()?;
+
+        if fee.is_empty() {
+            return Err(FeeError::EmptyFee);
+        }
+
+        Ok(MsgPayPacketFee {
+            fee,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: Signer::new_checked(raw_msg.signer).map_err(|e| FeeError::InvalidSigner {
+                reason: e.to_string(),
+            })?,
+        })
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: Vec::new(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => {
+                MsgPayPacketFee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
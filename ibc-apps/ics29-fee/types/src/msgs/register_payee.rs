@@ -0,0 +1,79 @@
+//! Defines the message used by relayers to register a counterparty payee
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+/// Message used by a relayer to register the address paid with the receive
+/// fee of the packets it delivers on the given channel.
+///
+/// The counterparty payee is an address on the counterparty chain, and is
+/// forwarded to it in the [`IncentivizedAcknowledgement`](crate::IncentivizedAcknowledgement)
+/// of the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterCounterpartyPayee {
+    /// the port of the channel on which the relayer delivers packets
+    pub port_id: PortId,
+    /// the channel on which the relayer delivers packets
+    pub chan_id: ChannelId,
+    /// the relayer address on this chain
+    pub relayer: Signer,
+    /// the address paid on the counterparty chain
+    pub counterparty_payee: Signer,
+}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        let checked_signer = |signer: String| {
+            Signer::new_checked(signer).map_err(|e| FeeError::InvalidSigner {
+                reason: e.to_string(),
+            })
+        };
+
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            relayer: checked_signer(raw_msg.relayer)?,
+            counterparty_payee: checked_signer(raw_msg.counterparty_payee)?,
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<Any> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => MsgRegisterCounterpartyPayee::decode_vec(&raw.value).map_err(|e| {
+                FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
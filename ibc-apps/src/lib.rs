@@ -19,6 +19,14 @@ pub mod transfer {
     pub use ibc_app_transfer::*;
 }
 
+/// Re-exports the implementation of the IBC [fee
+/// middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) logic.
+pub mod fee {
+    #[doc(inline)]
+    pub use ibc_app_fee::*;
+}
+
 /// Re-exports the implementation of the IBC [Non-Fungible Token
 /// Transfer](https://github.com/cosmos/ibc/blob/main/spec/app/ics-721-nft-transfer/README.md)
 /// (ICS-721) application logic.
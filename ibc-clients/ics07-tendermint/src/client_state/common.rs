@@ -119,9 +119,9 @@ pub fn validate_proof_height(
     let latest_height = client_state.latest_height;
 
     if latest_height < proof_height {
-        return Err(ClientError::InvalidProofHeight {
-            latest_height,
-            proof_height,
+        return Err(ClientError::HeightTooNew {
+            got: proof_height,
+            latest: latest_height,
         });
     }
 
@@ -9,7 +9,7 @@ use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
-use super::ClientState;
+use super::{misbehaviour_heights, ClientState};
 
 impl<E> ClientStateExecution<E> for ClientState
 where
@@ -101,20 +101,20 @@ where
 
     let tm_consensus_state: ConsensusStateType = consensus_state.try_into()?;
 
-    ctx.store_client_state(
+    ctx.store_client_state_observed(
         ClientStatePath::new(client_id.clone()),
-        client_state.clone().into(),
+        client_state.clone(),
     )?;
-    ctx.store_consensus_state(
+    ctx.store_consensus_state_observed(
         ClientConsensusStatePath::new(
             client_id.clone(),
             client_state.latest_height.revision_number(),
             client_state.latest_height.revision_height(),
         ),
-        tm_consensus_state.into(),
+        tm_consensus_state,
     )?;
 
-    ctx.store_update_meta(
+    ctx.store_update_meta_observed(
         client_id.clone(),
         client_state.latest_height,
         host_timestamp,
@@ -145,7 +145,9 @@ where
     let header = TmHeader::try_from(header)?;
     let header_height = header.height();
 
-    prune_oldest_consensus_state(client_state, ctx, client_id)?;
+    // The consensus state the header was verified against is kept, even if
+    // expired, so that the header can still be checked against it.
+    prune_oldest_consensus_state(client_state, ctx, client_id, &header.trusted_height)?;
 
     let maybe_existing_consensus_state = {
         let path_at_header_height = ClientConsensusStatePath::new(
@@ -169,24 +171,26 @@ where
         let new_consensus_state = ConsensusStateType::from(header.clone());
         let new_client_state = client_state.clone().with_header(header)?;
 
-        ctx.store_consensus_state(
+        ctx.store_consensus_state_observed(
             ClientConsensusStatePath::new(
                 client_id.clone(),
                 header_height.revision_number(),
                 header_height.revision_height(),
             ),
-            new_consensus_state.into(),
+            new_consensus_state,
         )?;
-        ctx.store_client_state(
+        ctx.store_client_state_observed(
             ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
+            new_client_state.clone(),
         )?;
-        ctx.store_update_meta(
+        ctx.store_update_meta_observed(
             client_id.clone(),
             header_height,
             host_timestamp,
             host_height,
         )?;
+
+        prune_excess_consensus_states(&new_client_state, ctx, client_id)?;
     }
 
     Ok(vec![header_height])
@@ -195,6 +199,9 @@ where
 /// Commit a frozen client state, which was frozen as a result of having exhibited
 /// misbehaviour, to the store.
 ///
+/// The client is frozen at the height at which misbehaviour was detected, i.e.
+/// the lowest of the conflicting header heights carried by `client_message`.
+///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateExecution`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
@@ -202,23 +209,23 @@ pub fn update_on_misbehaviour<E>(
     client_state: &ClientStateType,
     ctx: &mut E,
     client_id: &ClientId,
-    _client_message: Any,
+    client_message: Any,
 ) -> Result<(), ClientError>
 where
     E: ExtClientExecutionContext,
     E::ClientStateRef: From<ClientStateType>,
 {
-    // NOTE: frozen height is  set to `Height {revision_height: 0,
-    // revision_number: 1}` and it is the same for all misbehaviour. This
-    // aligns with the
-    // [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
-    // implementation.
-    let frozen_client_state = client_state.clone().with_frozen_height(Height::min(0));
-
-    ctx.store_client_state(
-        ClientStatePath::new(client_id.clone()),
-        frozen_client_state.into(),
-    )?;
+    // NOTE: ibc-go freezes every client at the sentinel height `0-1`, and
+    // only ever checks whether a frozen height is set. Recording the actual
+    // height keeps the client frozen all the same.
+    let frozen_height = misbehaviour_heights(client_message)?
+        .into_iter()
+        .min()
+        .ok_or(ClientError::InvalidUpdateClientMessage)?;
+
+    let frozen_client_state = client_state.clone().with_frozen_height(frozen_height);
+
+    ctx.store_client_state_observed(ClientStatePath::new(client_id.clone()), frozen_client_state)?;
 
     Ok(())
 }
@@ -286,19 +293,16 @@ where
     let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
     let host_height = ExtClientValidationContext::host_height(ctx)?;
 
-    ctx.store_client_state(
-        ClientStatePath::new(client_id.clone()),
-        new_client_state.into(),
-    )?;
-    ctx.store_consensus_state(
+    ctx.store_client_state_observed(ClientStatePath::new(client_id.clone()), new_client_state)?;
+    ctx.store_consensus_state_observed(
         ClientConsensusStatePath::new(
             client_id.clone(),
             latest_height.revision_number(),
             latest_height.revision_height(),
         ),
-        new_consensus_state.into(),
+        new_consensus_state,
     )?;
-    ctx.store_update_meta(
+    ctx.store_update_meta_observed(
         client_id.clone(),
         latest_height,
         host_timestamp,
@@ -311,10 +315,13 @@ where
 /// Removes consensus states from the client store whose timestamps
 /// are less than or equal to the host timestamp. This ensures that
 /// the client store does not amass a buildup of stale consensus states.
+///
+/// The consensus state at `retained_height` is never removed.
 pub fn prune_oldest_consensus_state<E>(
     client_state: &ClientStateType,
     ctx: &mut E,
     client_id: &ClientId,
+    retained_height: &Height,
 ) -> Result<(), ClientError>
 where
     E: ClientExecutionContext + ExtClientValidationContext,
@@ -327,6 +334,10 @@ where
     heights.sort();
 
     for height in heights {
+        if height == *retained_height {
+            continue;
+        }
+
         let client_consensus_state_path = ClientConsensusStatePath::new(
             client_id.clone(),
             height.revision_number(),
@@ -356,8 +367,53 @@ where
             break;
         }
 
-        ctx.delete_consensus_state(client_consensus_state_path)?;
-        ctx.delete_update_meta(client_id.clone(), height)?;
+        ctx.delete_consensus_state_observed(client_consensus_state_path)?;
+        ctx.delete_update_meta_observed(client_id.clone(), height)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the oldest consensus states, along with their update metadata,
+/// until the number of consensus states stored for the client no longer
+/// exceeds the limit given by
+/// [`ExtClientValidationContext::max_consensus_states`]. This complements the
+/// expiry-based pruning of [`prune_oldest_consensus_state`].
+///
+/// The consensus state at the latest height of the client is never removed.
+pub fn prune_excess_consensus_states<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+{
+    let Some(max_consensus_states) = ctx.max_consensus_states() else {
+        return Ok(());
+    };
+
+    let mut heights = ctx.consensus_state_heights(client_id)?;
+
+    let excess = (heights.len() as u64).saturating_sub(max_consensus_states);
+
+    if excess == 0 {
+        return Ok(());
+    }
+
+    heights.sort();
+
+    for height in heights
+        .into_iter()
+        .filter(|height| *height != client_state.latest_height)
+        .take(excess as usize)
+    {
+        ctx.delete_consensus_state_observed(ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))?;
+        ctx.delete_update_meta_observed(client_id.clone(), height)?;
     }
 
     Ok(())
@@ -407,21 +463,21 @@ where
 
     let tm_consensus_state: ConsensusStateType = substitute_consensus_state.try_into()?;
 
-    ctx.store_consensus_state(
+    ctx.store_consensus_state_observed(
         ClientConsensusStatePath::new(
             subject_client_id.clone(),
             new_client_state.latest_height.revision_number(),
             new_client_state.latest_height.revision_height(),
         ),
-        tm_consensus_state.into(),
+        tm_consensus_state,
     )?;
 
-    ctx.store_client_state(
+    ctx.store_client_state_observed(
         ClientStatePath::new(subject_client_id.clone()),
-        new_client_state.into(),
+        new_client_state,
     )?;
 
-    ctx.store_update_meta(
+    ctx.store_update_meta_observed(
         subject_client_id.clone(),
         latest_height,
         host_timestamp,
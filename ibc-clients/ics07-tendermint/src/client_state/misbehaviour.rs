@@ -80,6 +80,11 @@ where
     )
 }
 
+/// Verifies that a misbehaviour header is signed by the validator set trusted
+/// at its trusted height.
+///
+/// A header that is not properly signed by that set is not evidence of
+/// misbehaviour, and is rejected with [`ClientError::InvalidMisbehaviour`].
 pub fn verify_misbehaviour_header<H>(
     header: &TmHeader,
     chain_id: &ChainId,
@@ -93,7 +98,11 @@ where
     H: MerkleHash + Sha256 + Default,
 {
     // ensure correctness of the trusted next validator set provided by the relayer
-    header.check_trusted_next_validator_set::<H>(&trusted_next_validator_hash)?;
+    header
+        .check_trusted_next_validator_set::<H>(&trusted_next_validator_hash)
+        .map_err(|e| ClientError::InvalidMisbehaviour {
+            reason: e.to_string(),
+        })?;
 
     // ensure trusted consensus state is within trusting period
     {
@@ -135,7 +144,10 @@ where
 
     verifier
         .verify_misbehaviour_header(untrusted_state, trusted_state, options, current_timestamp)
-        .into_result()?;
+        .into_result()
+        .map_err(|e| ClientError::InvalidMisbehaviour {
+            reason: e.to_string(),
+        })?;
 
     Ok(())
 }
@@ -1,17 +1,23 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::{
+    ConsensusState as ConsensusStateType, Header as TmHeader, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
 use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 use tendermint_light_client_verifier::Verifier;
 
+use super::ClientState;
+
 pub fn verify_header<V, H>(
     ctx: &V,
     header: &TmHeader,
@@ -26,6 +32,65 @@ where
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256 + Default,
 {
+    verify_header_with::<V, H>(ctx, header, chain_id, options, verifier, || {
+        let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.trusted_height.revision_number(),
+            header.trusted_height.revision_height(),
+        );
+
+        ctx.consensus_state(&trusted_client_cons_state_path)
+            .map_err(|_| ClientError::ConsensusStateNotFound {
+                client_id: client_id.clone(),
+                height: header.trusted_height,
+            })?
+            .try_into()
+            .map_err(Into::into)
+    })
+}
+
+/// Verifies `header` against the given consensus state at its trusted height,
+/// which does not need to be stored yet.
+///
+/// This allows verifying a header that builds upon the consensus state
+/// installed by another header, before the latter is applied.
+pub fn verify_header_against_trusted_state<V, H>(
+    ctx: &V,
+    header: &TmHeader,
+    trusted_consensus_state: ConsensusStateType,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    H: MerkleHash + Sha256 + Default,
+{
+    verify_header_with::<V, H>(ctx, header, chain_id, options, verifier, || {
+        Ok(trusted_consensus_state)
+    })
+}
+
+fn verify_header_with<V, H>(
+    ctx: &V,
+    header: &TmHeader,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+    load_trusted_consensus_state: impl FnOnce() -> Result<ConsensusStateType, ClientError>,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    H: MerkleHash + Sha256 + Default,
+{
+    // The header must build upon a trusted height strictly below its own.
+    if header.trusted_height >= header.height() {
+        return Err(ClientError::InvalidTrustedHeight {
+            trusted_height: header.trusted_height,
+            height: header.height(),
+        });
+    }
+
     // Checks that the header fields are valid.
     header.validate_basic::<H>()?;
 
@@ -37,16 +102,11 @@ where
     // of the new header against the trusted consensus state.
     {
         let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: ConsensusStateType = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(Into::into)?;
+            let trusted_consensus_state = load_trusted_consensus_state()?;
 
+            // The trusted validators must be checked against the consensus
+            // state at `header.trusted_height`, which is not necessarily the
+            // latest one for non-adjacent updates.
             header.check_trusted_next_validator_set::<H>(
                 &trusted_consensus_state.next_validators_hash,
             )?;
@@ -99,6 +159,74 @@ where
     Ok(())
 }
 
+/// Returns `true` if the consensus state derived from `header` is already
+/// stored at the header height, i.e. the header has been processed before.
+///
+/// Such a header carries no new information: [`update_state`] treats it as a
+/// no-op, so its verification can be skipped.
+///
+/// [`update_state`]: crate::client_state::update_state
+pub fn is_header_already_processed<V>(
+    ctx: &V,
+    header: &TmHeader,
+    client_id: &ClientId,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.height().revision_number(),
+        header.height().revision_height(),
+    );
+
+    let Ok(existing_consensus_state) = ctx.consensus_state(&path_at_header_height) else {
+        return Ok(false);
+    };
+
+    let existing_consensus_state: ConsensusStateType =
+        existing_consensus_state.try_into().map_err(Into::into)?;
+
+    Ok(existing_consensus_state.security_equals(&ConsensusStateType::from(header.clone())))
+}
+
+impl ClientState {
+    /// Returns whether updating the client with `client_message` would
+    /// advance it, i.e. whether the message is a header above the client's
+    /// latest height that was not processed yet.
+    ///
+    /// This only inspects the stored state and performs no verification, so
+    /// that relayers can cheaply skip redundant updates. A message for which
+    /// this returns `true` may still fail validation.
+    pub fn would_advance_client<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        match client_message.type_url.as_str() {
+            TENDERMINT_HEADER_TYPE_URL => {
+                let header = TmHeader::try_from(client_message)?;
+
+                if self.0.is_frozen() || header.height() <= self.0.latest_height {
+                    return Ok(false);
+                }
+
+                Ok(!is_header_already_processed(ctx, &header, client_id)?)
+            }
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => Ok(false),
+            _ => Err(ClientError::InvalidUpdateClientMessage),
+        }
+    }
+}
+
 /// Checks for misbehaviour upon receiving a new consensus state as part
 /// of a client update.
 pub fn check_for_misbehaviour_on_update<V>(
@@ -129,8 +257,8 @@ where
         let header_consensus_state = ConsensusStateType::from(header);
 
         // There is evidence of misbehaviour if the stored consensus state
-        // is different from the new one we received.
-        Ok(existing_consensus_state != header_consensus_state)
+        // differs from the new one we received in a security-relevant field.
+        Ok(!existing_consensus_state.security_equals(&header_consensus_state))
     } else {
         // If no header was previously installed, we ensure the monotonicity of timestamps.
 
@@ -5,7 +5,7 @@ use ibc_client_tendermint_types::{
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
-use ibc_core_client::types::Status;
+use ibc_core_client::types::{Height, Status};
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
@@ -16,7 +16,10 @@ use tendermint::merkle::MerkleHash;
 use tendermint_light_client_verifier::{ProdVerifier, Verifier};
 
 use super::{check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update, ClientState};
-use crate::client_state::{verify_header, verify_misbehaviour};
+use crate::client_state::{
+    is_header_already_processed, verify_header, verify_header_against_trusted_state,
+    verify_misbehaviour,
+};
 
 impl<V> ClientStateValidation<V> for ClientState
 where
@@ -58,6 +61,21 @@ where
         )
     }
 
+    fn verify_client_message_batch(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_messages: &[Any],
+    ) -> Result<(), ClientError> {
+        verify_client_message_batch::<V, Sha256>(
+            self.inner(),
+            ctx,
+            client_id,
+            client_messages,
+            &ProdVerifier::default(),
+        )
+    }
+
     fn check_for_misbehaviour(
         &self,
         ctx: &V,
@@ -67,10 +85,27 @@ where
         check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
     }
 
+    fn is_client_message_processed(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        is_client_message_processed(ctx, client_id, client_message)
+    }
+
+    fn header_height(&self, client_message: Any) -> Result<Option<Height>, ClientError> {
+        header_height(client_message)
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         status(self.inner(), ctx, client_id)
     }
 
+    fn misbehaviour_heights(&self, client_message: Any) -> Result<Vec<Height>, ClientError> {
+        misbehaviour_heights(client_message)
+    }
+
     fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
         check_substitute::<V>(self.inner(), substitute_client_state)
     }
@@ -101,6 +136,13 @@ where
     match client_message.type_url.as_str() {
         TENDERMINT_HEADER_TYPE_URL => {
             let header = TmHeader::try_from(client_message)?;
+
+            // Re-submitting an already processed header is a no-op, so we
+            // skip the costly verification.
+            if is_header_already_processed(ctx, &header, client_id)? {
+                return Ok(());
+            }
+
             verify_header::<V, H>(
                 ctx,
                 &header,
@@ -125,6 +167,77 @@ where
     }
 }
 
+/// Verify a batch of client messages to be applied in order as part of the
+/// client state validation process.
+///
+/// A header may trust the consensus state installed by an earlier header of
+/// the batch, in which case it is verified against that consensus state even
+/// though it is not stored yet. Any other message is verified against the
+/// stored state by [`verify_client_message`].
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function in
+/// order to make the ClientState APIs more flexible.
+pub fn verify_client_message_batch<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_messages: &[Any],
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: MerkleHash + Sha256Trait + Default,
+{
+    // Consensus states installed by the headers verified so far.
+    let mut pending_consensus_states: Vec<(Height, ConsensusStateType)> = Vec::new();
+
+    for client_message in client_messages {
+        if client_message.type_url != TENDERMINT_HEADER_TYPE_URL {
+            verify_client_message::<V, H>(
+                client_state,
+                ctx,
+                client_id,
+                client_message.clone(),
+                verifier,
+            )?;
+            continue;
+        }
+
+        let header = TmHeader::try_from(client_message.clone())?;
+
+        let pending_trusted_consensus_state = pending_consensus_states
+            .iter()
+            .rev()
+            .find(|(height, _)| *height == header.trusted_height)
+            .map(|(_, consensus_state)| consensus_state.clone());
+
+        match pending_trusted_consensus_state {
+            Some(trusted_consensus_state) => verify_header_against_trusted_state::<V, H>(
+                ctx,
+                &header,
+                trusted_consensus_state,
+                client_state.chain_id(),
+                &client_state.as_light_client_options()?,
+                verifier,
+            )?,
+            None => verify_client_message::<V, H>(
+                client_state,
+                ctx,
+                client_id,
+                client_message.clone(),
+                verifier,
+            )?,
+        }
+
+        pending_consensus_states.push((header.height(), ConsensusStateType::from(header)));
+    }
+
+    Ok(())
+}
+
 /// Check for misbehaviour on the client state as part of the client state
 /// validation process.
 ///
@@ -229,6 +342,79 @@ where
     Ok(Status::Active)
 }
 
+/// Returns `true` if the client message is a header whose consensus state is
+/// already stored at its height. Misbehaviour messages are never considered
+/// processed.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn is_client_message_processed<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            is_header_already_processed(ctx, &header, client_id)
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => Ok(false),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the height of the consensus state installed by a client message
+/// that is a header, or `None` for a misbehaviour message.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn header_height(client_message: Any) -> Result<Option<Height>, ClientError> {
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            Ok(Some(header.height()))
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => Ok(None),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the heights of the conflicting headers carried by a client message
+/// found to be evidence of misbehaviour.
+///
+/// A misbehaviour message yields the heights of both its headers, or a single
+/// height when they are equal. A header yields its own height, conflicting
+/// with the consensus state already stored by the client.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn misbehaviour_heights(client_message: Any) -> Result<Vec<Height>, ClientError> {
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            Ok(vec![header.height()])
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            let mut heights = vec![
+                misbehaviour.header1().height(),
+                misbehaviour.header2().height(),
+            ];
+            heights.dedup();
+            Ok(heights)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
 /// Check that the subject and substitute client states match as part of
 /// the client recovery validation step.
 ///
@@ -95,4 +95,8 @@ impl ConsensusStateTrait for ConsensusState {
     fn timestamp(&self) -> Timestamp {
         self.0.timestamp.into()
     }
+
+    fn security_equals(&self, other: &Self) -> bool {
+        self.0.security_equals(&other.0)
+    }
 }
@@ -42,6 +42,14 @@ impl ConsensusState {
     pub fn root(&self) -> CommitmentRoot {
         self.root.clone()
     }
+
+    /// Returns `true` if both consensus states share the same root,
+    /// timestamp and next validators hash.
+    pub fn security_equals(&self, other: &Self) -> bool {
+        self.root == other.root
+            && self.timestamp == other.timestamp
+            && self.next_validators_hash == other.next_validators_hash
+    }
 }
 
 impl Protobuf<RawConsensusState> for ConsensusState {}
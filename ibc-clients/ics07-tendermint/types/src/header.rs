@@ -107,13 +107,15 @@ impl Header {
         &self,
         trusted_next_validator_hash: &Hash,
     ) -> Result<(), ClientError> {
-        if &self.trusted_next_validator_set.hash_with::<H>() == trusted_next_validator_hash {
+        let trusted_validators_hash = self.trusted_next_validator_set.hash_with::<H>();
+
+        if &trusted_validators_hash == trusted_next_validator_hash {
             Ok(())
         } else {
-            Err(ClientError::HeaderVerificationFailure {
-                reason:
-                    "header trusted next validator set hash does not match hash stored on chain"
-                        .to_string(),
+            Err(ClientError::MisalignedTrustedValidators {
+                trusted_height: self.trusted_height,
+                expected: trusted_next_validator_hash.to_string(),
+                actual: trusted_validators_hash.to_string(),
             })
         }
     }
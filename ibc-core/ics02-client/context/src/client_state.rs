@@ -110,6 +110,24 @@ where
         client_message: Any,
     ) -> Result<(), ClientError>;
 
+    /// Verifies a batch of client messages to be applied in order, each on top
+    /// of the state resulting from the previous ones.
+    ///
+    /// By default, every message is verified against the stored state, so
+    /// messages can only build upon consensus states that are already stored.
+    /// Light clients may override this to let a message trust the consensus
+    /// state installed by an earlier message of the same batch.
+    fn verify_client_message_batch(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_messages: &[Any],
+    ) -> Result<(), ClientError> {
+        client_messages.iter().try_for_each(|client_message| {
+            self.verify_client_message(ctx, client_id, client_message.clone())
+        })
+    }
+
     /// Checks for evidence of a misbehaviour in Header or Misbehaviour type. It
     /// assumes the client_message has already been verified.
     fn check_for_misbehaviour(
@@ -119,9 +137,42 @@ where
         client_message: Any,
     ) -> Result<bool, ClientError>;
 
+    /// Returns `true` if the given client message is a header the client has
+    /// already processed, i.e. whose consensus state is already stored at its
+    /// height. Applying it again is a no-op, for which no event is emitted.
+    ///
+    /// Defaults to `false`, for light clients that do not detect them.
+    fn is_client_message_processed(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// Returns the height of the consensus state the given client message
+    /// installs if it is a header.
+    ///
+    /// Headers of a batch update that do not advance the client's latest
+    /// height are skipped based on it. Defaults to `None`, for light clients
+    /// that do not tell, in which case every header of a batch is applied.
+    fn header_height(&self, _client_message: Any) -> Result<Option<Height>, ClientError> {
+        Ok(None)
+    }
+
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
 
+    /// Returns the heights at which the given client message, found to be
+    /// evidence of misbehaviour, shows the counterparty misbehaving.
+    ///
+    /// These are reported in the `ClientMisbehaviour` event. Defaults to no
+    /// heights, for light clients whose evidence carries none.
+    fn misbehaviour_heights(&self, _client_message: Any) -> Result<Vec<Height>, ClientError> {
+        Ok(Vec::new())
+    }
+
     /// Verifies whether the calling (subject) client state matches the substitute
     /// client state for the purposes of client recovery.
     ///
@@ -17,4 +17,15 @@ pub trait ConsensusState: Send + Sync + Convertible<Any> {
 
     /// The timestamp of the consensus state
     fn timestamp(&self) -> Timestamp;
+
+    /// Returns `true` if `other` agrees with this consensus state on every
+    /// security-relevant field.
+    ///
+    /// Used to decide whether a second consensus state at an already
+    /// installed height is a duplicate or evidence of misbehaviour. Fields
+    /// that carry no security guarantees must not take part in the
+    /// comparison. Defaults to comparing the root and the timestamp.
+    fn security_equals(&self, other: &Self) -> bool {
+        self.root() == other.root() && self.timestamp() == other.timestamp()
+    }
 }
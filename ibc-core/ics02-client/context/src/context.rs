@@ -1,9 +1,12 @@
 use ibc_core_client_types::Height;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host_types::identifiers::ClientId;
-use ibc_core_host_types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core_host_types::path::{
+    ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
+};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::proto::Any;
+use ibc_primitives::{Timestamp, ToVec};
 
 use crate::client_state::{ClientStateExecution, ClientStateValidation};
 use crate::consensus_state::ConsensusState;
@@ -102,6 +105,124 @@ pub trait ClientExecutionContext:
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Called right after a light client writes `value` under `path`
+    /// through [`ObservedClientExecutionContext`], allowing hosts that
+    /// maintain their own commitment tree to mirror the write.
+    ///
+    /// `value` is encoded as ibc-go commits it:
+    /// - client and consensus states are protobuf-encoded `Any`s;
+    /// - update times are big-endian `u64` nanoseconds;
+    /// - update heights are the string representation of the height.
+    fn on_store_write(&mut self, _path: &Path, _value: &[u8]) {}
+
+    /// Called right after a light client deletes the value under `path`
+    /// through [`ObservedClientExecutionContext`].
+    fn on_store_delete(&mut self, _path: &Path) {}
+}
+
+/// Store methods of [`ClientExecutionContext`] that report every write to
+/// [`ClientExecutionContext::on_store_write`] and every deletion to
+/// [`ClientExecutionContext::on_store_delete`].
+///
+/// Light clients are expected to mutate the store through these methods only.
+/// The trait is implemented for all client execution contexts, so the
+/// reporting cannot be bypassed by overriding.
+pub trait ObservedClientExecutionContext: ClientExecutionContext {
+    /// Stores the client state and reports the write.
+    fn store_client_state_observed<C>(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: C,
+    ) -> Result<(), ContextError>
+    where
+        C: Clone + Into<Self::ClientStateRef> + Into<Any>,
+    {
+        let value = Into::<Any>::into(client_state.clone()).to_vec();
+        self.store_client_state(client_state_path.clone(), client_state.into())?;
+        self.on_store_write(&client_state_path.into(), &value);
+        Ok(())
+    }
+
+    /// Stores the consensus state and reports the write.
+    fn store_consensus_state_observed<C>(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: C,
+    ) -> Result<(), ContextError>
+    where
+        C: Clone + Into<Self::ConsensusStateRef> + Into<Any>,
+    {
+        let value = Into::<Any>::into(consensus_state.clone()).to_vec();
+        self.store_consensus_state(consensus_state_path.clone(), consensus_state.into())?;
+        self.on_store_write(&consensus_state_path.into(), &value);
+        Ok(())
+    }
+
+    /// Deletes the consensus state and reports the deletion.
+    fn delete_consensus_state_observed(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        self.delete_consensus_state(consensus_state_path.clone())?;
+        self.on_store_delete(&consensus_state_path.into());
+        Ok(())
+    }
+
+    /// Stores the update time and height and reports both writes.
+    fn store_update_meta_observed(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.store_update_meta(client_id.clone(), height, host_timestamp, host_height)?;
+
+        let (time_path, height_path) = update_meta_paths(client_id, &height);
+        self.on_store_write(
+            &time_path.into(),
+            &host_timestamp.nanoseconds().to_be_bytes(),
+        );
+        self.on_store_write(&height_path.into(), host_height.to_string().as_bytes());
+
+        Ok(())
+    }
+
+    /// Deletes the update time and height and reports both deletions.
+    fn delete_update_meta_observed(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.delete_update_meta(client_id.clone(), height)?;
+
+        let (time_path, height_path) = update_meta_paths(client_id, &height);
+        self.on_store_delete(&time_path.into());
+        self.on_store_delete(&height_path.into());
+
+        Ok(())
+    }
+}
+
+impl<T> ObservedClientExecutionContext for T where T: ClientExecutionContext {}
+
+fn update_meta_paths(
+    client_id: ClientId,
+    height: &Height,
+) -> (ClientUpdateTimePath, ClientUpdateHeightPath) {
+    (
+        ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ),
+        ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        ),
+    )
 }
 
 /// An optional trait that extends the client validation context capabilities by
@@ -123,6 +244,28 @@ pub trait ExtClientValidationContext: ClientValidationContext {
     /// Returns all the heights at which a consensus state is stored.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns the maximum number of consensus states a client is allowed to
+    /// keep in the store. Once exceeded, the oldest consensus states are
+    /// evicted during client updates.
+    ///
+    /// Defaults to `None`, meaning no limit is enforced and consensus states
+    /// are only pruned upon expiry.
+    fn max_consensus_states(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the highest height at which a consensus state is stored, if
+    /// any.
+    ///
+    /// Heights are compared by revision number first, so after a chain
+    /// upgrade the latest height belongs to the new revision.
+    fn latest_consensus_state_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(self.consensus_state_heights(client_id)?.into_iter().max())
+    }
+
     /// Search for the lowest consensus state higher than `height`.
     fn next_consensus_state(
         &self,
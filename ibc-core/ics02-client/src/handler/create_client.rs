@@ -6,7 +6,10 @@ use ibc_core_client_types::events::CreateClient;
 use ibc_core_client_types::msgs::MsgCreateClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::{ClientStateMut, ClientStateRef, ExecutionContext, ValidationContext};
+use ibc_core_handler_types::msgs::MsgKind;
+use ibc_core_host::{
+    ClientStateMut, ClientStateRef, ExecutionContext, ObservedExecutionContext, ValidationContext,
+};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
@@ -23,6 +26,16 @@ where
 
     ctx.validate_message_signer(&signer)?;
 
+    ctx.authorize(&signer, &MsgKind::CreateClient)?;
+
+    // An identical client already exists and its identifier will be reused.
+    if ctx
+        .find_matching_client(&client_state, &consensus_state)?
+        .is_some()
+    {
+        return Ok(());
+    }
+
     // Construct this client's identifier
     let id_counter = ctx.client_counter()?;
 
@@ -61,19 +74,37 @@ where
         signer: _,
     } = msg;
 
-    // Construct this client's identifier
-    let id_counter = ctx.client_counter()?;
-
-    let client_exec_ctx = ctx.get_client_execution_context();
+    let maybe_existing_client_id = ctx.find_matching_client(&client_state, &consensus_state)?;
 
     let client_state = ClientStateMut::<Ctx>::try_from(client_state).map_err(Into::into)?;
 
     let client_type = client_state.client_type();
-    let client_id = client_type.build_client_id(id_counter);
 
-    client_state.initialise(client_exec_ctx, &client_id, consensus_state)?;
+    let client_id = if let Some(client_id) = maybe_existing_client_id {
+        ctx.log_message(format!(
+            "success: reused existing client identifier: {client_id}"
+        ))?;
+
+        client_id
+    } else {
+        // Construct this client's identifier
+        let id_counter = ctx.client_counter()?;
+        let client_id = client_type.build_client_id(id_counter);
 
-    ctx.increase_client_counter()?;
+        client_state.initialise(
+            ctx.get_client_execution_context(),
+            &client_id,
+            consensus_state,
+        )?;
+
+        ctx.increase_client_counter_observed()?;
+
+        ctx.log_message(format!(
+            "success: generated new client identifier: {client_id}"
+        ))?;
+
+        client_id
+    };
 
     let event = IbcEvent::CreateClient(CreateClient::new(
         client_id.clone(),
@@ -83,9 +114,5 @@ where
     ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
     ctx.emit_ibc_event(event)?;
 
-    ctx.log_message(format!(
-        "success: generated new client identifier: {client_id}"
-    ))?;
-
     Ok(())
 }
@@ -4,6 +4,7 @@ use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::msgs::MsgRecoverClient;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 
@@ -21,6 +22,8 @@ where
 
     ctx.validate_message_signer(&signer)?;
 
+    ctx.authorize(&signer, &MsgKind::RecoverClient)?;
+
     let client_val_ctx = ctx.get_client_validation_context();
 
     let subject_client_state = client_val_ctx.client_state(&subject_client_id)?;
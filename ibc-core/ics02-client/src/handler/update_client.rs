@@ -3,10 +3,11 @@
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::events::{ClientMisbehaviour, UpdateClient};
-use ibc_core_client_types::msgs::MsgUpdateOrMisbehaviour;
+use ibc_core_client_types::msgs::{MsgUpdateClientBatch, MsgUpdateOrMisbehaviour};
 use ibc_core_client_types::UpdateKind;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::ToVec;
@@ -17,6 +18,12 @@ where
 {
     ctx.validate_message_signer(msg.signer())?;
 
+    let msg_kind = match msg {
+        MsgUpdateOrMisbehaviour::UpdateClient(_) => MsgKind::UpdateClient,
+        MsgUpdateOrMisbehaviour::Misbehaviour(_) => MsgKind::SubmitMisbehaviour,
+    };
+    ctx.authorize(msg.signer(), &msg_kind)?;
+
     let client_id = msg.client_id().clone();
 
     let client_val_ctx = ctx.get_client_validation_context();
@@ -54,11 +61,14 @@ where
         client_state.check_for_misbehaviour(client_exec_ctx, &client_id, client_message.clone())?;
 
     if found_misbehaviour {
+        let misbehaviour_heights = client_state.misbehaviour_heights(client_message.clone())?;
+
         client_state.update_state_on_misbehaviour(client_exec_ctx, &client_id, client_message)?;
 
         let event = IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
             client_id,
             client_state.client_type(),
+            misbehaviour_heights,
         ));
         ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
         ctx.emit_ibc_event(event)?;
@@ -72,6 +82,12 @@ where
 
         let header = client_message;
 
+        // Re-submitting an already processed header is a no-op, which is not
+        // reported by a redundant event.
+        if client_state.is_client_message_processed(client_exec_ctx, &client_id, header.clone())? {
+            return Ok(());
+        }
+
         let consensus_heights =
             client_state.update_state(client_exec_ctx, &client_id, header.clone())?;
 
@@ -96,3 +112,123 @@ where
 
     Ok(())
 }
+
+pub fn validate_batch<Ctx>(ctx: &Ctx, msg: &MsgUpdateClientBatch) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(&msg.signer)?;
+
+    ctx.authorize(&msg.signer, &MsgKind::UpdateClient)?;
+
+    if msg.client_messages.is_empty() {
+        return Err(ClientError::MissingClientMessage.into());
+    }
+
+    let client_val_ctx = ctx.get_client_validation_context();
+
+    let client_state = client_val_ctx.client_state(&msg.client_id)?;
+
+    client_state
+        .status(client_val_ctx, &msg.client_id)?
+        .verify_is_active()?;
+
+    // Every header is verified here, each one on top of the previous ones,
+    // so that the execution applies the whole batch or nothing.
+    client_state.verify_client_message_batch(
+        client_val_ctx,
+        &msg.client_id,
+        &msg.client_messages,
+    )?;
+
+    Ok(())
+}
+
+/// Applies every header of the batch in order.
+///
+/// All the headers were verified by [`validate_batch`], so only storage
+/// errors can make this fail. If a header turns out to be evidence of
+/// misbehaviour, the client is frozen and the remaining headers are dropped.
+/// Headers that do not advance the client's latest height are skipped,
+/// without any write. A single `UpdateClient` event reports every height
+/// applied; none is emitted if no header was applied.
+pub fn execute_batch<Ctx>(ctx: &mut Ctx, msg: MsgUpdateClientBatch) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let client_id = msg.client_id;
+
+    let client_state = ctx
+        .get_client_execution_context()
+        .client_state(&client_id)?;
+    let client_type = client_state.client_type();
+    let mut latest_height = client_state.latest_height();
+    let mut consensus_heights = Vec::new();
+    let mut last_header = None;
+    let mut misbehaviour_event = None;
+
+    for header in msg.client_messages {
+        let client_exec_ctx = ctx.get_client_execution_context();
+
+        let client_state = client_exec_ctx.client_state(&client_id)?;
+
+        let found_misbehaviour =
+            client_state.check_for_misbehaviour(client_exec_ctx, &client_id, header.clone())?;
+
+        if found_misbehaviour {
+            let misbehaviour_heights = client_state.misbehaviour_heights(header.clone())?;
+
+            client_state.update_state_on_misbehaviour(client_exec_ctx, &client_id, header)?;
+
+            misbehaviour_event = Some(IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(
+                client_id.clone(),
+                client_type.clone(),
+                misbehaviour_heights,
+            )));
+
+            break;
+        }
+
+        if client_state
+            .header_height(header.clone())?
+            .is_some_and(|height| height <= latest_height)
+        {
+            continue;
+        }
+
+        let heights = client_state.update_state(client_exec_ctx, &client_id, header.clone())?;
+
+        // Light clients that do not report header heights may have applied a
+        // header relayed twice within the batch, which is reported once.
+        for height in heights {
+            if !consensus_heights.contains(&height) {
+                consensus_heights.push(height);
+            }
+        }
+        latest_height = client_exec_ctx.client_state(&client_id)?.latest_height();
+        last_header = Some(header);
+    }
+
+    if let Some(header) = last_header {
+        let consensus_height = *consensus_heights.first().ok_or(ClientError::Other {
+            description: "client update state returned no updated height".to_string(),
+        })?;
+
+        let event = IbcEvent::UpdateClient(UpdateClient::new(
+            client_id,
+            client_type,
+            consensus_height,
+            consensus_heights,
+            header.to_vec(),
+        ));
+        ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+        ctx.emit_ibc_event(event)?;
+    }
+
+    if let Some(event) = misbehaviour_event {
+        ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+        ctx.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
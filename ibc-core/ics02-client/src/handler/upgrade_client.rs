@@ -6,6 +6,7 @@ use ibc_core_client_types::events::UpgradeClient;
 use ibc_core_client_types::msgs::MsgUpgradeClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
@@ -20,6 +21,8 @@ where
 
     ctx.validate_message_signer(&signer)?;
 
+    ctx.authorize(&signer, &MsgKind::UpgradeClient)?;
+
     let client_val_ctx = ctx.get_client_validation_context();
 
     // Read the current latest client state from the host chain store.
@@ -40,6 +40,17 @@ pub enum ClientError {
     UpdateMetaDataNotFound { client_id: ClientId, height: Height },
     /// header verification failed with reason: `{reason}`
     HeaderVerificationFailure { reason: String },
+    /// header trusted validators hash `{actual}` does not match the next validators hash `{expected}` stored at trusted height `{trusted_height}`
+    MisalignedTrustedValidators {
+        trusted_height: Height,
+        expected: String,
+        actual: String,
+    },
+    /// header trusted height `{trusted_height}` is not below the header height `{height}`
+    InvalidTrustedHeight {
+        trusted_height: Height,
+        height: Height,
+    },
     /// failed to build trust threshold from fraction: `{numerator}`/`{denominator}`
     InvalidTrustThreshold { numerator: u64, denominator: u64 },
     /// failed to build Tendermint domain type trust threshold from fraction: `{numerator}`/`{denominator}`
@@ -76,11 +87,8 @@ pub enum ClientError {
     InvalidHeight,
     /// height cannot end up zero or negative
     InvalidHeightResult,
-    /// the proof height is insufficient: latest_height=`{latest_height}` proof_height=`{proof_height}`
-    InvalidProofHeight {
-        latest_height: Height,
-        proof_height: Height,
-    },
+    /// proof height `{got}` is above the client's latest height `{latest}`; update the client first
+    HeightTooNew { got: Height, latest: Height },
     /// invalid commitment proof bytes error: `{0}`
     InvalidCommitmentProof(CommitmentError),
     /// invalid packet timeout timestamp value error: `{0}`
@@ -97,6 +105,8 @@ pub enum ClientError {
     Ics23Verification(CommitmentError),
     /// misbehaviour handling failed with reason: `{reason}`
     MisbehaviourHandlingFailure { reason: String },
+    /// invalid misbehaviour: `{reason}`
+    InvalidMisbehaviour { reason: String },
     /// client specific error: `{description}`
     ClientSpecific { description: String },
     /// client counter overflow error
@@ -12,6 +12,7 @@ pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+pub const CLIENT_EXPIRED_EVENT: &str = "client_expired";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
@@ -25,6 +26,9 @@ pub const CONSENSUS_HEIGHT_ATTRIBUTE_KEY: &str = "consensus_height";
 /// The content of the `key` field for the attribute containing the heights of consensus states that were processed.
 pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
 
+/// The content of the `key` field for the attribute containing the heights at which misbehaviour occurred.
+pub const MISBEHAVIOUR_HEIGHTS_ATTRIBUTE_KEY: &str = "misbehaviour_heights";
+
 /// The content of the `key` field for the header in update client event.
 pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
@@ -129,6 +133,39 @@ impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct MisbehaviourHeightsAttribute {
+    misbehaviour_heights: Vec<Height>,
+}
+
+impl From<MisbehaviourHeightsAttribute> for abci::EventAttribute {
+    fn from(attr: MisbehaviourHeightsAttribute) -> Self {
+        let misbehaviour_heights: Vec<String> = attr
+            .misbehaviour_heights
+            .into_iter()
+            .map(|misbehaviour_height| misbehaviour_height.to_string())
+            .collect();
+        (
+            MISBEHAVIOUR_HEIGHTS_ATTRIBUTE_KEY,
+            misbehaviour_heights.join(","),
+        )
+            .into()
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -325,13 +362,19 @@ impl From<UpdateClient> for abci::Event {
 pub struct ClientMisbehaviour {
     client_id: ClientIdAttribute,
     client_type: ClientTypeAttribute,
+    misbehaviour_heights: MisbehaviourHeightsAttribute,
 }
 
 impl ClientMisbehaviour {
-    pub fn new(client_id: ClientId, client_type: ClientType) -> Self {
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        misbehaviour_heights: Vec<Height>,
+    ) -> Self {
         Self {
             client_id: ClientIdAttribute::from(client_id),
             client_type: ClientTypeAttribute::from(client_type),
+            misbehaviour_heights: MisbehaviourHeightsAttribute::from(misbehaviour_heights),
         }
     }
 
@@ -343,6 +386,10 @@ impl ClientMisbehaviour {
         &self.client_type.client_type
     }
 
+    pub fn misbehaviour_heights(&self) -> &[Height] {
+        self.misbehaviour_heights.misbehaviour_heights.as_ref()
+    }
+
     pub fn event_type(&self) -> &str {
         CLIENT_MISBEHAVIOUR_EVENT
     }
@@ -352,7 +399,11 @@ impl From<ClientMisbehaviour> for abci::Event {
     fn from(c: ClientMisbehaviour) -> Self {
         Self {
             kind: CLIENT_MISBEHAVIOUR_EVENT.to_owned(),
-            attributes: vec![c.client_id.into(), c.client_type.into()],
+            attributes: vec![
+                c.client_id.into(),
+                c.client_type.into(),
+                c.misbehaviour_heights.into(),
+            ],
         }
     }
 }
@@ -416,3 +467,54 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+/// Signals that an on-chain client (IBC Client) has expired, i.e. its trusting
+/// period elapsed without an update.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientExpired {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl ClientExpired {
+    pub fn new(client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_EXPIRED_EVENT
+    }
+}
+
+impl From<ClientExpired> for abci::Event {
+    fn from(c: ClientExpired) -> Self {
+        Self {
+            kind: CLIENT_EXPIRED_EVENT.to_owned(),
+            attributes: vec![c.client_id.into(), c.client_type.into()],
+        }
+    }
+}
@@ -30,6 +30,7 @@ pub use upgrade_client::*;
 pub enum ClientMsg {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
+    UpdateClientBatch(MsgUpdateClientBatch),
     Misbehaviour(MsgSubmitMisbehaviour),
     UpgradeClient(MsgUpgradeClient),
     RecoverClient(MsgRecoverClient),
@@ -55,3 +55,23 @@ impl From<MsgUpdateClient> for RawMsgUpdateClient {
         }
     }
 }
+
+/// Represents the message that updates an on-chain (IBC) client with a batch
+/// of headers, applied in order.
+///
+/// This is a domain-only message: it has no protobuf counterpart and is meant
+/// for hosts that relay several headers at once. Each client message is
+/// verified against the state left by the previous one, and only those
+/// advancing the latest height of the client are reported in the emitted
+/// `UpdateClient` event.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateClientBatch {
+    pub client_id: ClientId,
+    pub client_messages: Vec<Any>,
+    pub signer: Signer,
+}
@@ -24,7 +24,7 @@ where
         .client_update_meta(client_id, &packet_proof_height)?;
 
     // Fetch the connection delay time and height periods.
-    let conn_delay_time_period = connection_end.delay_period();
+    let conn_delay_time_period = connection_end.delay_period_time();
     let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period);
 
     // Verify that the current host chain time is later than the last client update time
@@ -52,3 +52,40 @@ where
 
     Ok(())
 }
+
+/// Verifies that `proof_height` does not lag `latest_height`, the latest
+/// height of the client verifying the proof, by more than the host's
+/// [`ValidationContext::max_proof_staleness`].
+///
+/// A proof from an earlier revision than the client's latest height is always
+/// considered stale when a bound is set.
+pub fn verify_proof_not_stale<Ctx>(
+    ctx: &Ctx,
+    proof_height: Height,
+    latest_height: Height,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let Some(max_staleness) = ctx.max_proof_staleness() else {
+        return Ok(());
+    };
+
+    let staleness = if proof_height.revision_number() == latest_height.revision_number() {
+        latest_height
+            .revision_height()
+            .saturating_sub(proof_height.revision_height())
+    } else {
+        u64::MAX
+    };
+
+    if staleness > max_staleness {
+        return Err(ContextError::ConnectionError(ConnectionError::StaleProof {
+            proof_height,
+            latest_height,
+            max_staleness,
+        }));
+    }
+
+    Ok(())
+}
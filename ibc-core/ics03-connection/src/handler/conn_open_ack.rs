@@ -5,16 +5,19 @@ use ibc_core_client::types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenAck;
 use ibc_core_connection_types::msgs::MsgConnectionOpenAck;
-use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::{ConnectionEnd, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_primitives::ToVec;
 
+use crate::delay::verify_proof_not_stale;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -34,6 +37,7 @@ where
     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::ConnectionOpenAck)?;
 
     let host_height = ctx_a.host_height().map_err(|_| ConnectionError::Other {
         description: "failed to get host height".to_string(),
@@ -66,6 +70,11 @@ where
             .status(client_val_ctx_a, vars.client_id_on_a())?
             .verify_is_active()?;
         client_state_of_b_on_a.validate_proof_height(msg.proofs_height_on_b)?;
+        verify_proof_not_stale(
+            ctx_a,
+            msg.proofs_height_on_b,
+            client_state_of_b_on_a.latest_height(),
+        )?;
 
         let client_cons_state_path_on_a = ClientConsensusStatePath::new(
             vars.client_id_on_a().clone(),
@@ -80,16 +89,11 @@ where
         let prefix_on_b = vars.conn_end_on_a.counterparty().prefix();
 
         {
-            let expected_conn_end_on_b = ConnectionEnd::new(
+            let expected_conn_end_on_b = vars.conn_end_on_a.expected_counterparty(
+                Some(msg.conn_id_on_a.clone()),
+                prefix_on_a,
                 State::TryOpen,
-                vars.client_id_on_b().clone(),
-                Counterparty::new(
-                    vars.client_id_on_a().clone(),
-                    Some(msg.conn_id_on_a.clone()),
-                    prefix_on_a,
-                ),
                 vec![msg.version.clone()],
-                vars.conn_end_on_a.delay_period(),
             )?;
 
             client_state_of_b_on_a
@@ -181,7 +185,8 @@ where
             new_conn_end_on_a
         };
 
-        ctx_a.store_connection(&ConnectionPath::new(&msg.conn_id_on_a), new_conn_end_on_a)?;
+        let conn_path_on_a = ConnectionPath::new(&msg.conn_id_on_a);
+        ctx_a.store_connection_observed(&conn_path_on_a, new_conn_end_on_a)?;
     }
 
     Ok(())
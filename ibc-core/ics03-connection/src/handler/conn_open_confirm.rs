@@ -4,12 +4,13 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenConfirm;
 use ibc_core_connection_types::msgs::MsgConnectionOpenConfirm;
-use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::{ConnectionEnd, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
@@ -30,12 +31,12 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::ConnectionOpenConfirm)?;
 
     let conn_end_on_b = vars.conn_end_on_b();
 
     conn_end_on_b.verify_state_matches(&State::TryOpen)?;
 
-    let client_id_on_a = vars.client_id_on_a();
     let client_id_on_b = vars.client_id_on_b();
     let conn_id_on_a = vars.conn_id_on_a()?;
 
@@ -61,16 +62,11 @@ where
         let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
 
-        let expected_conn_end_on_a = ConnectionEnd::new(
+        let expected_conn_end_on_a = conn_end_on_b.expected_counterparty(
+            Some(msg.conn_id_on_b.clone()),
+            prefix_on_b,
             State::Open,
-            client_id_on_a.clone(),
-            Counterparty::new(
-                client_id_on_b.clone(),
-                Some(msg.conn_id_on_b.clone()),
-                prefix_on_b,
-            ),
             conn_end_on_b.versions().to_vec(),
-            conn_end_on_b.delay_period(),
         )?;
 
         client_state_of_a_on_b
@@ -125,7 +121,8 @@ where
             new_conn_end_on_b
         };
 
-        ctx_b.store_connection(&ConnectionPath(msg.conn_id_on_b.clone()), new_conn_end_on_b)?;
+        let conn_path_on_b = ConnectionPath(msg.conn_id_on_b.clone());
+        ctx_b.store_connection_observed(&conn_path_on_b, new_conn_end_on_b)?;
     }
 
     Ok(())
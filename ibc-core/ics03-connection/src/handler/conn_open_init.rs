@@ -1,13 +1,15 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
 use ibc_core_client::context::prelude::*;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
 use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::ConnectionId;
 use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
@@ -15,6 +17,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::ConnectionOpenInit)?;
 
     let client_val_ctx_a = ctx_a.get_client_validation_context();
 
@@ -29,6 +32,15 @@ where
         version.verify_is_supported(&ctx_a.get_compatible_versions())?;
     }
 
+    let min_delay_period = ctx_a.min_connection_delay();
+    if msg.delay_period < min_delay_period {
+        return Err(ConnectionError::DelayPeriodTooShort {
+            delay_period: msg.delay_period,
+            min_delay_period,
+        }
+        .into());
+    }
+
     Ok(())
 }
 
@@ -74,12 +86,12 @@ where
         ctx_a.emit_ibc_event(event)?;
     }
 
-    ctx_a.increase_connection_counter()?;
-    ctx_a.store_connection_to_client(
-        &ClientConnectionPath::new(msg.client_id_on_a),
-        conn_id_on_a.clone(),
-    )?;
-    ctx_a.store_connection(&ConnectionPath::new(&conn_id_on_a), conn_end_on_a)?;
+    ctx_a.increase_connection_counter_observed()?;
+    let client_conn_path_on_a = ClientConnectionPath::new(msg.client_id_on_a);
+    ctx_a.store_connection_to_client_observed(&client_conn_path_on_a, conn_id_on_a.clone())?;
+
+    let conn_path_on_a = ConnectionPath::new(&conn_id_on_a);
+    ctx_a.store_connection_observed(&conn_path_on_a, conn_end_on_a)?;
 
     Ok(())
 }
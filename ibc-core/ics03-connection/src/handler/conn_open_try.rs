@@ -4,18 +4,21 @@ use ibc_core_client::types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenTry;
 use ibc_core_connection_types::msgs::MsgConnectionOpenTry;
-use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_connection_types::{ConnectionEnd, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_primitives::ToVec;
 
+use crate::delay::verify_proof_not_stale;
+
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -35,6 +38,7 @@ where
     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::ConnectionOpenTry)?;
 
     let client_val_ctx_b = ctx_b.get_client_validation_context();
 
@@ -43,6 +47,15 @@ where
 
     ctx_b.validate_self_client(client_state_of_b_on_a)?;
 
+    let min_delay_period = ctx_b.min_connection_delay();
+    if msg.delay_period < min_delay_period {
+        return Err(ConnectionError::DelayPeriodTooShort {
+            delay_period: msg.delay_period,
+            min_delay_period,
+        }
+        .into());
+    }
+
     let host_height = ctx_b.host_height().map_err(|_| ConnectionError::Other {
         description: "failed to get host height".to_string(),
     })?;
@@ -66,6 +79,11 @@ where
             .status(client_val_ctx_b, &msg.client_id_on_b)?
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proofs_height_on_a)?;
+        verify_proof_not_stale(
+            ctx_b,
+            msg.proofs_height_on_a,
+            client_state_of_a_on_b.latest_height(),
+        )?;
 
         let client_cons_state_path_on_b = ClientConsensusStatePath::new(
             msg.client_id_on_b.clone(),
@@ -80,12 +98,11 @@ where
         let prefix_on_b = ctx_b.commitment_prefix();
 
         {
-            let expected_conn_end_on_a = ConnectionEnd::new(
+            let expected_conn_end_on_a = vars.conn_end_on_b.expected_counterparty(
+                None,
+                prefix_on_b,
                 State::Init,
-                client_id_on_a.clone(),
-                Counterparty::new(msg.client_id_on_b.clone(), None, prefix_on_b),
                 msg.versions_on_a.clone(),
-                msg.delay_period,
             )?;
 
             client_state_of_a_on_b
@@ -169,12 +186,12 @@ where
     ctx_b.emit_ibc_event(event)?;
     ctx_b.log_message("success: conn_open_try verification passed".to_string())?;
 
-    ctx_b.increase_connection_counter()?;
-    ctx_b.store_connection_to_client(
-        &ClientConnectionPath::new(msg.client_id_on_b),
-        vars.conn_id_on_b.clone(),
-    )?;
-    ctx_b.store_connection(&ConnectionPath::new(&vars.conn_id_on_b), vars.conn_end_on_b)?;
+    ctx_b.increase_connection_counter_observed()?;
+    let client_conn_path_on_b = ClientConnectionPath::new(msg.client_id_on_b);
+    ctx_b.store_connection_to_client_observed(&client_conn_path_on_b, vars.conn_id_on_b.clone())?;
+
+    let conn_path_on_b = ConnectionPath::new(&vars.conn_id_on_b);
+    ctx_b.store_connection_observed(&conn_path_on_b, vars.conn_end_on_b)?;
 
     Ok(())
 }
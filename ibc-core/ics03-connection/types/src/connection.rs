@@ -347,6 +347,69 @@ impl ConnectionEnd {
     pub fn delay_period(&self) -> Duration {
         self.delay_period
     }
+
+    /// Returns the time component of the delay period, i.e. the duration that
+    /// must elapse on the host after a client update before a packet proven
+    /// against it can be processed.
+    pub fn delay_period_time(&self) -> Duration {
+        self.delay_period
+    }
+
+    /// Returns the block component of the delay period, i.e. the number of
+    /// blocks that must be produced on the host after a client update before
+    /// a packet proven against it can be processed.
+    ///
+    /// It is derived from the time component as
+    /// `ceil(delay_period_time / max_expected_time_per_block)`.
+    pub fn delay_period_blocks(&self, max_expected_time_per_block: Duration) -> u64 {
+        calculate_block_delay(&self.delay_period, &max_expected_time_per_block)
+    }
+
+    /// Reconstructs the connection end that the counterparty is expected to
+    /// have committed, as seen from this end.
+    ///
+    /// The client identifiers are swapped and the delay period is carried
+    /// over. `conn_id` is the identifier of this end on the counterparty
+    /// (`None` while the counterparty is in `Init`), `prefix` is the
+    /// commitment prefix of the host of this end, and `state` and `versions`
+    /// are the ones the counterparty is expected to be in.
+    pub fn expected_counterparty(
+        &self,
+        conn_id: Option<ConnectionId>,
+        prefix: CommitmentPrefix,
+        state: State,
+        versions: Vec<Version>,
+    ) -> Result<ConnectionEnd, ConnectionError> {
+        ConnectionEnd::new(
+            state,
+            self.counterparty.client_id().clone(),
+            Counterparty::new(self.client_id.clone(), conn_id, prefix),
+            versions,
+            self.delay_period,
+        )
+    }
+}
+
+/// Calculates the number of blocks, `ceil(delay_period_time /
+/// max_expected_time_per_block)`, spanning the given delay period.
+///
+/// Both durations are taken at the granularity of seconds. Returns `0` if
+/// `max_expected_time_per_block` is under a second.
+pub fn calculate_block_delay(
+    delay_period_time: &Duration,
+    max_expected_time_per_block: &Duration,
+) -> u64 {
+    let delay_period_time = delay_period_time.as_secs();
+    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
+    if max_expected_time_per_block == 0 {
+        return 0;
+    }
+    if delay_period_time % max_expected_time_per_block == 0 {
+        return delay_period_time / max_expected_time_per_block;
+    }
+
+    // TODO: Use `u64::div_ceil` here instead
+    (delay_period_time / max_expected_time_per_block) + 1
 }
 
 #[cfg_attr(
@@ -548,3 +611,61 @@ impl From<State> for i32 {
         value as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_counterparty_matches_committed_end() {
+        let client_id_on_a = ClientId::new("07-tendermint", 0).unwrap();
+        let client_id_on_b = ClientId::new("07-tendermint", 1).unwrap();
+        let conn_id_on_a = ConnectionId::new(0);
+        let prefix_on_a = CommitmentPrefix::try_from(b"ibc_a".to_vec()).unwrap();
+        let prefix_on_b = CommitmentPrefix::try_from(b"ibc_b".to_vec()).unwrap();
+        let delay_period = Duration::from_secs(10);
+        let versions = Version::compatibles();
+
+        // What A commits on `ConnOpenInit`.
+        let conn_end_on_a = ConnectionEnd::new(
+            State::Init,
+            client_id_on_a.clone(),
+            Counterparty::new(client_id_on_b.clone(), None, prefix_on_b.clone()),
+            versions.clone(),
+            delay_period,
+        )
+        .unwrap();
+
+        // What B commits on `ConnOpenTry`.
+        let conn_end_on_b = ConnectionEnd::new(
+            State::TryOpen,
+            client_id_on_b,
+            Counterparty::new(
+                client_id_on_a,
+                Some(conn_id_on_a.clone()),
+                prefix_on_a.clone(),
+            ),
+            versions[..1].to_vec(),
+            delay_period,
+        )
+        .unwrap();
+
+        assert_eq!(
+            conn_end_on_b
+                .expected_counterparty(None, prefix_on_b, State::Init, versions)
+                .unwrap(),
+            conn_end_on_a
+        );
+        assert_eq!(
+            conn_end_on_a
+                .expected_counterparty(
+                    Some(conn_id_on_a),
+                    prefix_on_a,
+                    State::TryOpen,
+                    conn_end_on_b.versions().to_vec(),
+                )
+                .unwrap(),
+            conn_end_on_b
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Defines the connection error type
 
+use core::time::Duration;
+
 use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
@@ -52,6 +54,11 @@ pub enum ConnectionError {
     ConnectionNotFound { connection_id: ConnectionId },
     /// invalid counterparty
     InvalidCounterparty,
+    /// the counterparty of connection `{connection_id}` is not the next hop `{next_hop}`
+    InconsistentHops {
+        connection_id: ConnectionId,
+        next_hop: ConnectionId,
+    },
     /// missing counterparty
     MissingCounterparty,
     /// missing client state
@@ -79,6 +86,17 @@ pub enum ConnectionError {
         current_host_time: Timestamp,
         earliest_valid_time: Timestamp,
     },
+    /// delay period `{delay_period:?}` is shorter than the minimum of `{min_delay_period:?}` required by the host
+    DelayPeriodTooShort {
+        delay_period: Duration,
+        min_delay_period: Duration,
+    },
+    /// proof height `{proof_height}` lags the client's latest height `{latest_height}` by more than `{max_staleness}` blocks
+    StaleProof {
+        proof_height: Height,
+        latest_height: Height,
+        max_staleness: u64,
+    },
     /// timestamp overflowed error: `{0}`
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
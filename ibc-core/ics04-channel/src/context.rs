@@ -8,7 +8,7 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
 use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
 use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 
 /// Methods required in send packet validation, to be implemented by the host
@@ -84,7 +84,7 @@ where
         seq_send_path: &SeqSendPath,
         seq: Sequence,
     ) -> Result<(), ContextError> {
-        self.store_next_sequence_send(seq_send_path, seq)
+        self.store_next_sequence_send_observed(seq_send_path, seq)
     }
 
     fn store_packet_commitment(
@@ -92,7 +92,7 @@ where
         commitment_path: &CommitmentPath,
         commitment: PacketCommitment,
     ) -> Result<(), ContextError> {
-        self.store_packet_commitment(commitment_path, commitment)
+        self.store_packet_commitment_observed(commitment_path, commitment)
     }
 
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
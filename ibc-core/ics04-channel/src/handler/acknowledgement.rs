@@ -8,10 +8,12 @@ use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
+use ibc_core_host::types::identifiers::Sequence;
 use ibc_core_host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
@@ -23,7 +25,7 @@ pub fn acknowledgement_packet_validate<ValCtx>(
 where
     ValCtx: ValidationContext,
 {
-    validate(ctx_a, &msg)?;
+    validate(ctx_a, &msg, None)?;
 
     module
         .on_acknowledgement_packet_validate(&msg.packet, &msg.acknowledgement, &msg.signer)
@@ -43,6 +45,8 @@ where
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
+    ensure_not_acknowledged(ctx_a, &chan_end_on_a.ordering, &msg)?;
+
     // In all cases, this event is emitted
     let event = IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
         msg.packet.clone(),
@@ -74,14 +78,23 @@ where
 
     // apply state changes
     {
-        ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.delete_packet_commitment_observed(&commitment_path_on_a)?;
+
+        if let Order::Unordered = chan_end_on_a.ordering {
+            let seq_ack_path_on_a =
+                SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+            ctx_a.store_acknowledged_sequence_observed(&seq_ack_path_on_a, msg.packet.seq_on_a)?;
+        }
 
         if let Order::Ordered = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
             let seq_ack_path_on_a =
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
-            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
+            ctx_a.store_next_sequence_ack_observed(
+                &seq_ack_path_on_a,
+                msg.packet.seq_on_a.increment(),
+            )?;
         }
     }
 
@@ -103,11 +116,118 @@ where
     Ok(())
 }
 
-fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgAcknowledgement) -> Result<(), ContextError>
+/// Validates and executes a batch of acknowledgements for packets sent over
+/// the same ordered channel.
+///
+/// The batch must be contiguous, starting at the channel's next ack
+/// sequence. Every acknowledgement is validated before any of them is
+/// executed, so a batch that fails validation leaves the store untouched.
+/// Errors raised during execution are returned as is; as for any failed
+/// message, hosts are expected to discard the state changes of the
+/// enclosing transaction.
+pub fn ack_packets_ordered<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msgs: Vec<MsgAcknowledgement>,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let Some(first) = msgs.first() else {
+        return Ok(());
+    };
+
+    let port_id_on_a = first.packet.port_id_on_a.clone();
+    let chan_id_on_a = first.packet.chan_id_on_a.clone();
+
+    let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !matches!(chan_end_on_a.ordering, Order::Ordered) {
+        return Err(ChannelError::InvalidOrderType {
+            expected: Order::Ordered.to_string(),
+            actual: chan_end_on_a.ordering.to_string(),
+        }
+        .into());
+    }
+
+    let seq_ack_path_on_a = SeqAckPath::new(&port_id_on_a, &chan_id_on_a);
+    let mut next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+
+    for msg in &msgs {
+        let packet = &msg.packet;
+
+        if packet.port_id_on_a != port_id_on_a || packet.chan_id_on_a != chan_id_on_a {
+            return Err(ChannelError::InvalidChannelId {
+                expected: format!("{port_id_on_a}/{chan_id_on_a}"),
+                actual: format!("{}/{}", packet.port_id_on_a, packet.chan_id_on_a),
+            }
+            .into());
+        }
+
+        if packet.seq_on_a != next_seq_ack {
+            return Err(ChannelError::UnexpectedSequence {
+                expected: next_seq_ack,
+                actual: packet.seq_on_a,
+            }
+            .into());
+        }
+
+        validate(ctx_a, msg, Some(next_seq_ack))?;
+
+        module
+            .on_acknowledgement_packet_validate(packet, &msg.acknowledgement, &msg.signer)
+            .map_err(ContextError::PacketError)?;
+
+        next_seq_ack = next_seq_ack.increment();
+    }
+
+    for msg in msgs {
+        acknowledgement_packet_execute(ctx_a, module, msg)?;
+    }
+
+    Ok(())
+}
+
+/// Rejects the acknowledgement of a packet sent on an unordered channel if
+/// the host recorded it as already acknowledged.
+fn ensure_not_acknowledged<Ctx>(
+    ctx_a: &Ctx,
+    ordering: &Order,
+    msg: &MsgAcknowledgement,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if let Order::Unordered = ordering {
+        let seq_ack_path_on_a = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
+        if ctx_a.is_sequence_acknowledged(&seq_ack_path_on_a, msg.packet.seq_on_a)? {
+            return Err(PacketError::AcknowledgementExists {
+                sequence: msg.packet.seq_on_a,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates an acknowledgement message.
+///
+/// For ordered channels, `expected_seq_ack` overrides the next ack sequence
+/// read from the store; this is used when validating a batch of
+/// acknowledgements ahead of executing them.
+fn validate<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgAcknowledgement,
+    expected_seq_ack: Option<Sequence>,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::Acknowledgement)?;
 
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
@@ -127,6 +247,8 @@ where
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
+    ensure_not_acknowledged(ctx_a, &chan_end_on_a.ordering, msg)?;
+
     let commitment_path_on_a =
         CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
 
@@ -153,12 +275,17 @@ where
     }
 
     if let Order::Ordered = chan_end_on_a.ordering {
-        let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
-        let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+        let next_seq_ack = match expected_seq_ack {
+            Some(seq) => seq,
+            None => {
+                let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+                ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?
+            }
+        };
         if packet.seq_on_a != next_seq_ack {
-            return Err(PacketError::InvalidPacketSequence {
-                given_sequence: packet.seq_on_a,
-                next_sequence: next_seq_ack,
+            return Err(ChannelError::UnexpectedSequence {
+                expected: next_seq_ack,
+                actual: packet.seq_on_a,
             }
             .into());
         }
@@ -1,15 +1,21 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelCloseConfirm`.
 
-use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::channel::{
+    ChannelEnd, Counterparty, Order, State, State as ChannelState,
+};
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseConfirm;
 use ibc_core_channel_types::msgs::MsgChannelCloseConfirm;
 use ibc_core_client::context::prelude::*;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_commitment_types::merkle::{MerkleProof, ProofLimits};
+use ibc_core_commitment_types::proto::ics23::commitment_proof::Proof;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_handler_types::msgs::MsgKind;
+use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path, SeqRecvPath};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
@@ -48,7 +54,7 @@ where
             chan_end_on_b.set_state(State::Closed);
             chan_end_on_b
         };
-        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+        ctx_b.store_channel_observed(&chan_end_path_on_b, chan_end_on_b)?;
     }
 
     // emit events and logs
@@ -96,6 +102,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::ChannelCloseConfirm)?;
 
     // Retrieve the old channel end and validate it against the message.
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
@@ -121,6 +128,19 @@ where
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
+        // On ordered channels, the proof must not predate the packets already
+        // received, or an old close proof could be replayed.
+        if chan_end_on_b.ordering == Order::Ordered {
+            let seq_recv_path_on_b = SeqRecvPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+            if let Some(last_recv_proof_height) =
+                ctx_b.last_recv_proof_height(&seq_recv_path_on_b)?
+            {
+                if msg.proof_height_on_a < last_recv_proof_height {
+                    return Err(ChannelError::InvalidProof.into());
+                }
+            }
+        }
+
         let client_cons_state_path_on_b = ClientConsensusStatePath::new(
             client_id_on_b.clone(),
             msg.proof_height_on_a.revision_number(),
@@ -151,16 +171,61 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
-            .verify_membership(
+        let verify_chan_end_on_a = |chan_end_on_a: ChannelEnd| {
+            client_state_of_a_on_b.verify_membership(
                 prefix_on_a,
                 &msg.proof_chan_end_on_a,
                 consensus_state_of_a_on_b.root(),
-                Path::ChannelEnd(chan_end_path_on_a),
-                expected_chan_end_on_a.encode_vec(),
+                Path::ChannelEnd(chan_end_path_on_a.clone()),
+                chan_end_on_a.encode_vec(),
             )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+        };
+
+        if let Err(e) = verify_chan_end_on_a(expected_chan_end_on_a.clone()) {
+            // The counterparty end is only carried as a proof. Only when the
+            // proof claims an end with another ordering is it verified once
+            // more, against that end, to report the mismatch precisely. This
+            // is best-effort, see `claimed_ordering`.
+            let local_ordering = *chan_end_on_b.ordering();
+            if let Some(ordering) = claimed_ordering(&msg.proof_chan_end_on_a)
+                .filter(|ordering| *ordering != local_ordering)
+            {
+                let mut chan_end_on_a = expected_chan_end_on_a;
+                chan_end_on_a.ordering = ordering;
+
+                if verify_chan_end_on_a(chan_end_on_a).is_ok() {
+                    return Err(ChannelError::OrderingMismatch {
+                        expected: local_ordering,
+                        actual: ordering,
+                    }
+                    .into());
+                }
+            }
+
+            return Err(ChannelError::VerifyChannelFailed(e).into());
+        }
     }
 
     Ok(())
 }
+
+/// Returns the ordering of the channel end whose existence the given proof
+/// claims, if it is an ICS-23 merkle proof of a value.
+///
+/// This only serves best-effort error reporting: proofs are opaque to the core
+/// handlers, and proofs in another format, or exceeding the default proof
+/// limits, yield `None`, in which case the failure is reported as a generic
+/// verification failure. The claimed end is not verified here, and must not be
+/// trusted.
+fn claimed_ordering(proof: &CommitmentProofBytes) -> Option<Order> {
+    let merkle_proof =
+        MerkleProof::try_from_bytes_with_limits(proof, &ProofLimits::default()).ok()?;
+
+    let Some(Proof::Exist(existence_proof)) = &merkle_proof.proofs.first()?.proof else {
+        return None;
+    };
+
+    ChannelEnd::decode_vec(&existence_proof.value)
+        .ok()
+        .map(|chan_end| chan_end.ordering)
+}
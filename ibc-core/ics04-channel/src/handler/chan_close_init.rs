@@ -7,8 +7,9 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::ChannelEndPath;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
@@ -47,7 +48,7 @@ where
             chan_end_on_a
         };
 
-        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.store_channel_observed(&chan_end_path_on_a, chan_end_on_a)?;
     }
 
     // emit events and logs
@@ -56,22 +57,14 @@ where
 
         let core_event = {
             let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
-            let chan_id_on_b = chan_end_on_a
-                .counterparty()
-                .channel_id
-                .clone()
-                .ok_or(ContextError::ChannelError(ChannelError::Other {
-                description:
-                    "internal error: ChannelEnd doesn't have a counterparty channel id in CloseInit"
-                        .to_string(),
-            }))?;
+            let maybe_chan_id_on_b = chan_end_on_a.counterparty().channel_id.clone();
             let conn_id_on_a = chan_end_on_a.connection_hops[0].clone();
 
             IbcEvent::CloseInitChannel(CloseInit::new(
                 msg.port_id_on_a.clone(),
                 msg.chan_id_on_a.clone(),
                 port_id_on_b,
-                chan_id_on_b,
+                maybe_chan_id_on_b,
                 conn_id_on_a,
             ))
         };
@@ -95,6 +88,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::ChannelCloseInit)?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
@@ -102,11 +96,27 @@ where
     // Validate that the channel end is in a state where it can be closed.
     chan_end_on_a.verify_not_closed()?;
 
-    // An OPEN IBC connection running on the local (host) chain should exist.
+    // An IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
     let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
 
+    match chan_end_on_a.state() {
+        // A channel whose handshake got stuck can be abandoned regardless of
+        // the state of the underlying connection and client, so that the
+        // handshake may be retried on a fresh channel.
+        State::Init | State::TryOpen => return Ok(()),
+        State::Open => {}
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Channel state must be Init, TryOpen or Open".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    }
+
+    // For an `Open` channel, the connection must be OPEN as well.
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
     let client_id_on_a = conn_end_on_a.client_id();
@@ -7,8 +7,9 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
@@ -52,7 +53,7 @@ where
 
             chan_end_on_a
         };
-        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.store_channel_observed(&chan_end_path_on_a, chan_end_on_a)?;
     }
 
     // emit events and logs
@@ -91,6 +92,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::ChannelOpenAck)?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
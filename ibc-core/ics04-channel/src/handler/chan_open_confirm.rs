@@ -8,8 +8,9 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
@@ -49,7 +50,7 @@ where
 
             chan_end_on_b
         };
-        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+        ctx_b.store_channel_observed(&chan_end_path_on_b, chan_end_on_b)?;
     }
 
     // emit events and logs
@@ -95,6 +96,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::ChannelOpenConfirm)?;
 
     // Unwrap the old channel end and validate it against the message.
     let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
@@ -1,14 +1,16 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenInit`.
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenInit;
 use ibc_core_channel_types::msgs::MsgChannelOpenInit;
 use ibc_core_client::context::prelude::*;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
@@ -65,19 +67,19 @@ where
             msg.version_proposal.clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &chan_id_on_a);
-        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.store_channel_observed(&chan_end_path_on_a, chan_end_on_a)?;
 
-        ctx_a.increase_channel_counter()?;
+        ctx_a.increase_channel_counter_observed()?;
 
         // Initialize send, recv, and ack sequence numbers.
         let seq_send_path = SeqSendPath::new(&msg.port_id_on_a, &chan_id_on_a);
-        ctx_a.store_next_sequence_send(&seq_send_path, 1.into())?;
+        ctx_a.store_next_sequence_send_observed(&seq_send_path, 1.into())?;
 
         let seq_recv_path = SeqRecvPath::new(&msg.port_id_on_a, &chan_id_on_a);
-        ctx_a.store_next_sequence_recv(&seq_recv_path, 1.into())?;
+        ctx_a.store_next_sequence_recv_observed(&seq_recv_path, 1.into())?;
 
         let seq_ack_path = SeqAckPath::new(&msg.port_id_on_a, &chan_id_on_a);
-        ctx_a.store_next_sequence_ack(&seq_ack_path, 1.into())?;
+        ctx_a.store_next_sequence_ack_observed(&seq_ack_path, 1.into())?;
     }
 
     // emit events and logs
@@ -112,6 +114,11 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::ChannelOpenInit)?;
+
+    if msg.connection_hops_on_a.is_empty() {
+        return Err(ChannelError::MissingConnectionHops.into());
+    }
 
     msg.verify_connection_hops_length()?;
     // An IBC connection running on the local (host) chain should exist.
@@ -8,11 +8,12 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
@@ -72,18 +73,18 @@ where
         )?;
 
         let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &chan_id_on_b);
-        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
-        ctx_b.increase_channel_counter()?;
+        ctx_b.store_channel_observed(&chan_end_path_on_b, chan_end_on_b)?;
+        ctx_b.increase_channel_counter_observed()?;
 
         // Initialize send, recv, and ack sequence numbers.
         let seq_send_path = SeqSendPath::new(&msg.port_id_on_b, &chan_id_on_b);
-        ctx_b.store_next_sequence_send(&seq_send_path, 1.into())?;
+        ctx_b.store_next_sequence_send_observed(&seq_send_path, 1.into())?;
 
         let seq_recv_path = SeqRecvPath::new(&msg.port_id_on_b, &chan_id_on_b);
-        ctx_b.store_next_sequence_recv(&seq_recv_path, 1.into())?;
+        ctx_b.store_next_sequence_recv_observed(&seq_recv_path, 1.into())?;
 
         let seq_ack_path = SeqAckPath::new(&msg.port_id_on_b, &chan_id_on_b);
-        ctx_b.store_next_sequence_ack(&seq_ack_path, 1.into())?;
+        ctx_b.store_next_sequence_ack_observed(&seq_ack_path, 1.into())?;
     }
 
     // emit events and logs
@@ -120,6 +121,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::ChannelOpenTry)?;
 
     msg.verify_connection_hops_length()?;
 
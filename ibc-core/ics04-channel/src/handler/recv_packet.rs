@@ -1,4 +1,4 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
 use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
@@ -9,11 +9,12 @@ use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
     SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
@@ -82,13 +83,20 @@ where
                     sequence: msg.packet.seq_on_a,
                 };
 
-                ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
+                ctx_b.store_packet_receipt_observed(&receipt_path_on_b, Receipt::Ok)?;
             }
             Order::Ordered => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
-                ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
+                ctx_b.store_next_sequence_recv_observed(
+                    &seq_recv_path_on_b,
+                    next_seq_recv.increment(),
+                )?;
+                ctx_b.store_last_recv_proof_height_observed(
+                    &seq_recv_path_on_b,
+                    msg.proof_height_on_a,
+                )?;
             }
             _ => {}
         }
@@ -98,7 +106,7 @@ where
             msg.packet.seq_on_a,
         );
         // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
+        ctx_b.store_packet_acknowledgement_observed(
             &ack_path_on_b,
             compute_ack_commitment(&acknowledgement),
         )?;
@@ -109,21 +117,22 @@ where
         ctx_b.log_message("success: packet receive".to_string())?;
         ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
+        // `ReceivePacket` and `WriteAcknowledgement` are emitted as a pair,
+        // behind a single message event.
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
+        let recv_event = IbcEvent::ReceivePacket(ReceivePacket::new(
             msg.packet.clone(),
             chan_end_on_b.ordering,
             conn_id_on_b.clone(),
         ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+        let write_ack_event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
             msg.packet,
             acknowledgement,
             conn_id_on_b.clone(),
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
+        ctx_b.emit_ibc_event(recv_event)?;
+        ctx_b.emit_ibc_event(write_ack_event)?;
 
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
@@ -142,6 +151,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.authorize(&msg.signer, &MsgKind::RecvPacket)?;
 
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
@@ -149,12 +159,9 @@ where
 
     chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
 
-    let counterparty = Counterparty::new(
-        msg.packet.port_id_on_a.clone(),
-        Some(msg.packet.chan_id_on_a.clone()),
-    );
-
-    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+    // A packet sent on any other channel must not be replayed here.
+    chan_end_on_b
+        .verify_packet_source_matches(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)?;
 
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
     let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
@@ -209,7 +216,8 @@ where
 
         verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
 
-        // Verify the proof for the packet against the chain store.
+        // Verify the commitment of the packet against the chain store, at
+        // `commitments/ports/{port}/channels/{channel}/sequences/{seq}`.
         client_state_of_a_on_b
             .verify_membership(
                 conn_end_on_b.counterparty().prefix(),
@@ -218,7 +226,7 @@ where
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
-            .map_err(|e| ChannelError::PacketVerificationFailed {
+            .map_err(|e| ChannelError::PacketCommitmentVerificationFailed {
                 sequence: msg.packet.seq_on_a,
                 client_error: e,
             })
@@ -7,10 +7,11 @@ use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::msgs::MsgKind;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, ObservedExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
@@ -59,11 +60,6 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    // In all cases, this event is emitted
-    let event = IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
-    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-    ctx_a.emit_ibc_event(event)?;
-
     let commitment_path_on_a =
         CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
 
@@ -76,18 +72,23 @@ where
         return Ok(());
     };
 
+    // In all other cases, this event is emitted
+    let event = IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx_a.emit_ibc_event(event)?;
+
     let (extras, cb_result) = module.on_timeout_packet_execute(&packet, &signer);
 
     cb_result?;
 
     // apply state changes
     let chan_end_on_a = {
-        ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.delete_packet_commitment_observed(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             let mut chan_end_on_a = chan_end_on_a;
             chan_end_on_a.state = State::Closed;
-            ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+            ctx_a.store_channel_observed(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
         } else {
@@ -131,6 +132,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.authorize(&msg.signer, &MsgKind::Timeout)?;
 
     let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(
         &msg.packet.port_id_on_a,
@@ -248,6 +248,23 @@ impl ChannelEnd {
         &self.version
     }
 
+    /// Returns the application version of this channel end, unwrapping a
+    /// middleware-wrapped version if present. See [`Version::app_version`].
+    pub fn app_version(&self) -> String {
+        self.version.app_version()
+    }
+
+    /// Returns `true` if this channel delivers packets with the given ordering.
+    pub fn supports_ordering(&self, order: Order) -> bool {
+        self.ordering == order
+    }
+
+    /// Returns `true` if the channel version announces the given middleware
+    /// feature, e.g. `fee`. See [`Version::supports_feature`].
+    pub fn supports_feature(&self, feature: &str) -> bool {
+        self.version.supports_feature(feature)
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -312,6 +329,26 @@ impl ChannelEnd {
         Ok(())
     }
 
+    /// Checks that a packet received on this channel end was sent from its
+    /// counterparty, i.e. that the packet source port and channel are the
+    /// ones of the counterparty.
+    pub fn verify_packet_source_matches(
+        &self,
+        port_id_on_a: &PortId,
+        chan_id_on_a: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        let counterparty = self.counterparty();
+
+        if counterparty.port_id() != port_id_on_a || counterparty.channel_id() != Some(chan_id_on_a)
+        {
+            return Err(ChannelError::MismatchedChannel {
+                expected: counterparty.clone(),
+                actual: Counterparty::new(port_id_on_a.clone(), Some(chan_id_on_a.clone())),
+            });
+        }
+        Ok(())
+    }
+
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
     /// Note: Current IBC version only supports one connection hop.
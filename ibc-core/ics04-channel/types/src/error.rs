@@ -2,13 +2,14 @@
 
 use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
+use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
 use ibc_primitives::{ParseTimestampError, Timestamp};
 
-use super::channel::Counterparty;
+use super::channel::{Counterparty, Order};
 use super::timeout::TimeoutHeight;
 use crate::channel::State;
 use crate::Version;
@@ -23,8 +24,17 @@ pub enum ChannelError {
     InvalidState { expected: String, actual: String },
     /// invalid channel order type: expected `{expected}`, actual `{actual}`
     InvalidOrderType { expected: String, actual: String },
+    /// channel ordering mismatch: expected `{expected}`, actual `{actual}`
+    OrderingMismatch { expected: Order, actual: Order },
+    /// unexpected packet sequence: expected `{expected}`, actual `{actual}`
+    UnexpectedSequence {
+        expected: Sequence,
+        actual: Sequence,
+    },
     /// invalid connection hops length: expected `{expected}`; actual `{actual}`
     InvalidConnectionHopsLength { expected: u64, actual: u64 },
+    /// missing connection hops
+    MissingConnectionHops,
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// invalid proof: missing height
@@ -35,6 +45,8 @@ pub enum ChannelError {
     MissingCounterparty,
     /// unsupported channel upgrade sequence
     UnsupportedChannelUpgradeSequence,
+    /// invalid channel upgrade: `{reason}`
+    InvalidUpgrade { reason: String },
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
     /// missing channel end
@@ -49,6 +61,11 @@ pub enum ChannelError {
         sequence: Sequence,
         client_error: client_error::ClientError,
     },
+    /// Verification fails for the commitment of the packet with the sequence number `{sequence}`, error: `{client_error}`
+    PacketCommitmentVerificationFailed {
+        sequence: Sequence,
+        client_error: client_error::ClientError,
+    },
     /// Error verifying channel state error: `{0}`
     VerifyChannelFailed(client_error::ClientError),
     /// String `{value}` cannot be converted to packet sequence, error: `{error}`
@@ -61,12 +78,19 @@ pub enum ChannelError {
         expected: Counterparty,
         actual: Counterparty,
     },
+    /// packet source `{actual}` does not match the channel counterparty `{expected}`
+    MismatchedChannel {
+        expected: Counterparty,
+        actual: Counterparty,
+    },
     /// application module error: `{description}`
     AppModule { description: String },
     /// Undefined counterparty connection for `{connection_id}`
     UndefinedConnectionCounterparty { connection_id: ConnectionId },
-    /// invalid proof: empty proof
+    /// invalid proof
     InvalidProof,
+    /// invalid commitment proof: `{0}`
+    InvalidCommitmentProof(CommitmentError),
     /// identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
     /// channel counter overflow error
@@ -105,8 +129,10 @@ pub enum PacketError {
     ImplementationSpecific,
     /// Undefined counterparty connection for `{connection_id}`
     UndefinedConnectionCounterparty { connection_id: ConnectionId },
-    /// invalid proof: empty proof
+    /// invalid proof
     InvalidProof,
+    /// invalid commitment proof: `{0}`
+    InvalidCommitmentProof(CommitmentError),
     /// Packet timeout height `{timeout_height}` > chain height `{chain_height} and timeout timestamp `{timeout_timestamp}` > chain timestamp `{chain_timestamp}`
     PacketTimeoutNotReached {
         timeout_height: TimeoutHeight,
@@ -138,6 +164,10 @@ pub enum PacketError {
     ZeroPacketData,
     /// invalid timeout height for the packet
     InvalidTimeoutHeight,
+    /// packet timeout height delta cannot be 0
+    ZeroTimeoutHeightDelta,
+    /// packet timeout height overflows: `{height}` + `{delta}`
+    TimeoutHeightOverflow { height: Height, delta: u64 },
     /// Invalid packet timeout timestamp value error: `{0}`
     InvalidPacketTimestamp(ParseTimestampError),
     /// missing timeout
@@ -189,6 +219,7 @@ impl std::error::Error for PacketError {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCommitmentProof(e) => Some(e),
             _ => None,
         }
     }
@@ -202,7 +233,11 @@ impl std::error::Error for ChannelError {
             Self::PacketVerificationFailed {
                 client_error: e, ..
             } => Some(e),
+            Self::PacketCommitmentVerificationFailed {
+                client_error: e, ..
+            } => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
+            Self::InvalidCommitmentProof(e) => Some(e),
             _ => None,
         }
     }
@@ -364,23 +364,25 @@ pub struct CloseInit {
     port_id_attr_on_a: PortIdAttribute,
     chan_id_attr_on_a: ChannelIdAttribute,
     port_id_attr_on_b: CounterpartyPortIdAttribute,
-    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    maybe_chan_id_attr_on_b: Option<CounterpartyChannelIdAttribute>,
     conn_id_attr_on_a: ConnectionIdAttribute,
 }
 
 impl CloseInit {
+    /// Note that `maybe_chan_id_on_b` is `None` when closing a channel whose
+    /// handshake was abandoned before the counterparty channel was created.
     pub fn new(
         port_id_on_a: PortId,
         chan_id_on_a: ChannelId,
         port_id_on_b: PortId,
-        chan_id_on_b: ChannelId,
+        maybe_chan_id_on_b: Option<ChannelId>,
         conn_id_on_a: ConnectionId,
     ) -> Self {
         Self {
             port_id_attr_on_a: port_id_on_a.into(),
             chan_id_attr_on_a: chan_id_on_a.into(),
             port_id_attr_on_b: port_id_on_b.into(),
-            chan_id_attr_on_b: chan_id_on_b.into(),
+            maybe_chan_id_attr_on_b: maybe_chan_id_on_b.map(Into::into),
             conn_id_attr_on_a: conn_id_on_a.into(),
         }
     }
@@ -393,8 +395,8 @@ impl CloseInit {
     pub fn port_id_on_b(&self) -> &PortId {
         &self.port_id_attr_on_b.counterparty_port_id
     }
-    pub fn chan_id_on_b(&self) -> &ChannelId {
-        &self.chan_id_attr_on_b.counterparty_channel_id
+    pub fn chan_id_on_b(&self) -> Option<&ChannelId> {
+        self.maybe_chan_id_attr_on_b.as_ref().map(AsRef::as_ref)
     }
     pub fn conn_id_on_a(&self) -> &ConnectionId {
         &self.conn_id_attr_on_a.connection_id
@@ -413,7 +415,10 @@ impl From<CloseInit> for abci::Event {
                 o.port_id_attr_on_a.into(),
                 o.chan_id_attr_on_a.into(),
                 o.port_id_attr_on_b.into(),
-                o.chan_id_attr_on_b.into(),
+                o.maybe_chan_id_attr_on_b.map_or_else(
+                    || (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, "").into(),
+                    Into::into,
+                ),
                 o.conn_id_attr_on_a.into(),
             ],
         }
@@ -1220,7 +1225,7 @@ mod tests {
                     port_id.clone(),
                     channel_id.clone(),
                     counterparty_port_id.clone(),
-                    counterparty_channel_id.clone(),
+                    Some(counterparty_channel_id.clone()),
                     connection_id.clone(),
                 )
                 .into(),
@@ -45,7 +45,7 @@ impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
             proof_acked_on_b: raw_msg
                 .proof_acked
                 .try_into()
-                .map_err(|_| PacketError::InvalidProof)?,
+                .map_err(PacketError::InvalidCommitmentProof)?,
             proof_height_on_b: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
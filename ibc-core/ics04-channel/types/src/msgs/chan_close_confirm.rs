@@ -45,12 +45,16 @@ impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
             proof_chan_end_on_a: raw_msg
                 .proof_init
                 .try_into()
-                .map_err(|_| ChannelError::InvalidProof)?,
+                .map_err(ChannelError::InvalidCommitmentProof)?,
             proof_height_on_a: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(ChannelError::MissingHeight)?,
-            signer: raw_msg.signer.into(),
+            signer: Signer::new_checked(raw_msg.signer).map_err(|e| {
+                ChannelError::InvalidSigner {
+                    reason: e.to_string(),
+                }
+            })?,
         })
     }
 }
@@ -41,7 +41,7 @@ impl TryFrom<RawMsgChannelOpenConfirm> for MsgChannelOpenConfirm {
             proof_chan_end_on_a: raw_msg
                 .proof_ack
                 .try_into()
-                .map_err(|_| ChannelError::InvalidProof)?,
+                .map_err(ChannelError::InvalidCommitmentProof)?,
             proof_height_on_a: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
@@ -82,7 +82,7 @@ impl TryFrom<RawMsgChannelOpenTry> for MsgChannelOpenTry {
             proof_chan_end_on_a: raw_msg
                 .proof_init
                 .try_into()
-                .map_err(|_| ChannelError::InvalidProof)?,
+                .map_err(ChannelError::InvalidCommitmentProof)?,
             proof_height_on_a: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
@@ -0,0 +1,92 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+use ibc_proto::Protobuf;
+
+use crate::channel::ChannelEnd;
+use crate::error::ChannelError;
+use crate::upgrade::{FlushState, Upgrade};
+
+pub const CHAN_UPGRADE_CONFIRM_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeConfirm";
+
+///
+/// Message definition for the fourth step in the channel upgrade handshake (`ChanUpgradeConfirm`
+/// datagram).
+/// Per our convention, this message is sent to chain B.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeConfirm {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub chan_state_on_a: FlushState,
+    pub upgrade_on_a: Upgrade,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeConfirm {
+    /// Checks that the upgrade of chain A is a valid upgrade of the channel
+    /// end on chain B. Its state is not checked, as chain B is flushing at
+    /// this step.
+    pub fn verify_upgrade_of(&self, chan_end_on_b: &ChannelEnd) -> Result<(), ChannelError> {
+        self.upgrade_on_a.fields.verify_upgrade_of(chan_end_on_b)
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {}
+
+impl TryFrom<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeConfirm) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeConfirm {
+            port_id_on_b: raw_msg.port_id.parse()?,
+            chan_id_on_b: raw_msg.channel_id.parse()?,
+            chan_state_on_a: FlushState::from_i32(raw_msg.counterparty_channel_state)?,
+            upgrade_on_a: raw_msg
+                .counterparty_upgrade
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing counterparty upgrade".to_string(),
+                })?
+                .try_into()?,
+            proof_chan_end_on_a: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(ChannelError::InvalidCommitmentProof)?,
+            proof_upgrade_on_a: raw_msg
+                .proof_upgrade
+                .try_into()
+                .map_err(ChannelError::InvalidCommitmentProof)?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeConfirm> for RawMsgChannelUpgradeConfirm {
+    fn from(domain_msg: MsgChannelUpgradeConfirm) -> Self {
+        RawMsgChannelUpgradeConfirm {
+            port_id: domain_msg.port_id_on_b.to_string(),
+            channel_id: domain_msg.chan_id_on_b.to_string(),
+            counterparty_channel_state: domain_msg.chan_state_on_a as i32,
+            counterparty_upgrade: Some(domain_msg.upgrade_on_a.into()),
+            proof_channel: domain_msg.proof_chan_end_on_a.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+use ibc_proto::Protobuf;
+
+use crate::channel::{ChannelEnd, State};
+use crate::error::ChannelError;
+use crate::upgrade::UpgradeFields;
+
+pub const CHAN_UPGRADE_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeInit";
+
+///
+/// Message definition for the first step in the channel upgrade handshake (`ChanUpgradeInit`
+/// datagram).
+/// Per our convention, this message is sent to chain A.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeInit {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub fields: UpgradeFields,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeInit {
+    /// Checks that the proposed ordering, connection hops and version are a
+    /// valid upgrade of the channel end on chain A, which must be open.
+    pub fn verify_upgrade_of(&self, chan_end_on_a: &ChannelEnd) -> Result<(), ChannelError> {
+        chan_end_on_a.verify_state_matches(&State::Open)?;
+
+        self.fields.verify_upgrade_of(chan_end_on_a)
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {}
+
+impl TryFrom<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeInit) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeInit {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            fields: raw_msg
+                .fields
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing upgrade fields".to_string(),
+                })?
+                .try_into()?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeInit> for RawMsgChannelUpgradeInit {
+    fn from(domain_msg: MsgChannelUpgradeInit) -> Self {
+        RawMsgChannelUpgradeInit {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            fields: Some(domain_msg.fields.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, ChannelEnd, State};
+use crate::error::ChannelError;
+use crate::upgrade::UpgradeFields;
+
+pub const CHAN_UPGRADE_TRY_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTry";
+
+///
+/// Message definition for the second step in the channel upgrade handshake (`ChanUpgradeTry`
+/// datagram).
+/// Per our convention, this message is sent to chain B.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTry {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub proposed_connection_hops_on_b: Vec<ConnectionId>,
+    pub upgrade_fields_on_a: UpgradeFields,
+    pub upgrade_sequence_on_a: u64,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeTry {
+    /// Checks that the upgrade proposed by chain A is a valid upgrade of the
+    /// channel end on chain B, which must be open.
+    pub fn verify_upgrade_of(&self, chan_end_on_b: &ChannelEnd) -> Result<(), ChannelError> {
+        chan_end_on_b.verify_state_matches(&State::Open)?;
+
+        self.upgrade_fields_on_a.verify_upgrade_of(chan_end_on_b)
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {}
+
+impl TryFrom<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTry) -> Result<Self, Self::Error> {
+        let proposed_connection_hops_on_b = raw_msg
+            .proposed_upgrade_connection_hops
+            .into_iter()
+            .map(|conn_id| conn_id.parse())
+            .collect::<Result<Vec<ConnectionId>, _>>()?;
+
+        verify_connection_hops_length(&proposed_connection_hops_on_b, 1)?;
+
+        Ok(MsgChannelUpgradeTry {
+            port_id_on_b: raw_msg.port_id.parse()?,
+            chan_id_on_b: raw_msg.channel_id.parse()?,
+            proposed_connection_hops_on_b,
+            upgrade_fields_on_a: raw_msg
+                .counterparty_upgrade_fields
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing counterparty upgrade fields".to_string(),
+                })?
+                .try_into()?,
+            upgrade_sequence_on_a: raw_msg.counterparty_upgrade_sequence,
+            proof_chan_end_on_a: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(ChannelError::InvalidCommitmentProof)?,
+            proof_upgrade_on_a: raw_msg
+                .proof_upgrade
+                .try_into()
+                .map_err(ChannelError::InvalidCommitmentProof)?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTry> for RawMsgChannelUpgradeTry {
+    fn from(domain_msg: MsgChannelUpgradeTry) -> Self {
+        RawMsgChannelUpgradeTry {
+            port_id: domain_msg.port_id_on_b.to_string(),
+            channel_id: domain_msg.chan_id_on_b.to_string(),
+            proposed_upgrade_connection_hops: domain_msg
+                .proposed_connection_hops_on_b
+                .iter()
+                .map(|conn_id| conn_id.as_str().to_string())
+                .collect(),
+            counterparty_upgrade_fields: Some(domain_msg.upgrade_fields_on_a.into()),
+            counterparty_upgrade_sequence: domain_msg.upgrade_sequence_on_a,
+            proof_channel: domain_msg.proof_chan_end_on_a.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -1,5 +1,5 @@
-//! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
-//! as packets.
+//! Message definitions for all ICS4 domain types: channel open, close & upgrade handshake
+//! datagrams, as well as packets.
 
 mod acknowledgement;
 mod chan_close_confirm;
@@ -8,6 +8,10 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_try;
 mod recv_packet;
 mod timeout;
 mod timeout_on_close;
@@ -22,6 +26,11 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+// Upgrade handshake messages.
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_try::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
 pub use recv_packet::*;
@@ -44,7 +44,7 @@ impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
             proof_commitment_on_a: raw_msg
                 .proof_commitment
                 .try_into()
-                .map_err(|_| PacketError::InvalidProof)?,
+                .map_err(PacketError::InvalidCommitmentProof)?,
             proof_height_on_a: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
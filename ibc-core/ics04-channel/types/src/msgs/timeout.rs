@@ -47,7 +47,7 @@ impl TryFrom<RawMsgTimeout> for MsgTimeout {
             proof_unreceived_on_b: raw_msg
                 .proof_unreceived
                 .try_into()
-                .map_err(|_| PacketError::InvalidProof)?,
+                .map_err(PacketError::InvalidCommitmentProof)?,
             proof_height_on_b: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
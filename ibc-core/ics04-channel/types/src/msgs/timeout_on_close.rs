@@ -54,11 +54,11 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
             proof_unreceived_on_b: raw_msg
                 .proof_unreceived
                 .try_into()
-                .map_err(|_| PacketError::InvalidProof)?,
+                .map_err(PacketError::InvalidCommitmentProof)?,
             proof_close_on_b: raw_msg
                 .proof_close
                 .try_into()
-                .map_err(|_| PacketError::InvalidProof)?,
+                .map_err(PacketError::InvalidCommitmentProof)?,
             proof_height_on_b: raw_msg
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
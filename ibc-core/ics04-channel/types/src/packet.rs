@@ -145,6 +145,87 @@ impl Packet {
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Sets the packet to time out `delta` blocks past `client_height`, the
+    /// latest height of the client tracking the destination chain, e.g. as
+    /// returned by `ValidationContext::client_latest_height`.
+    ///
+    /// Fails with [`PacketError::ZeroTimeoutHeightDelta`] if `delta` is zero,
+    /// as the packet would then time out at a height already reached, and
+    /// with [`PacketError::TimeoutHeightOverflow`] if the resulting height
+    /// does not fit in a `u64`.
+    pub fn with_timeout_after(
+        self,
+        client_height: Height,
+        delta: u64,
+    ) -> Result<Self, PacketError> {
+        if delta == 0 {
+            return Err(PacketError::ZeroTimeoutHeightDelta);
+        }
+
+        let timeout_height = client_height
+            .revision_height()
+            .checked_add(delta)
+            .and_then(|revision_height| {
+                Height::new(client_height.revision_number(), revision_height).ok()
+            })
+            .ok_or(PacketError::TimeoutHeightOverflow {
+                height: client_height,
+                delta,
+            })?;
+
+        Ok(Self {
+            timeout_height_on_b: TimeoutHeight::At(timeout_height),
+            ..self
+        })
+    }
+
+    /// Returns the identifier of the packet on its source chain (A).
+    pub fn id_on_a(&self) -> PacketId {
+        PacketId {
+            port_id: self.port_id_on_a.clone(),
+            chan_id: self.chan_id_on_a.clone(),
+            seq: self.seq_on_a,
+        }
+    }
+
+    /// Returns the identifier of the packet on its destination chain (B).
+    pub fn id_on_b(&self) -> PacketId {
+        PacketId {
+            port_id: self.port_id_on_b.clone(),
+            chan_id: self.chan_id_on_b.clone(),
+            seq: self.seq_on_a,
+        }
+    }
+}
+
+/// Uniquely identifies a packet on one end of a channel, suitable as a key
+/// for tracking per-packet state such as escrows or fees.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    pub seq: Sequence,
+}
+
+impl core::fmt::Display for PacketId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}/{}/{}", self.port_id, self.chan_id, self.seq)
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -0,0 +1,280 @@
+//! Types for the channel upgrade handshake, as described in ICS-04.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::{
+    Timeout as RawUpgradeTimeout, Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, ChannelEnd, Order};
+use crate::error::ChannelError;
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The channel parameters an upgrade proposes to change.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    /// Checks that the proposed fields are a valid upgrade of the given
+    /// channel end.
+    ///
+    /// The upgrade must change at least one of its parameters. As in ibc-go,
+    /// an ordered channel may become unordered, but not the other way around.
+    /// The state of the channel end is left to the caller, since it depends
+    /// on the step of the handshake.
+    pub fn verify_upgrade_of(&self, chan_end: &ChannelEnd) -> Result<(), ChannelError> {
+        if chan_end.ordering == Order::Unordered && self.ordering == Order::Ordered {
+            return Err(ChannelError::InvalidOrderType {
+                expected: Order::Unordered.to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        if self.ordering == chan_end.ordering
+            && self.connection_hops == chan_end.connection_hops
+            && self.version == chan_end.version
+        {
+            return Err(ChannelError::InvalidUpgrade {
+                reason: "proposed upgrade fields are identical to the current channel end"
+                    .to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = match Order::from_i32(value.ordering)? {
+            Order::None => {
+                return Err(ChannelError::InvalidOrderType {
+                    expected: "Must be one of 1, 2".to_string(),
+                    actual: value.ordering.to_string(),
+                })
+            }
+            ordering => ordering,
+        };
+
+        let connection_hops = value
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| conn_id.parse())
+            .collect::<Result<Vec<ConnectionId>, _>>()?;
+
+        verify_connection_hops_length(&connection_hops, 1)?;
+
+        Ok(UpgradeFields {
+            ordering,
+            connection_hops,
+            version: value.version.into(),
+        })
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|conn_id| conn_id.as_str().to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The state of a channel end that accepted an upgrade and is flushing its
+/// in-flight packets, as reported by `MsgChannelUpgradeConfirm`.
+///
+/// These states extend the channel [`State`](crate::channel::State) in
+/// ibc-go, where they are numbered after it.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlushState {
+    Flushing = 5isize,
+    FlushComplete = 6isize,
+}
+
+impl FlushState {
+    /// Yields the state as a string
+    pub fn as_string(&self) -> &'static str {
+        match self {
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
+        }
+    }
+
+    // Parses the FlushState out from a i32.
+    pub fn from_i32(s: i32) -> Result<Self, ChannelError> {
+        match s {
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
+            _ => Err(ChannelError::InvalidState {
+                expected: "Must be one of: 5, 6".to_string(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for FlushState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+/// The height and time on the counterparty after which an upgrade is
+/// aborted.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl Protobuf<RawUpgradeTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawUpgradeTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeTimeout) -> Result<Self, Self::Error> {
+        let height =
+            TimeoutHeight::try_from(value.height).map_err(|e| ChannelError::InvalidUpgrade {
+                reason: format!("invalid timeout height: {e}"),
+            })?;
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp).map_err(|e| {
+            ChannelError::InvalidUpgrade {
+                reason: format!("invalid timeout timestamp: {e}"),
+            }
+        })?;
+
+        if height == TimeoutHeight::Never && !timestamp.is_set() {
+            return Err(ChannelError::InvalidUpgrade {
+                reason: "timeout height and timestamp cannot both be zero".to_string(),
+            });
+        }
+
+        Ok(UpgradeTimeout { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawUpgradeTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        RawUpgradeTimeout {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade proposed by one end of a channel, as stored on its chain.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Upgrade {
+            fields: value
+                .fields
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing upgrade fields".to_string(),
+                })?
+                .try_into()?,
+            timeout: value
+                .timeout
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing upgrade timeout".to_string(),
+                })?
+                .try_into()?,
+            next_sequence_send: value.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
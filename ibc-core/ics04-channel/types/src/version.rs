@@ -49,6 +49,25 @@ impl Version {
         &self.0
     }
 
+    /// Returns the application version carried by this version.
+    ///
+    /// The ICS-29 fee middleware wraps the application version in a JSON
+    /// object, e.g. `{"fee_version":"ics29-1","app_version":"ics20-1"}`. When
+    /// such a wrapper is present, its `app_version` field is returned;
+    /// otherwise the raw version is returned as-is.
+    pub fn app_version(&self) -> String {
+        fee_wrapped_versions(&self.0).map_or_else(|| self.0.clone(), |(_, app_version)| app_version)
+    }
+
+    /// Returns `true` if this version is wrapped by a middleware announcing
+    /// `feature`, i.e. carries a `<feature>_version` field, e.g. `fee` for
+    /// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+    pub fn supports_feature(&self, feature: &str) -> bool {
+        feature != "app"
+            && wrapped_fields(&self.0)
+                .is_some_and(|fields| fields.contains_key(&format!("{feature}_version")))
+    }
+
     pub fn verify_is_expected(&self, expected: Version) -> Result<(), ChannelError> {
         if self != &expected {
             return Err(ChannelError::VersionNotSupported {
@@ -79,3 +98,24 @@ impl Display for Version {
         write!(f, "{}", self.0)
     }
 }
+
+/// Parses a fee-wrapped version of the form
+/// `{"fee_version":"<fee version>","app_version":"<app version>"}` into its fee
+/// and application versions, or returns `None` if `version` is not one.
+fn fee_wrapped_versions(version: &str) -> Option<(String, String)> {
+    let mut fields = wrapped_fields(version)?;
+
+    let fee_version = fields.remove("fee_version")?;
+    let app_version = fields.remove("app_version")?;
+
+    fields.is_empty().then_some((fee_version, app_version))
+}
+
+/// Parses a middleware-wrapped version, i.e. a JSON object of string fields
+/// carrying the application version under `app_version`, or returns `None` if
+/// `version` is not one.
+fn wrapped_fields(version: &str) -> Option<BTreeMap<String, String>> {
+    let fields: BTreeMap<String, String> = serde_json::from_str(version).ok()?;
+
+    fields.contains_key("app_version").then_some(fields)
+}
@@ -5,10 +5,9 @@ use core::fmt;
 use ibc_primitives::prelude::*;
 use ibc_primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
-use ibc_proto::Protobuf;
 use subtle_encoding::{Encoding, Hex};
 
-use super::merkle::MerkleProof;
+use super::merkle::{MerkleProof, ProofLimits};
 use crate::error::CommitmentError;
 
 /// Encodes a commitment root; most often a Merkle tree root hash.
@@ -121,8 +120,7 @@ impl<'a> TryFrom<&'a CommitmentProofBytes> for MerkleProof {
     type Error = CommitmentError;
 
     fn try_from(value: &'a CommitmentProofBytes) -> Result<Self, Self::Error> {
-        Protobuf::<RawMerkleProof>::decode(value.as_ref())
-            .map_err(|e| CommitmentError::DecodingFailure(e.to_string()))
+        MerkleProof::try_from_bytes_with_limits(value, &ProofLimits::default())
     }
 }
 
@@ -23,6 +23,12 @@ pub enum CommitmentError {
     NumberOfKeysMismatch,
     /// invalid merkle proof
     InvalidMerkleProof,
+    /// proof at index `{index}` does not match the expected proof spec
+    ProofSpecMismatch { index: usize },
+    /// proof too large: `{actual}` bytes exceeds the limit of `{max}`
+    ProofTooLarge { actual: usize, max: usize },
+    /// proof too deep: `{depth}` inner nodes exceeds the limit of `{max}`
+    ProofTooDeep { depth: usize, max: usize },
     /// proof verification failed
     VerificationFailure,
     /// encoded commitment prefix is not a valid hex string: `{0}`
@@ -6,13 +6,47 @@ use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleP
 use ibc_proto::ics23::commitment_proof::Proof;
 use ibc_proto::ics23::{
     calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
-    HostFunctionsProvider, NonExistenceProof,
+    ExistenceProof, HostFunctionsProvider, NonExistenceProof,
 };
 
-use crate::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
 
+/// Default upper bound, in bytes, on the size of an encoded merkle proof
+/// accepted for decoding.
+pub const DEFAULT_MAX_PROOF_SIZE: usize = 1 << 20;
+
+/// Default upper bound on the number of inner nodes in the path of any
+/// existence proof contained in a merkle proof.
+///
+/// An IAVL tree holding `2^64` keys is well within this bound.
+pub const DEFAULT_MAX_PROOF_DEPTH: usize = 256;
+
+/// Upper bounds on the merkle proofs accepted for verification, checked when
+/// decoding them with [`MerkleProof::try_from_bytes_with_limits`].
+///
+/// Membership and non-membership verification always reject proofs deeper
+/// than [`DEFAULT_MAX_PROOF_DEPTH`], whatever limits they were decoded with.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProofLimits {
+    /// Maximum size, in bytes, of an encoded merkle proof.
+    pub max_size: usize,
+    /// Maximum number of inner nodes in the path of any existence proof
+    /// contained in a merkle proof.
+    pub max_depth: usize,
+}
+
+impl Default for ProofLimits {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_PROOF_SIZE,
+            max_depth: DEFAULT_MAX_PROOF_DEPTH,
+        }
+    }
+}
+
 pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
     let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
     key_path.append(&mut path);
@@ -53,6 +87,60 @@ impl From<MerkleProof> for RawMerkleProof {
 }
 
 impl MerkleProof {
+    /// Decodes a merkle proof from its encoded bytes, rejecting proofs larger
+    /// than the size limit before decoding them, and proofs deeper than the
+    /// depth limit right after.
+    pub fn try_from_bytes_with_limits(
+        bytes: &CommitmentProofBytes,
+        limits: &ProofLimits,
+    ) -> Result<Self, CommitmentError> {
+        let bytes: &[u8] = bytes.as_ref();
+
+        if bytes.len() > limits.max_size {
+            return Err(CommitmentError::ProofTooLarge {
+                actual: bytes.len(),
+                max: limits.max_size,
+            });
+        }
+
+        let proof: Self = Protobuf::<RawMerkleProof>::decode(bytes)
+            .map_err(|e| CommitmentError::DecodingFailure(e.to_string()))?;
+
+        proof.validate_depth(limits.max_depth)?;
+
+        Ok(proof)
+    }
+
+    /// Checks that none of the contained proofs has a path deeper than
+    /// `max_depth` inner nodes.
+    ///
+    /// Proofs decoded with [`Self::try_from_bytes_with_limits`] are already
+    /// checked.
+    pub fn validate_depth(&self, max_depth: usize) -> Result<(), CommitmentError> {
+        for proof in &self.proofs {
+            let depth = match &proof.proof {
+                Some(Proof::Exist(existence_proof)) => existence_proof.path.len(),
+                Some(Proof::Nonexist(non_existence_proof)) => non_existence_proof
+                    .left
+                    .iter()
+                    .chain(non_existence_proof.right.iter())
+                    .map(|existence_proof| existence_proof.path.len())
+                    .max()
+                    .unwrap_or_default(),
+                _ => 0,
+            };
+
+            if depth > max_depth {
+                return Err(CommitmentError::ProofTooDeep {
+                    depth,
+                    max: max_depth,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn verify_membership<H: HostFunctionsProvider>(
         &self,
         specs: &ProofSpecs,
@@ -68,6 +156,7 @@ impl MerkleProof {
         if root.hash.is_empty() {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
+        self.validate_depth(DEFAULT_MAX_PROOF_DEPTH)?;
         let num = self.proofs.len();
         let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
         if ics23_specs.len() != num {
@@ -83,11 +172,12 @@ impl MerkleProof {
         let mut subroot = value.clone();
         let mut value = value;
         // keys are represented from root-to-leaf
-        for ((proof, spec), key) in self
+        for (index, ((proof, spec), key)) in self
             .proofs
             .iter()
             .zip(ics23_specs.iter())
             .zip(keys.key_path.iter().rev())
+            .enumerate()
             .skip(
                 start_index
                     .try_into()
@@ -100,6 +190,9 @@ impl MerkleProof {
                         .map_err(|_| CommitmentError::InvalidMerkleProof)?;
 
                     if !verify_membership::<H>(proof, spec, &subroot, key.as_bytes(), &value) {
+                        if violates_spec::<H>(existence_proof, spec) {
+                            return Err(CommitmentError::ProofSpecMismatch { index });
+                        }
                         return Err(CommitmentError::VerificationFailure);
                     }
                     value.clone_from(&subroot);
@@ -128,6 +221,7 @@ impl MerkleProof {
         if root.hash.is_empty() {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
+        self.validate_depth(DEFAULT_MAX_PROOF_DEPTH)?;
         let num = self.proofs.len();
         let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
         if ics23_specs.len() != num {
@@ -155,6 +249,15 @@ impl MerkleProof {
                 let subroot = calculate_non_existence_root::<H>(non_existence_proof)?;
 
                 if !verify_non_membership::<H>(proof, spec, &subroot, key.as_bytes()) {
+                    let neighbour_violates_spec = non_existence_proof
+                        .left
+                        .iter()
+                        .chain(non_existence_proof.right.iter())
+                        .any(|neighbour| violates_spec::<H>(neighbour, spec));
+                    if neighbour_violates_spec {
+                        // The non-existence proof is always the first one.
+                        return Err(CommitmentError::ProofSpecMismatch { index: 0 });
+                    }
                     return Err(CommitmentError::VerificationFailure);
                 }
 
@@ -166,6 +269,27 @@ impl MerkleProof {
     }
 }
 
+/// Returns `true` if `ics23` rejects `proof` under `spec` even though it
+/// proves its own key and value against the root it hashes to, i.e. if the
+/// proof was built for a tree shaped differently than `spec` describes.
+///
+/// This tells a proof checked against the wrong specs apart from a proof that
+/// simply fails to verify, while leaving the spec checks themselves to `ics23`.
+fn violates_spec<H: HostFunctionsProvider>(
+    proof: &ExistenceProof,
+    spec: &ics23::ProofSpec,
+) -> bool {
+    let Ok(root) = calculate_existence_root::<H>(proof) else {
+        return false;
+    };
+
+    let commitment_proof = CommitmentProof {
+        proof: Some(Proof::Exist(proof.clone())),
+    };
+
+    !verify_membership::<H>(&commitment_proof, spec, &root, &proof.key, &proof.value)
+}
+
 // TODO move to ics23
 fn calculate_non_existence_root<H: HostFunctionsProvider>(
     proof: &NonExistenceProof,
@@ -178,3 +302,143 @@ fn calculate_non_existence_root<H: HostFunctionsProvider>(
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ics23::{ExistenceProof, HostFunctionsManager, InnerOp};
+
+    use super::*;
+
+    #[test]
+    fn test_over_deep_proof_rejected() {
+        let existence_proof = ExistenceProof {
+            key: b"key".to_vec(),
+            value: b"value".to_vec(),
+            leaf: None,
+            path: vec![InnerOp::default(); DEFAULT_MAX_PROOF_DEPTH + 1],
+        };
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+        let bytes = CommitmentProofBytes::try_from(proof.encode_vec()).expect("no error");
+
+        let res = MerkleProof::try_from(&bytes);
+
+        assert!(matches!(
+            res,
+            Err(CommitmentError::ProofTooDeep { depth, max })
+                if depth == DEFAULT_MAX_PROOF_DEPTH + 1 && max == DEFAULT_MAX_PROOF_DEPTH
+        ));
+
+        // A higher depth limit accepts the proof.
+        let limits = ProofLimits {
+            max_depth: DEFAULT_MAX_PROOF_DEPTH + 1,
+            ..ProofLimits::default()
+        };
+        let res = MerkleProof::try_from_bytes_with_limits(&bytes, &limits);
+
+        assert_eq!(res.ok(), Some(proof));
+    }
+
+    #[test]
+    fn test_proof_spec_mismatch_detected() {
+        let tendermint_spec = ics23::tendermint_spec();
+        let leaf = tendermint_spec
+            .leaf_spec
+            .clone()
+            .expect("tendermint spec has a leaf spec");
+        let existence_proof = ExistenceProof {
+            key: b"key".to_vec(),
+            value: b"value".to_vec(),
+            leaf: Some(leaf),
+            path: vec![],
+        };
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof.clone())),
+            }],
+        };
+        let root = MerkleRoot {
+            hash: calculate_existence_root::<HostFunctionsManager>(&existence_proof)
+                .expect("no error"),
+        };
+        let keys = MerklePath {
+            key_path: vec!["key".to_string()],
+        };
+
+        let matching_specs: ProofSpecs =
+            vec![tendermint_spec.clone()].try_into().expect("no error");
+        let res = proof.verify_membership::<HostFunctionsManager>(
+            &matching_specs,
+            root.clone(),
+            keys.clone(),
+            b"value".to_vec(),
+            0,
+        );
+        assert!(res.is_ok(), "{res:?}");
+
+        // Same tree shape, but leaves hashed with a different function.
+        let mut wrong_spec = tendermint_spec;
+        if let Some(leaf_spec) = wrong_spec.leaf_spec.as_mut() {
+            leaf_spec.hash = ics23::HashOp::Sha512.into();
+        }
+        let wrong_specs: ProofSpecs = vec![wrong_spec].try_into().expect("no error");
+        let res = proof.verify_membership::<HostFunctionsManager>(
+            &wrong_specs,
+            root,
+            keys,
+            b"value".to_vec(),
+            0,
+        );
+        assert!(matches!(
+            res,
+            Err(CommitmentError::ProofSpecMismatch { index: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_oversized_proof_rejected_before_decoding() {
+        let bytes = CommitmentProofBytes::try_from(vec![0xff; 16]).expect("no error");
+
+        let limits = ProofLimits {
+            max_size: 8,
+            ..ProofLimits::default()
+        };
+        let res = MerkleProof::try_from_bytes_with_limits(&bytes, &limits);
+
+        assert!(matches!(
+            res,
+            Err(CommitmentError::ProofTooLarge { actual: 16, max: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_proof_rejected_on_decoding() {
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(ExistenceProof {
+                    key: b"key".to_vec(),
+                    value: b"value".to_vec(),
+                    leaf: ics23::iavl_spec().leaf_spec,
+                    path: vec![],
+                })),
+            }],
+        };
+        let mut encoded = proof.encode_vec();
+        encoded.truncate(encoded.len() / 2);
+        let bytes = CommitmentProofBytes::try_from(encoded).expect("no error");
+
+        let res = MerkleProof::try_from(&bytes);
+
+        match res {
+            Err(err @ CommitmentError::DecodingFailure(_)) => {
+                assert!(err
+                    .to_string()
+                    .starts_with("decoding commitment proof bytes failed"));
+            }
+            res => panic!("expected a decoding failure, got {res:?}"),
+        }
+    }
+}
@@ -4,19 +4,27 @@ use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client_context::prelude::*;
-use ibc_core_client_types::Height;
+use ibc_core_client_types::events::ClientExpired;
+use ibc_core_client_types::{Height, Status};
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::proto::v1::ClientPaths;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
-use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_connection_types::{ConnectionEnd, State as ConnectionState};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_handler_types::msgs::MsgKind;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, PortId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, AckedSequencePath, ChannelEndPath, ClientConnectionPath, ClientStatusPath,
+    CommitmentPath, ConnectionPath, LastRecvProofHeightPath, NextChannelSequencePath,
+    NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
+use ibc_core_router_types::module::ModuleId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Signer, Timestamp};
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::{Signer, Timestamp, ToVec};
 
 use crate::utils::calculate_block_delay;
 
@@ -50,6 +58,15 @@ pub trait ValidationContext {
     /// `ExecutionContext::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the latest height of the given client, i.e. the latest height
+    /// of the counterparty chain it tracks.
+    fn client_latest_height(&self, client_id: &ClientId) -> Result<Height, ContextError> {
+        Ok(self
+            .get_client_validation_context()
+            .client_state(client_id)?
+            .latest_height())
+    }
+
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
@@ -73,6 +90,19 @@ pub trait ValidationContext {
     /// Returns a counter on how many connections have been created thus far.
     fn connection_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the identifiers of the connections stored under the given
+    /// client connection path.
+    ///
+    /// Used to report the full list of connections of a client to
+    /// [`ExecutionContext::on_store_write`]. Defaults to an empty list, in
+    /// which case only the connection being added is reported.
+    fn client_connections(
+        &self,
+        _client_connection_path: &ClientConnectionPath,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Ok(Vec::new())
+    }
+
     /// Function required by ICS-03. Returns the list of all possible versions that the connection
     /// handshake protocol supports.
     fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
@@ -121,6 +151,35 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
+    /// Returns the proof height of the last packet received on the ordered
+    /// channel at the given store path, if any.
+    ///
+    /// Channel closing proofs below this height are rejected, so that an old
+    /// proof cannot be replayed once packets were received past it. Defaults
+    /// to `None`, which disables the check.
+    fn last_recv_proof_height(
+        &self,
+        _seq_recv_path: &SeqRecvPath,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns true if the acknowledgement of the packet with the given
+    /// sequence, sent on the unordered channel at the given store path, was
+    /// already processed.
+    ///
+    /// A duplicate acknowledgement is then rejected with
+    /// `PacketError::AcknowledgementExists`. Defaults to `false`, in which case
+    /// a duplicate acknowledgement is a no-op, as its packet commitment is
+    /// already deleted.
+    fn is_sequence_acknowledged(
+        &self,
+        _seq_ack_path: &SeqAckPath,
+        _sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
@@ -135,9 +194,110 @@ pub trait ValidationContext {
         calculate_block_delay(delay_period_time, &self.max_expected_time_per_block())
     }
 
+    /// Returns the minimum delay period the host accepts for new connections.
+    ///
+    /// Connection handshakes proposing a shorter delay period are rejected
+    /// in `ConnOpenInit` and `ConnOpenTry`. Defaults to zero, i.e. no minimum.
+    fn min_connection_delay(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Returns the maximum number of blocks by which the proof height of a
+    /// connection handshake message may lag the latest height of the client
+    /// verifying it.
+    ///
+    /// `ConnOpenTry` and `ConnOpenAck` messages carrying staler proofs are
+    /// rejected. Defaults to `None`, i.e. no bound.
+    fn max_proof_staleness(&self) -> Option<u64> {
+        None
+    }
+
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Authorizes the `signer` to submit a message of the given [`MsgKind`].
+    ///
+    /// Called at the beginning of each handler's validation, this hook lets
+    /// hosts gate sensitive operations, e.g. restricting client recovery to a
+    /// governance account. By default, all signers are authorized.
+    fn authorize(&self, _signer: &Signer, _msg_kind: &MsgKind) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Returns the identifier of the module that bound the given port, if any.
+    ///
+    /// When set, channel handshake callbacks on that port are routed to this
+    /// module. Otherwise, routing falls back to the router's port lookup.
+    fn port_owner(&self, _port_id: &PortId) -> Option<ModuleId> {
+        None
+    }
+
+    /// Returns all the ports bound by modules on the host.
+    fn bound_ports(&self) -> Vec<PortId> {
+        Vec::new()
+    }
+
+    /// Returns the raw bytes stored at the given path in the host's IBC store,
+    /// if any.
+    ///
+    /// This allows clients tracking the host itself, such as a localhost
+    /// client, to check (non-)membership by reading the local store directly
+    /// instead of verifying a Merkle proof.
+    fn get_raw_value(&self, _path: &Path) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Returns the status of the given client as last recorded by
+    /// [`ExecutionContext::check_and_emit_client_status`], if any.
+    ///
+    /// Defaults to `None`, in which case an expired client is reported on
+    /// every check.
+    fn last_client_status(&self, _client_id: &ClientId) -> Result<Option<Status>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the identifier of an existing client created from the given
+    /// client and consensus states, if any.
+    ///
+    /// Hosts may implement this to de-duplicate create-client messages: when a
+    /// match is found, `MsgCreateClient` reuses the existing identifier instead
+    /// of allocating a new one. Defaults to `None`, meaning a new client is
+    /// always created.
+    fn find_matching_client(
+        &self,
+        _client_state: &Any,
+        _consensus_state: &Any,
+    ) -> Result<Option<ClientId>, ContextError> {
+        Ok(None)
+    }
+
+    /// Validates a path of connection hops, e.g. for a multi-hop channel.
+    ///
+    /// Each connection must exist and be open, and its counterparty
+    /// connection must be the next hop in `connection_hops`.
+    fn validate_connection_hops(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<(), ContextError> {
+        for (index, conn_id) in connection_hops.iter().enumerate() {
+            let conn_end = self.connection_end(conn_id)?;
+
+            conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+            if let Some(next_hop) = connection_hops.get(index + 1) {
+                if conn_end.counterparty().connection_id() != Some(next_hop) {
+                    return Err(ConnectionError::InconsistentHops {
+                        connection_id: conn_id.clone(),
+                        next_hop: next_hop.clone(),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -229,17 +389,333 @@ pub trait ExecutionContext: ValidationContext {
         seq: Sequence,
     ) -> Result<(), ContextError>;
 
+    /// Records the proof height of the last packet received on the ordered
+    /// channel at the given store path.
+    ///
+    /// Hosts implementing [`ValidationContext::last_recv_proof_height`] are
+    /// expected to persist it here.
+    fn store_last_recv_proof_height(
+        &mut self,
+        _seq_recv_path: &SeqRecvPath,
+        _height: Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Called upon channel identifier creation (Init or Try message processing).
     /// Increases the counter which keeps track of how many channels have been created.
     fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Records that the acknowledgement of the packet with the given sequence,
+    /// sent on the unordered channel at the given store path, was processed.
+    ///
+    /// Hosts implementing [`ValidationContext::is_sequence_acknowledged`] are
+    /// expected to persist it here.
+    fn store_acknowledged_sequence(
+        &mut self,
+        _seq_ack_path: &SeqAckPath,
+        _sequence: Sequence,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Emit the given IBC event
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
     /// Log the given message.
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Records the status of the given client observed by
+    /// [`ExecutionContext::check_and_emit_client_status`].
+    fn store_client_status(
+        &mut self,
+        _client_id: &ClientId,
+        _status: Status,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Checks the status of the given client and emits a `ClientExpired`
+    /// event if it became expired since the last check.
+    ///
+    /// Expiry is time-based and not triggered by any message, so hosts are
+    /// expected to call this themselves, e.g. at the start of each block.
+    fn check_and_emit_client_status(
+        &mut self,
+        client_id: &ClientId,
+    ) -> Result<Status, ContextError> {
+        let client_val_ctx = self.get_client_validation_context();
+        let client_state = client_val_ctx.client_state(client_id)?;
+        let status = client_state.status(client_val_ctx, client_id)?;
+
+        if status.is_expired() && self.last_client_status(client_id)? != Some(Status::Expired) {
+            self.emit_ibc_event(IbcEvent::ClientExpired(ClientExpired::new(
+                client_id.clone(),
+                client_state.client_type(),
+            )))?;
+        }
+
+        self.store_client_status_observed(client_id, status)?;
+
+        Ok(status)
+    }
+
+    /// Called right after a value is written under `path` through
+    /// [`ObservedExecutionContext`], allowing hosts that maintain their own
+    /// commitment tree to mirror the write.
+    ///
+    /// `value` is encoded as ibc-go commits it:
+    /// - connection and channel ends are protobuf-encoded;
+    /// - client connections are the protobuf-encoded `ClientPaths` listing
+    ///   all the connections of the client;
+    /// - next sequence numbers and identifier counters are big-endian `u64`s;
+    /// - packet and acknowledgement commitments are passed as is;
+    /// - packet receipts are a single `1` byte.
+    ///
+    /// Host-local bookkeeping is reported under paths that are not provable:
+    /// proof heights and client statuses as their string representation,
+    /// acknowledged sequences as a single `1` byte.
+    ///
+    /// Defaults to forwarding to [`ClientExecutionContext::on_store_write`],
+    /// which also observes the writes of light clients, so that hosts can
+    /// observe all writes in one place.
+    fn on_store_write(&mut self, path: &Path, value: &[u8]) {
+        self.get_client_execution_context()
+            .on_store_write(path, value)
+    }
+
+    /// Called right after the value under `path` is deleted through
+    /// [`ObservedExecutionContext`].
+    ///
+    /// Defaults to forwarding to [`ClientExecutionContext::on_store_delete`].
+    fn on_store_delete(&mut self, path: &Path) {
+        self.get_client_execution_context().on_store_delete(path)
+    }
+}
+
+/// Store methods of [`ExecutionContext`] that report every write to
+/// [`ExecutionContext::on_store_write`] and every deletion to
+/// [`ExecutionContext::on_store_delete`].
+///
+/// The core handlers mutate the store through these methods only. The trait
+/// is implemented for all execution contexts, so the reporting cannot be
+/// bypassed by overriding.
+pub trait ObservedExecutionContext: ExecutionContext {
+    /// Increases the client counter and reports its new value.
+    fn increase_client_counter_observed(&mut self) -> Result<(), ContextError> {
+        self.increase_client_counter()?;
+        let next_client_sequence = self.client_counter()?;
+        self.on_store_write(
+            &NextClientSequencePath.into(),
+            &next_client_sequence.to_be_bytes(),
+        );
+        Ok(())
+    }
+
+    /// Stores the connection end and reports the write.
+    fn store_connection_observed(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        let value = connection_end.clone().encode_vec();
+        self.store_connection(connection_path, connection_end)?;
+        self.on_store_write(&connection_path.clone().into(), &value);
+        Ok(())
+    }
+
+    /// Adds the connection to the client and reports the resulting list of
+    /// connections of the client.
+    fn store_connection_to_client_observed(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.store_connection_to_client(client_connection_path, conn_id.clone())?;
+
+        let mut conn_ids = self.client_connections(client_connection_path)?;
+        if !conn_ids.contains(&conn_id) {
+            conn_ids.push(conn_id);
+        }
+        let value = ClientPaths {
+            paths: conn_ids.iter().map(ToString::to_string).collect(),
+        }
+        .to_vec();
+        self.on_store_write(&client_connection_path.clone().into(), &value);
+
+        Ok(())
+    }
+
+    /// Increases the connection counter and reports its new value.
+    fn increase_connection_counter_observed(&mut self) -> Result<(), ContextError> {
+        self.increase_connection_counter()?;
+        let next_connection_sequence = self.connection_counter()?;
+        self.on_store_write(
+            &NextConnectionSequencePath.into(),
+            &next_connection_sequence.to_be_bytes(),
+        );
+        Ok(())
+    }
+
+    /// Stores the packet commitment and reports the write.
+    fn store_packet_commitment_observed(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        let value = commitment.clone().into_vec();
+        self.store_packet_commitment(commitment_path, commitment)?;
+        self.on_store_write(&commitment_path.clone().into(), &value);
+        Ok(())
+    }
+
+    /// Deletes the packet commitment and reports the deletion.
+    fn delete_packet_commitment_observed(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.delete_packet_commitment(commitment_path)?;
+        self.on_store_delete(&commitment_path.clone().into());
+        Ok(())
+    }
+
+    /// Stores the packet receipt and reports the write.
+    fn store_packet_receipt_observed(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.store_packet_receipt(receipt_path, receipt)?;
+        self.on_store_write(&receipt_path.clone().into(), &[1]);
+        Ok(())
+    }
+
+    /// Stores the acknowledgement commitment and reports the write.
+    fn store_packet_acknowledgement_observed(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        let value = ack_commitment.clone().into_vec();
+        self.store_packet_acknowledgement(ack_path, ack_commitment)?;
+        self.on_store_write(&ack_path.clone().into(), &value);
+        Ok(())
+    }
+
+    /// Deletes the acknowledgement commitment and reports the deletion.
+    fn delete_packet_acknowledgement_observed(
+        &mut self,
+        ack_path: &AckPath,
+    ) -> Result<(), ContextError> {
+        self.delete_packet_acknowledgement(ack_path)?;
+        self.on_store_delete(&ack_path.clone().into());
+        Ok(())
+    }
+
+    /// Stores the channel end and reports the write.
+    fn store_channel_observed(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        let value = channel_end.clone().encode_vec();
+        self.store_channel(channel_end_path, channel_end)?;
+        self.on_store_write(&channel_end_path.clone().into(), &value);
+        Ok(())
+    }
+
+    /// Stores the `nextSequenceSend` number and reports the write.
+    fn store_next_sequence_send_observed(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store_next_sequence_send(seq_send_path, seq)?;
+        self.on_store_write(&seq_send_path.clone().into(), &seq.to_vec());
+        Ok(())
+    }
+
+    /// Stores the `nextSequenceRecv` number and reports the write.
+    fn store_next_sequence_recv_observed(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store_next_sequence_recv(seq_recv_path, seq)?;
+        self.on_store_write(&seq_recv_path.clone().into(), &seq.to_vec());
+        Ok(())
+    }
+
+    /// Stores the `nextSequenceAck` number and reports the write.
+    fn store_next_sequence_ack_observed(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store_next_sequence_ack(seq_ack_path, seq)?;
+        self.on_store_write(&seq_ack_path.clone().into(), &seq.to_vec());
+        Ok(())
+    }
+
+    /// Records the proof height of the last received packet and reports the
+    /// write under the channel's [`LastRecvProofHeightPath`].
+    fn store_last_recv_proof_height_observed(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.store_last_recv_proof_height(seq_recv_path, height)?;
+        self.on_store_write(
+            &LastRecvProofHeightPath::new(&seq_recv_path.0, &seq_recv_path.1).into(),
+            height.to_string().as_bytes(),
+        );
+        Ok(())
+    }
+
+    /// Increases the channel counter and reports its new value.
+    fn increase_channel_counter_observed(&mut self) -> Result<(), ContextError> {
+        self.increase_channel_counter()?;
+        let next_channel_sequence = self.channel_counter()?;
+        self.on_store_write(
+            &NextChannelSequencePath.into(),
+            &next_channel_sequence.to_be_bytes(),
+        );
+        Ok(())
+    }
+
+    /// Records the acknowledged sequence and reports the write under its
+    /// [`AckedSequencePath`].
+    fn store_acknowledged_sequence_observed(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        sequence: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store_acknowledged_sequence(seq_ack_path, sequence)?;
+        self.on_store_write(
+            &AckedSequencePath::new(&seq_ack_path.0, &seq_ack_path.1, sequence).into(),
+            &[1],
+        );
+        Ok(())
+    }
+
+    /// Records the client status and reports the write under the client's
+    /// [`ClientStatusPath`].
+    fn store_client_status_observed(
+        &mut self,
+        client_id: &ClientId,
+        status: Status,
+    ) -> Result<(), ContextError> {
+        self.store_client_status(client_id, status)?;
+        self.on_store_write(
+            &ClientStatusPath::new(client_id.clone()).into(),
+            status.to_string().as_bytes(),
+        );
+        Ok(())
+    }
 }
 
+impl<T> ObservedExecutionContext for T where T: ExecutionContext + ?Sized {}
+
 /// Convenient type alias for `ClientStateRef`, providing access to client
 /// validation methods within the context.
 pub type ClientStateRef<Ctx> =
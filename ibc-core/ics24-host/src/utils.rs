@@ -1,25 +1,10 @@
-use core::time::Duration;
-
-pub fn calculate_block_delay(
-    delay_period_time: &Duration,
-    max_expected_time_per_block: &Duration,
-) -> u64 {
-    let delay_period_time = delay_period_time.as_secs();
-    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
-    if max_expected_time_per_block == 0 {
-        return 0;
-    }
-    if delay_period_time % max_expected_time_per_block == 0 {
-        return delay_period_time / max_expected_time_per_block;
-    }
-
-    // TODO: Use `u64::div_ceil` here instead
-    (delay_period_time / max_expected_time_per_block) + 1
-}
+pub use ibc_core_connection_types::calculate_block_delay;
 
 #[cfg(test)]
 mod tests {
 
+    use core::time::Duration;
+
     use rstest::rstest;
 
     use super::*;
@@ -94,14 +94,54 @@ impl ChainId {
     /// assert_eq!(chain_id.revision_number(), u64::MAX);
     /// ```
     pub fn increment_revision_number(&mut self) -> Result<(), IdentifierError> {
-        let (chain_name, _) = self.split_chain_id()?;
-        let inc_revision_number = self
+        *self = self.increment_revision()?;
+        Ok(())
+    }
+
+    /// Returns the `ChainId` of the same chain at the next revision, e.g.
+    /// after an upgrade. Fails under the same conditions as
+    /// [`Self::increment_revision_number`].
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// let chain_id = ChainId::new("chainA-1").unwrap();
+    /// assert_eq!(chain_id.increment_revision().unwrap().as_str(), "chainA-2");
+    /// ```
+    pub fn increment_revision(&self) -> Result<Self, IdentifierError> {
+        let revision_number = self
             .revision_number
             .checked_add(1)
             .ok_or(IdentifierError::RevisionNumberOverflow)?;
-        self.id = format!("{}-{}", chain_name, inc_revision_number);
-        self.revision_number = inc_revision_number;
-        Ok(())
+        self.with_revision_number(revision_number)
+    }
+
+    /// Returns the `ChainId` with the same chain name and the given revision
+    /// number. Fails if the chain identifier is not in
+    /// `{chain_name}-{revision_number}` format, or if the resulting
+    /// identifier is not a valid one with those components.
+    ///
+    /// ```
+    /// use ibc_core_host_types::identifiers::ChainId;
+    ///
+    /// let chain_id = ChainId::new("chainA-1").unwrap();
+    /// let upgraded = chain_id.with_revision_number(5).unwrap();
+    /// assert_eq!(upgraded.as_str(), "chainA-5");
+    /// assert_eq!(upgraded.revision_number(), 5);
+    ///
+    /// assert!(ChainId::new("chainA").unwrap().with_revision_number(1).is_err());
+    /// ```
+    pub fn with_revision_number(&self, revision_number: u64) -> Result<Self, IdentifierError> {
+        let (chain_name, _) = self.split_chain_id()?;
+        let chain_id = Self::new(&format!("{chain_name}-{revision_number}"))?;
+
+        if chain_id.split_chain_id()? != (chain_name, revision_number) {
+            return Err(IdentifierError::UnformattedRevisionNumber {
+                chain_id: chain_id.id,
+            });
+        }
+
+        Ok(chain_id)
     }
 
     /// A convenient method to check if the `ChainId` forms a valid identifier
@@ -402,6 +442,44 @@ mod tests {
         assert_eq!(chain_id.as_str(), "chainA-3");
     }
 
+    #[rstest]
+    #[case("chainA-1", 0, "chainA-0")]
+    #[case("chainA-1", 7, "chainA-7")]
+    #[case("chainA-1-2", 10, "chainA-1-10")]
+    #[case("chainA-0", u64::MAX, &format!("chainA-{}", u64::MAX))]
+    fn test_with_revision_number(
+        #[case] raw_chain_id: &str,
+        #[case] revision_number: u64,
+        #[case] expected: &str,
+    ) {
+        let chain_id = ChainId::new(raw_chain_id)
+            .unwrap()
+            .with_revision_number(revision_number)
+            .unwrap();
+
+        assert_eq!(chain_id, ChainId::new(expected).unwrap());
+        assert_eq!(chain_id.revision_number(), revision_number);
+    }
+
+    #[test]
+    fn test_increment_revision() {
+        let chain_id = ChainId::new("chainA-1").unwrap();
+
+        let upgraded = chain_id.increment_revision().unwrap();
+        assert_eq!(upgraded.as_str(), "chainA-2");
+        assert_eq!(upgraded.revision_number(), 2);
+        assert_eq!(chain_id.as_str(), "chainA-1");
+
+        assert!(ChainId::new("chainA")
+            .unwrap()
+            .increment_revision()
+            .is_err());
+        assert!(ChainId::new(&format!("chainA-{}", u64::MAX))
+            .unwrap()
+            .increment_revision()
+            .is_err());
+    }
+
     #[test]
     fn test_failed_inc_revision_number() {
         let mut chain_id = ChainId::new("chainA").unwrap();
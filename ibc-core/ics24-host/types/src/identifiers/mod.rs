@@ -14,4 +14,4 @@ pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
 pub use port_id::PortId;
-pub use sequence::Sequence;
+pub use sequence::{Sequence, SequenceRange, SequenceRangeIter};
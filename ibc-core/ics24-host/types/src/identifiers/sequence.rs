@@ -72,3 +72,218 @@ impl core::fmt::Display for Sequence {
         write!(f, "{}", self.0)
     }
 }
+
+/// A half-open range of packet sequences, from `start` (inclusive) to `end`
+/// (exclusive).
+///
+/// The range always satisfies `start <= end`, which deserialization checks
+/// as well.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SequenceRange {
+    start: Sequence,
+    end: Sequence,
+}
+
+impl SequenceRange {
+    /// Creates the range of sequences from `start` up to, but excluding,
+    /// `end`. The range is empty if `end` is not greater than `start`.
+    pub fn new(start: Sequence, end: Sequence) -> Self {
+        Self {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// Returns the first sequence of the range.
+    pub fn start(&self) -> Sequence {
+        self.start
+    }
+
+    /// Returns the sequence right after the last one of the range.
+    pub fn end(&self) -> Sequence {
+        self.end
+    }
+
+    /// Returns the number of sequences in the range.
+    pub fn len(&self) -> u64 {
+        self.end.0 - self.start.0
+    }
+
+    /// Returns `true` if the range contains no sequence.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if the given sequence belongs to the range.
+    pub fn contains(&self, sequence: &Sequence) -> bool {
+        self.start <= *sequence && *sequence < self.end
+    }
+
+    /// Iterates over the sequences of the range, in increasing order.
+    pub fn iter(&self) -> SequenceRangeIter {
+        SequenceRangeIter(self.start.0..self.end.0)
+    }
+
+    /// Returns the sequences belonging to both ranges.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Returns the sequences belonging to either range, or `None` if the
+    /// ranges are neither overlapping nor adjacent, as their union is then
+    /// not a range.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+
+        if other.is_empty() {
+            return Some(*self);
+        }
+
+        if self.start > other.end || other.start > self.end {
+            return None;
+        }
+
+        Some(Self::new(
+            self.start.min(other.start),
+            self.end.max(other.end),
+        ))
+    }
+
+    /// Returns `true` if every sequence of this range belongs to `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.is_empty() || (other.start <= self.start && self.end <= other.end)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SequenceRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct RawSequenceRange {
+            start: Sequence,
+            end: Sequence,
+        }
+
+        let RawSequenceRange { start, end } = RawSequenceRange::deserialize(deserializer)?;
+
+        if end < start {
+            return Err(serde::de::Error::custom(format!(
+                "sequence range end {end} is lower than its start {start}"
+            )));
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+impl IntoIterator for SequenceRange {
+    type Item = Sequence;
+    type IntoIter = SequenceRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the sequences of a [`SequenceRange`].
+#[derive(Clone, Debug)]
+pub struct SequenceRangeIter(core::ops::Range<u64>);
+
+impl Iterator for SequenceRangeIter {
+    type Item = Sequence;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Sequence)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::try_from(self.0.end - self.0.start).unwrap_or(usize::MAX);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for SequenceRangeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Sequence)
+    }
+}
+
+impl ExactSizeIterator for SequenceRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn range(start: u64, end: u64) -> SequenceRange {
+        SequenceRange::new(start.into(), end.into())
+    }
+
+    #[test]
+    fn test_sequence_range_iter() {
+        let range = range(3, 6);
+
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.iter().len(), 3);
+        assert_eq!(
+            range.iter().collect::<Vec<_>>(),
+            [3, 4, 5].map(Sequence::from)
+        );
+        assert_eq!(
+            range.iter().rev().collect::<Vec<_>>(),
+            [5, 4, 3].map(Sequence::from)
+        );
+    }
+
+    #[rstest]
+    #[case(2, false)]
+    #[case(3, true)]
+    #[case(5, true)]
+    #[case(6, false)]
+    fn test_sequence_range_contains(#[case] sequence: u64, #[case] expected: bool) {
+        assert_eq!(range(3, 6).contains(&sequence.into()), expected);
+    }
+
+    #[rstest]
+    #[case(range(4, 4))]
+    #[case(range(6, 3))]
+    fn test_empty_sequence_range(#[case] range: SequenceRange) {
+        assert!(range.is_empty());
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.iter().next(), None);
+        assert!(!range.contains(&range.start()));
+    }
+
+    #[test]
+    fn test_sequence_range_set_operations() {
+        assert_eq!(range(1, 5).intersection(&range(3, 8)), range(3, 5));
+        assert!(range(1, 3).intersection(&range(5, 8)).is_empty());
+
+        assert_eq!(range(1, 5).union(&range(3, 8)), Some(range(1, 8)));
+        assert_eq!(range(1, 3).union(&range(3, 8)), Some(range(1, 8)));
+        assert_eq!(range(1, 3).union(&range(4, 8)), None);
+        assert_eq!(range(4, 4).union(&range(1, 3)), Some(range(1, 3)));
+
+        assert!(range(3, 5).is_subset(&range(1, 8)));
+        assert!(!range(3, 9).is_subset(&range(1, 8)));
+        assert!(range(9, 9).is_subset(&range(1, 8)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sequence_range_deserialization() {
+        let range: SequenceRange = serde_json::from_str(r#"{"start":3,"end":6}"#).unwrap();
+        assert_eq!(range, self::range(3, 6));
+
+        let res = serde_json::from_str::<SequenceRange>(r#"{"start":6,"end":3}"#);
+        assert!(res.is_err(), "{res:?}");
+    }
+}
@@ -34,6 +34,14 @@ pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
 pub const PROCESSED_HEIGHT: &str = "processedHeight";
 
+/// Keys of host-local bookkeeping, which is not part of the provable store
+/// - The key identifying the last observed status of a client
+pub const CLIENT_STATUS: &str = "status";
+/// - The prefix of the proof heights of the last packets received on ordered channels
+pub const LAST_RECV_PROOF_HEIGHT_PREFIX: &str = "lastRecvProofHeight";
+/// - The prefix of the acknowledged packet sequences of unordered channels
+pub const ACKED_SEQUENCE_PREFIX: &str = "ackedSequences";
+
 /// ABCI client upgrade keys
 /// - The key identifying the upgraded IBC state within the upgrade sub-store
 pub const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
@@ -63,6 +71,9 @@ pub enum Path {
     Ack(AckPath),
     Receipt(ReceiptPath),
     UpgradeClient(UpgradeClientPath),
+    ClientStatus(ClientStatusPath),
+    LastRecvProofHeight(LastRecvProofHeightPath),
+    AckedSequence(AckedSequencePath),
 }
 
 #[cfg_attr(
@@ -565,6 +576,24 @@ impl CommitmentPath {
     }
 }
 
+/// Parses a packet commitment path of the form
+/// `commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}`,
+/// the inverse of its `Display` implementation.
+impl FromStr for CommitmentPath {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components: Vec<&str> = s.split('/').collect();
+
+        match parse_commitments(&components) {
+            Some(Path::Commitment(path)) => Ok(path),
+            _ => Err(PathError::ParseFailure {
+                path: s.to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -665,6 +694,96 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Path under which the host records the last observed status of a client.
+///
+/// Not part of the specification nor of the provable store.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CLIENT_PREFIX}/{_0}/{CLIENT_STATUS}")]
+pub struct ClientStatusPath(pub ClientId);
+
+impl ClientStatusPath {
+    pub fn new(client_id: ClientId) -> ClientStatusPath {
+        ClientStatusPath(client_id)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Path under which the host records the proof height of the last packet
+/// received on an ordered channel.
+///
+/// Not part of the specification nor of the provable store.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{LAST_RECV_PROOF_HEIGHT_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct LastRecvProofHeightPath(pub PortId, pub ChannelId);
+
+impl LastRecvProofHeightPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> LastRecvProofHeightPath {
+        LastRecvProofHeightPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Path under which the host records that the acknowledgement of a packet
+/// sent on an unordered channel was processed.
+///
+/// Not part of the specification nor of the provable store.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{ACKED_SEQUENCE_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
+pub struct AckedSequencePath {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl AckedSequencePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> AckedSequencePath {
+        AckedSequencePath {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -689,7 +808,14 @@ enum SubPath {
 impl Path {
     /// Indication if the path is provable.
     pub fn is_provable(&self) -> bool {
-        !matches!(&self, Path::ClientConnection(_) | Path::Ports(_))
+        !matches!(
+            &self,
+            Path::ClientConnection(_)
+                | Path::Ports(_)
+                | Path::ClientStatus(_)
+                | Path::LastRecvProofHeight(_)
+                | Path::AckedSequence(_)
+        )
     }
 
     /// into_bytes implementation
@@ -723,6 +849,7 @@ impl FromStr for Path {
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
+            .or_else(|| parse_acked_sequences(&components))
             .or_else(|| parse_upgrades(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
@@ -756,6 +883,7 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         match components[2] {
             CLIENT_STATE => Some(ClientStatePath(client_id).into()),
             CONNECTION_PREFIX => Some(ClientConnectionPath(client_id).into()),
+            CLIENT_STATUS => Some(ClientStatusPath(client_id).into()),
             _ => None,
         }
     } else if components.len() == 4 || components.len() == 5 {
@@ -932,6 +1060,7 @@ fn parse_seqs(components: &[&str]) -> Option<Path> {
         NEXT_SEQ_SEND_PREFIX => Some(SeqSendPath(port_id, channel_id).into()),
         NEXT_SEQ_RECV_PREFIX => Some(SeqRecvPath(port_id, channel_id).into()),
         NEXT_SEQ_ACK_PREFIX => Some(SeqAckPath(port_id, channel_id).into()),
+        LAST_RECV_PROOF_HEIGHT_PREFIX => Some(LastRecvProofHeightPath(port_id, channel_id).into()),
         _ => None,
     }
 }
@@ -1047,6 +1176,43 @@ fn parse_receipts(components: &[&str]) -> Option<Path> {
     )
 }
 
+fn parse_acked_sequences(components: &[&str]) -> Option<Path> {
+    if components.len() != 7 {
+        return None;
+    }
+
+    let first = *components.first()?;
+
+    if first != ACKED_SEQUENCE_PREFIX {
+        return None;
+    }
+
+    let port = parse_ports(&components[1..=2]);
+    let channel = parse_channels(&components[3..=4]);
+    let sequence = parse_sequences(&components[5..]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    let Some(SubPath::Sequences(sequence)) = sequence else {
+        return None;
+    };
+
+    Some(
+        AckedSequencePath {
+            port_id,
+            channel_id,
+            sequence,
+        }
+        .into(),
+    )
+}
+
 fn parse_upgrades(components: &[&str]) -> Option<Path> {
     if components.len() != 3 {
         return None;
@@ -1176,6 +1342,22 @@ mod tests {
         "upgradedIBCState/0/upgradedConsState",
         Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0))
     )]
+    #[case(
+        "clients/07-tendermint-0/status",
+        Path::ClientStatus(ClientStatusPath(ClientId::new_dummy()))
+    )]
+    #[case(
+        "lastRecvProofHeight/ports/transfer/channels/channel-0",
+        Path::LastRecvProofHeight(LastRecvProofHeightPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "ackedSequences/ports/transfer/channels/channel-0/sequences/0",
+        Path::AckedSequence(AckedSequencePath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            sequence: Sequence::from(0),
+        })
+    )]
     fn test_successful_parsing(#[case] path_str: &str, #[case] path: Path) {
         // can be parsed into Path
         assert_eq!(Path::from_str(path_str).expect("no error"), path);
@@ -1336,6 +1518,48 @@ mod tests {
         );
     }
 
+    #[rstest::rstest]
+    #[case("transfer", "channel-0", 1)]
+    #[case("ab", "channel-0", 0)]
+    #[case("icacontroller-cosmos1abc.def_ghi", "channel-42", 7)]
+    #[case("port+v2#[1]<a>", "channel-18446744073709551615", u64::MAX)]
+    #[case(&"p".repeat(128), "channel-1", 1)]
+    fn test_commitment_path_round_trip(
+        #[case] port_id: &str,
+        #[case] channel_id: &str,
+        #[case] sequence: u64,
+    ) {
+        let path = CommitmentPath::new(
+            &PortId::from_str(port_id).expect("valid port id"),
+            &ChannelId::from_str(channel_id).expect("valid channel id"),
+            Sequence::from(sequence),
+        );
+        let path_str = path.to_string();
+
+        assert_eq!(
+            path_str,
+            format!("commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}")
+        );
+        assert_eq!(CommitmentPath::from_str(&path_str).expect("no error"), path);
+        assert_eq!(
+            Path::from_str(&path_str).expect("no error"),
+            Path::Commitment(path)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/0")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/0/extra")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/abc")]
+    #[case("commitments/ports/p/channels/channel-0/sequences/0")]
+    #[case("commitments/ports/transfer/channels/chan-0/sequences/0")]
+    #[case("commitments/ports/transfer/channel/channel-0/sequences/0")]
+    #[case("/commitments/ports/transfer/channels/channel-0/sequences/0")]
+    fn test_commitment_path_failure_parsing(#[case] path_str: &str) {
+        assert!(CommitmentPath::from_str(path_str).is_err());
+    }
+
     #[test]
     fn test_parse_commitments_fn() {
         let path = "commitments/ports/transfer/channels/channel-0/sequences/0";
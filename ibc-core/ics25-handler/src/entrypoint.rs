@@ -60,6 +60,7 @@ where
             ClientMsg::UpdateClient(msg) => {
                 update_client::validate(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
             }
+            ClientMsg::UpdateClientBatch(msg) => update_client::validate_batch(ctx, &msg),
             ClientMsg::Misbehaviour(msg) => {
                 update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
@@ -78,8 +79,9 @@ where
         },
         MsgEnvelope::Channel(msg) => {
             let port_id = channel_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
+            let module_id = ctx
+                .port_owner(port_id)
+                .or_else(|| router.lookup_module(port_id))
                 .ok_or(RouterError::UnknownPort {
                     port_id: port_id.clone(),
                 })?;
@@ -137,6 +139,7 @@ where
             ClientMsg::UpdateClient(msg) => {
                 update_client::execute(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
             }
+            ClientMsg::UpdateClientBatch(msg) => update_client::execute_batch(ctx, msg),
             ClientMsg::Misbehaviour(msg) => {
                 update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
@@ -155,8 +158,9 @@ where
         },
         MsgEnvelope::Channel(msg) => {
             let port_id = channel_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
+            let module_id = ctx
+                .port_owner(port_id)
+                .or_else(|| router.lookup_module(port_id))
                 .ok_or(RouterError::UnknownPort {
                     port_id: port_id.clone(),
                 })?;
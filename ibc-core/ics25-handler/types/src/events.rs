@@ -49,6 +49,18 @@ impl std::error::Error for Error {
 const MESSAGE_EVENT: &str = "message";
 
 /// Events created by the IBC component of a chain, destined for a relayer.
+///
+/// Handlers emit their events in a fixed order, which callers may rely on:
+///
+/// - core events are preceded by an [`IbcEvent::Message`] naming the core
+///   module that emitted them, e.g. `Message(Client)` followed by
+///   `UpdateClient`. `ReceivePacket` and `WriteAcknowledgement` form a pair
+///   behind a single `Message(Channel)`;
+/// - within a handler, core events follow the order of the state changes they
+///   describe: `ReceivePacket` before `WriteAcknowledgement`, and
+///   `TimeoutPacket` before the `ChannelClosed` of an ordered channel;
+/// - [`IbcEvent::Module`] events returned by the application callback come
+///   last, after all core events of the handler.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -68,6 +80,7 @@ pub enum IbcEvent {
     UpdateClient(ClientEvents::UpdateClient),
     UpgradeClient(ClientEvents::UpgradeClient),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
+    ClientExpired(ClientEvents::ClientExpired),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
     OpenTryConnection(ConnectionEvents::OpenTry),
@@ -101,6 +114,7 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::UpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
+            IbcEvent::ClientExpired(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
             IbcEvent::OpenAckConnection(event) => event.into(),
@@ -132,6 +146,7 @@ impl IbcEvent {
             IbcEvent::CreateClient(event) => event.event_type(),
             IbcEvent::UpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
+            IbcEvent::ClientExpired(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
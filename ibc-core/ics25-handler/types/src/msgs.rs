@@ -36,6 +36,33 @@ pub enum MsgEnvelope {
     Packet(PacketMsg),
 }
 
+/// Enumeration of the kinds of messages processed by the IBC handlers.
+///
+/// Passed to the host's `ValidationContext::authorize` hook so that hosts can
+/// decide which signers are allowed to submit a given kind of message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum MsgKind {
+    CreateClient,
+    UpdateClient,
+    SubmitMisbehaviour,
+    UpgradeClient,
+    RecoverClient,
+    ConnectionOpenInit,
+    ConnectionOpenTry,
+    ConnectionOpenAck,
+    ConnectionOpenConfirm,
+    ChannelOpenInit,
+    ChannelOpenTry,
+    ChannelOpenAck,
+    ChannelOpenConfirm,
+    ChannelCloseInit,
+    ChannelCloseConfirm,
+    RecvPacket,
+    Acknowledgement,
+    Timeout,
+    TimeoutOnClose,
+}
+
 #[allow(deprecated)]
 impl TryFrom<Any> for MsgEnvelope {
     type Error = RouterError;
@@ -1,6 +1,7 @@
 use displaydoc::Display;
 use ibc_core_host_types::identifiers::PortId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
 
 /// Error type for the router module.
 #[derive(Debug, Display)]
@@ -13,6 +14,8 @@ pub enum RouterError {
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
+    /// signer `{signer}` is not authorized to submit `{msg_kind}` messages
+    UnauthorizedSigner { signer: Signer, msg_kind: String },
 }
 
 #[cfg(feature = "std")]
@@ -18,6 +18,73 @@ pub mod entrypoint {
     pub use ibc_core_handler::entrypoint::*;
 }
 
+/// Re-exports the validation and execution functions of every IBC core
+/// message handler under flat, self-describing names, along with the
+/// [`dispatch`](crate::entrypoint::dispatch) entrypoint.
+///
+/// ```
+/// use ibc_core::client::context::prelude::*;
+/// use ibc_core::client::types::error::ClientError;
+/// use ibc_core::handler::types::error::ContextError;
+/// use ibc_core::handler::types::msgs::MsgEnvelope;
+/// use ibc_core::handlers::dispatch;
+/// use ibc_core::host::ExecutionContext;
+/// use ibc_core::primitives::proto::Any;
+/// use ibc_core::router::router::Router;
+///
+/// fn deliver<Ctx>(
+///     ctx: &mut Ctx,
+///     router: &mut impl Router,
+///     msg: MsgEnvelope,
+/// ) -> Result<(), ContextError>
+/// where
+///     Ctx: ExecutionContext,
+///     <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error:
+///         Into<ClientError>,
+///     <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error:
+///         Into<ClientError>,
+///     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+/// {
+///     dispatch(ctx, router, msg)
+/// }
+/// ```
+pub mod handlers {
+    pub use ibc_core_channel::handler::{
+        acknowledgement_packet_execute, acknowledgement_packet_validate,
+        chan_close_confirm_execute, chan_close_confirm_validate, chan_close_init_execute,
+        chan_close_init_validate, chan_open_ack_execute, chan_open_ack_validate,
+        chan_open_confirm_execute, chan_open_confirm_validate, chan_open_init_execute,
+        chan_open_init_validate, chan_open_try_execute, chan_open_try_validate,
+        recv_packet_execute, recv_packet_validate, send_packet, send_packet_execute,
+        send_packet_validate, timeout_packet_execute, timeout_packet_validate,
+    };
+    pub use ibc_core_client::handler::create_client::{
+        execute as create_client_execute, validate as create_client_validate,
+    };
+    pub use ibc_core_client::handler::recover_client::{
+        execute as recover_client_execute, validate as recover_client_validate,
+    };
+    pub use ibc_core_client::handler::update_client::{
+        execute as update_client_execute, validate as update_client_validate,
+    };
+    pub use ibc_core_client::handler::upgrade_client::{
+        execute as upgrade_client_execute, validate as upgrade_client_validate,
+    };
+    pub use ibc_core_connection::handler::conn_open_ack::{
+        execute as conn_open_ack_execute, validate as conn_open_ack_validate,
+    };
+    pub use ibc_core_connection::handler::conn_open_confirm::{
+        execute as conn_open_confirm_execute, validate as conn_open_confirm_validate,
+    };
+    pub use ibc_core_connection::handler::conn_open_init::{
+        execute as conn_open_init_execute, validate as conn_open_init_validate,
+    };
+    pub use ibc_core_connection::handler::conn_open_try::{
+        execute as conn_open_try_execute, validate as conn_open_try_validate,
+    };
+    pub use ibc_core_handler::entrypoint::{dispatch, execute, validate};
+}
+
 /// Re-exports IBC primitive types from the `ibc-primitives` crate
 pub mod primitives {
     #[doc(inline)]
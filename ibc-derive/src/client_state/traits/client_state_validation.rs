@@ -21,6 +21,14 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let verify_client_message_batch_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_client_message_batch(cs, ctx, client_id, client_messages) },
+        imports,
+    );
+
     let check_for_misbehaviour_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -29,6 +37,14 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let is_client_message_processed_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { is_client_message_processed(cs, ctx, client_id, client_message) },
+        imports,
+    );
+
     let status_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -37,6 +53,22 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let header_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { header_height(cs, client_message) },
+        imports,
+    );
+
+    let misbehaviour_heights_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { misbehaviour_heights(cs, client_message) },
+        imports,
+    );
+
     let check_substitute_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -50,6 +82,7 @@ pub(crate) fn impl_ClientStateValidation(
     let ClientId = imports.client_id();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
+    let Height = imports.height();
     let Status = imports.status();
 
     // The types we need for the generated code.
@@ -76,6 +109,17 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn verify_client_message_batch(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                client_messages: &[#Any],
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_client_message_batch_impl),*
+                }
+            }
+
             fn check_for_misbehaviour(
                 &self,
                 ctx: &#V,
@@ -87,6 +131,17 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn is_client_message_processed(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                client_message: #Any,
+            ) -> core::result::Result<bool, #ClientError> {
+                match self {
+                    #(#is_client_message_processed_impl),*
+                }
+            }
+
             fn status(
                 &self,
                 ctx: &#V,
@@ -97,6 +152,24 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn header_height(
+                &self,
+                client_message: #Any,
+            ) -> core::result::Result<Option<#Height>, #ClientError> {
+                match self {
+                    #(#header_height_impl),*
+                }
+            }
+
+            fn misbehaviour_heights(
+                &self,
+                client_message: #Any,
+            ) -> core::result::Result<Vec<#Height>, #ClientError> {
+                match self {
+                    #(#misbehaviour_heights_impl),*
+                }
+            }
+
             fn check_substitute(
                 &self,
                 ctx: &#V,
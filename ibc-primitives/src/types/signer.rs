@@ -1,4 +1,5 @@
 use derive_more::Display;
+use subtle_encoding::bech32;
 
 use crate::prelude::*;
 
@@ -31,3 +32,99 @@ impl AsRef<str> for Signer {
         self.0.as_str()
     }
 }
+
+impl Signer {
+    /// Creates a new `Signer`, rejecting empty or whitespace-only addresses.
+    ///
+    /// Unlike the permissive `From<String>` conversion, which is kept for
+    /// compatibility with proto messages, this is meant for handlers that
+    /// require a signer to be present.
+    pub fn new_checked(signer: String) -> Result<Self, SignerError> {
+        if signer.trim().is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        Ok(Self(signer))
+    }
+
+    /// Checks that the signer is a valid bech32 address, optionally carrying
+    /// the given human-readable part, e.g. `cosmos`.
+    pub fn validate_bech32(&self, expected_hrp: Option<&str>) -> Result<(), SignerError> {
+        if self.0.trim().is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        let (hrp, _) = bech32::decode(&self.0).map_err(|e| SignerError::InvalidBech32 {
+            signer: self.0.clone(),
+            reason: e.to_string(),
+        })?;
+
+        match expected_hrp {
+            Some(expected) if expected != hrp => Err(SignerError::MismatchedHrp {
+                expected: expected.to_string(),
+                actual: hrp,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, displaydoc::Display)]
+pub enum SignerError {
+    /// signer cannot be empty
+    EmptySigner,
+    /// invalid bech32 signer `{signer}`: `{reason}`
+    InvalidBech32 { signer: String, reason: String },
+    /// mismatched bech32 prefix: expected `{expected}`, actual `{actual}`
+    MismatchedHrp { expected: String, actual: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_ADDRESS: &str = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng";
+
+    #[test]
+    fn test_empty_signer() {
+        assert!(matches!(
+            Signer::new_checked(String::new()),
+            Err(SignerError::EmptySigner)
+        ));
+        assert!(matches!(
+            Signer::new_checked(" ".to_string()),
+            Err(SignerError::EmptySigner)
+        ));
+        assert!(matches!(
+            Signer::from(String::new()).validate_bech32(None),
+            Err(SignerError::EmptySigner)
+        ));
+    }
+
+    #[test]
+    fn test_valid_bech32_signer() {
+        let signer = Signer::new_checked(VALID_ADDRESS.to_string()).unwrap();
+
+        assert!(signer.validate_bech32(None).is_ok());
+        assert!(signer.validate_bech32(Some("cosmos")).is_ok());
+        assert!(matches!(
+            signer.validate_bech32(Some("osmo")),
+            Err(SignerError::MismatchedHrp { expected, actual })
+                if expected == "osmo" && actual == "cosmos"
+        ));
+    }
+
+    #[test]
+    fn test_bad_checksum_signer() {
+        // Same address as above with its last checksum character altered.
+        let signer = Signer::from("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7nh".to_string());
+
+        assert!(matches!(
+            signer.validate_bech32(None),
+            Err(SignerError::InvalidBech32 { .. })
+        ));
+    }
+}
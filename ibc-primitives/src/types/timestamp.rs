@@ -184,6 +184,27 @@ impl Timestamp {
         })
     }
 
+    /// Creates a `Timestamp` from an [`OffsetDateTime`], keeping its
+    /// nanosecond precision.
+    ///
+    /// Returns an error if the datetime is not after the Unix epoch or does
+    /// not fit in a `u64` number of nanoseconds, as such values have no IBC
+    /// timestamp representation.
+    pub fn from_datetime(datetime: OffsetDateTime) -> Result<Self, ParseTimestampError> {
+        let unix_nanos = datetime.unix_timestamp_nanos();
+
+        let nanoseconds = u64::try_from(unix_nanos)
+            .ok()
+            .filter(|nanos| *nanos > 0)
+            .ok_or_else(|| {
+                ParseTimestampError::DataOutOfRange(format!(
+                    "{unix_nanos} nanoseconds since the Unix epoch"
+                ))
+            })?;
+
+        Self::from_nanoseconds(nanoseconds)
+    }
+
     /// Convert a `Timestamp` to an optional [`OffsetDateTime`]
     pub fn into_datetime(self) -> Option<OffsetDateTime> {
         self.time.map(Into::into)
@@ -196,6 +217,10 @@ impl Timestamp {
 
     /// Checks whether the timestamp has expired when compared to the
     /// `other` timestamp. Returns an [`Expiry`] result.
+    ///
+    /// The timestamps are compared directly, without any arithmetic, so the
+    /// check holds for every value a timestamp can take, up to `u64::MAX`
+    /// nanoseconds.
     pub fn check_expiry(&self, other: &Self) -> Expiry {
         match (self.time, other.time) {
             (Some(time1), Some(time2)) => {
@@ -213,6 +238,24 @@ impl Timestamp {
     pub fn is_set(&self) -> bool {
         self.time.is_some()
     }
+
+    /// Adds a duration to the timestamp, returning an error instead of
+    /// panicking if the result is not representable as a `u64` number of
+    /// nanoseconds. An unset timestamp stays unset.
+    pub fn checked_add(self, duration: Duration) -> Result<Self, TimestampOverflowError> {
+        self + duration
+    }
+
+    /// Wraps a `Time` resulting from timestamp arithmetic, provided it can be
+    /// represented as a non-zero `u64` number of nanoseconds, so that
+    /// [`Timestamp::nanoseconds`] never panics on it.
+    fn from_representable_time(time: Time) -> Result<Self, TimestampOverflowError> {
+        let nanoseconds = OffsetDateTime::from(time).unix_timestamp_nanos();
+        if nanoseconds <= 0 || nanoseconds > i128::from(u64::MAX) {
+            return Err(TimestampOverflowError::TimestampOverflow);
+        }
+        Ok(Self { time: Some(time) })
+    }
 }
 
 impl Display for Timestamp {
@@ -242,8 +285,8 @@ impl Add<Duration> for Timestamp {
         self.time
             .map(|time| time + duration)
             .transpose()
-            .map(|time| Self { time })
-            .map_err(|_| TimestampOverflowError::TimestampOverflow)
+            .map_err(|_| TimestampOverflowError::TimestampOverflow)?
+            .map_or(Ok(Self::none()), Self::from_representable_time)
     }
 }
 
@@ -254,8 +297,8 @@ impl Sub<Duration> for Timestamp {
         self.time
             .map(|time| time - duration)
             .transpose()
-            .map(|time| Self { time })
-            .map_err(|_| TimestampOverflowError::TimestampOverflow)
+            .map_err(|_| TimestampOverflowError::TimestampOverflow)?
+            .map_or(Ok(Self::none()), Self::from_representable_time)
     }
 }
 
@@ -302,7 +345,7 @@ mod tests {
 
     use time::OffsetDateTime;
 
-    use super::{Expiry, Timestamp, ZERO_DURATION};
+    use super::{Expiry, Timestamp, TimestampOverflowError, ZERO_DURATION};
 
     #[test]
     fn test_timestamp_comparisons() {
@@ -341,6 +384,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_max_timestamp_expiry() {
+        let max_timestamp = Timestamp::from_nanoseconds(u64::MAX).unwrap();
+        let timestamp = Timestamp::from_nanoseconds(1_000_000_000).unwrap();
+
+        assert_eq!(timestamp.check_expiry(&max_timestamp), Expiry::NotExpired);
+        assert_eq!(max_timestamp.check_expiry(&timestamp), Expiry::Expired);
+        assert_eq!(
+            max_timestamp.check_expiry(&max_timestamp),
+            Expiry::NotExpired
+        );
+        assert!(timestamp.duration_since(&max_timestamp).is_none());
+        assert!((max_timestamp + Duration::from_nanos(1)).is_err());
+        assert!(matches!(
+            max_timestamp.checked_add(Duration::from_nanos(1)),
+            Err(TimestampOverflowError::TimestampOverflow)
+        ));
+        assert_eq!(
+            (max_timestamp - Duration::from_nanos(1))
+                .unwrap()
+                .nanoseconds(),
+            u64::MAX - 1
+        );
+        assert!((timestamp - Duration::from_secs(1)).is_err());
+    }
+
     #[test]
     fn test_timestamp_arithmetic() {
         let time0 = Timestamp::none();
@@ -353,9 +422,33 @@ mod tests {
         assert_eq!(time2, (time1 + duration).unwrap());
         assert_eq!(time3, (time1 - duration).unwrap());
         assert_eq!(time0, (time0 + duration).unwrap());
+        assert_eq!(time2, time1.checked_add(duration).unwrap());
+        assert_eq!(time0, time0.checked_add(duration).unwrap());
         assert_eq!(time0, (time0 - duration).unwrap());
     }
 
+    #[test]
+    fn test_timestamp_datetime_round_trip() {
+        // Sun Jan 01 2023 00:00:00 GMT+0000
+        let year_2023 = OffsetDateTime::from_unix_timestamp(1_672_531_200).unwrap();
+        let timestamp = Timestamp::from_datetime(year_2023).unwrap();
+        assert_eq!(timestamp.nanoseconds(), 1_672_531_200_000_000_000);
+        assert_eq!(timestamp.into_datetime(), Some(year_2023));
+
+        let max = OffsetDateTime::from_unix_timestamp_nanos(u64::MAX.into()).unwrap();
+        let timestamp = Timestamp::from_datetime(max).unwrap();
+        assert_eq!(timestamp.nanoseconds(), u64::MAX);
+        assert_eq!(timestamp.into_datetime(), Some(max));
+
+        let beyond_max =
+            OffsetDateTime::from_unix_timestamp_nanos(i128::from(u64::MAX) + 1).unwrap();
+        assert!(Timestamp::from_datetime(beyond_max).is_err());
+        assert!(Timestamp::from_datetime(OffsetDateTime::UNIX_EPOCH).is_err());
+        assert!(
+            Timestamp::from_datetime(OffsetDateTime::UNIX_EPOCH - Duration::from_nanos(1)).is_err()
+        );
+    }
+
     #[test]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
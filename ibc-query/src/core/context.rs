@@ -5,7 +5,7 @@ use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::IdentifiedConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence, SequenceRange};
 use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
@@ -72,6 +72,15 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<Sequence>, ContextError>;
 
+    /// Filters the packet sequences in the given range for the given channel end that are not received.
+    fn unreceived_packets_in_range(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        self.unreceived_packets(channel_end_path, sequences.iter())
+    }
+
     /// Filters the list of packet sequences for the given channel end whose acknowledgement is not received.
     /// Returns all the unreceived acknowledgements if `sequences` is empty.
     fn unreceived_acks(
@@ -0,0 +1,192 @@
+//! Benchmarks the `recv_packet` hot path: validating and executing an ICS-20
+//! packet on an unordered channel between two Tendermint hosts, including the
+//! verification of the packet commitment proof against the consensus state of
+//! the receiving chain's light client.
+//!
+//! Run with `cargo bench -p ibc-testkit --features serde --bench recv_packet`.
+
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ibc::apps::transfer::handler::send_transfer;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::DEFAULT_MAX_MEMO_LENGTH;
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::primitives::{Signer, Timestamp};
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::TendermintContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::TendermintHost;
+use ibc_testkit::relayer::context::RelayerContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+/// Sizes, in bytes, of the memo carried by the benchmarked packets, up to
+/// the default memo length limit of the sending chain.
+const MEMO_SIZES: [usize; 4] = [0, 256, 4 * 1024, DEFAULT_MAX_MEMO_LENGTH];
+
+/// Two Tendermint hosts connected by an open ICS-20 channel.
+struct Setup {
+    relayer: RelayerContext<TendermintHost, TendermintHost>,
+    client_id_on_b: ClientId,
+    chan_id_on_a: ChannelId,
+    signer: Signer,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let signer = dummy_account_id();
+
+        let mut relayer =
+            RelayerContext::new(TendermintContext::default(), TendermintContext::default());
+
+        let client_id_on_a = relayer.create_client_on_a(signer.clone());
+        let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+        let (conn_id_on_a, conn_id_on_b) =
+            relayer.create_connection_on_a(client_id_on_a, client_id_on_b.clone(), signer.clone());
+
+        let (chan_id_on_a, _) = relayer.create_channel_on_a(
+            conn_id_on_a,
+            PortId::transfer(),
+            conn_id_on_b,
+            PortId::transfer(),
+            signer.clone(),
+        );
+
+        Self {
+            relayer,
+            client_id_on_b,
+            chan_id_on_a,
+            signer,
+        }
+    }
+
+    /// Sends a transfer packet carrying a memo of `memo_size` bytes on `A`,
+    /// updates the client of `A` on `B`, and returns the `MsgRecvPacket` to
+    /// submit on `B`.
+    fn prepare_recv(&mut self, memo_size: usize) -> MsgEnvelope {
+        let packet_data = PacketData {
+            token: "1000uibc".parse().expect("valid prefixed coin"),
+            sender: self.signer.clone(),
+            receiver: self.signer.clone(),
+            memo: "m".repeat(memo_size).into(),
+        };
+
+        // Many packets are sent over a run, so only a far-off timestamp
+        // timeout is set.
+        let msg = MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: self.chan_id_on_a.clone(),
+            packet_data,
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: (Timestamp::now() + Duration::from_secs(10 * 365 * 86400))
+                .expect("no overflow"),
+        };
+
+        send_transfer(
+            self.relayer.get_ctx_a_mut().ibc_store_mut(),
+            &mut DummyTransferModule::new(),
+            msg,
+        )
+        .expect("successfully created send_packet");
+
+        // Commit the packet commitment on `A`.
+        self.relayer.get_ctx_a_mut().advance_block_height();
+
+        let packet = self
+            .relayer
+            .get_ctx_a()
+            .ibc_store()
+            .events
+            .lock()
+            .iter()
+            .rev()
+            .find_map(|event| match event {
+                IbcEvent::SendPacket(event) => Some(Packet {
+                    port_id_on_a: event.port_id_on_a().clone(),
+                    chan_id_on_a: event.chan_id_on_a().clone(),
+                    seq_on_a: *event.seq_on_a(),
+                    data: event.packet_data().to_vec(),
+                    timeout_height_on_b: *event.timeout_height_on_b(),
+                    timeout_timestamp_on_b: *event.timeout_timestamp_on_b(),
+                    port_id_on_b: event.port_id_on_b().clone(),
+                    chan_id_on_b: event.chan_id_on_b().clone(),
+                }),
+                _ => None,
+            })
+            .expect("send_packet event exists");
+
+        self.relayer
+            .update_client_on_b_with_sync(self.client_id_on_b.clone(), self.signer.clone());
+
+        let ctx_a = self.relayer.get_ctx_a();
+        let proof_height_on_a = ctx_a.latest_height();
+        let proof_commitment_on_a = ctx_a
+            .ibc_store()
+            .get_proof(
+                proof_height_on_a,
+                &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
+                    .into(),
+            )
+            .expect("commitment proof exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        MsgEnvelope::Packet(PacketMsg::Recv(MsgRecvPacket {
+            packet,
+            proof_commitment_on_a,
+            proof_height_on_a,
+            signer: self.signer.clone(),
+        }))
+    }
+}
+
+fn bench_recv_packet(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recv_packet");
+
+    for memo_size in MEMO_SIZES {
+        let mut setup = Setup::new();
+
+        group.throughput(Throughput::Bytes(memo_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(memo_size),
+            &memo_size,
+            |b, &memo_size| {
+                // Each iteration needs a fresh packet, so only the handler
+                // is timed; relaying the packet and its proof is not.
+                b.iter_custom(|iters| {
+                    let mut elapsed = Duration::ZERO;
+
+                    for _ in 0..iters {
+                        let msg = setup.prepare_recv(memo_size);
+                        let ctx_b = setup.relayer.get_ctx_b_mut();
+
+                        let start = Instant::now();
+                        validate(&ctx_b.ibc_store, &ctx_b.ibc_router, msg.clone())
+                            .expect("recv_packet validation succeeds");
+                        execute(&mut ctx_b.ibc_store, &mut ctx_b.ibc_router, msg)
+                            .expect("recv_packet execution succeeds");
+                        elapsed += start.elapsed();
+
+                        ctx_b.advance_block_height();
+                    }
+
+                    elapsed
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_recv_packet);
+criterion_main!(benches);
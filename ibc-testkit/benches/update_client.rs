@@ -0,0 +1,115 @@
+//! Benchmarks re-submitting a Tendermint header the client has already
+//! processed, with the fast path that recognizes it from the stored consensus
+//! state, and without it, i.e. running the full light client verification.
+//!
+//! Run with `cargo bench -p ibc-testkit --bench update_client`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ibc::clients::tendermint::client_state::verify_header;
+use ibc::clients::tendermint::types::{client_type as tm_client_type, Header as TmHeader};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::{MockHost, TendermintHost, TestBlock};
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientBuilder;
+use tendermint::crypto::default::Sha256;
+use tendermint_light_client_verifier::ProdVerifier;
+
+fn bench_redundant_update_client(c: &mut Criterion) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).expect("valid height");
+    let update_height = Height::new(1, 21).expect("valid height");
+    let chain_id_b = ChainId::new("mockgaiaB-1").expect("valid chain id");
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").expect("valid chain id"))
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).expect("valid height"))
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b
+        .host_block(&update_height)
+        .expect("block exists")
+        .into_header();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.clone().into(),
+        signer: dummy_account_id(),
+    }));
+
+    // Process the header once, so that re-submitting it is redundant.
+    validate(&ctx.ibc_store, &router, msg.clone()).expect("update validation succeeds");
+    execute(&mut ctx.ibc_store, &mut router, msg.clone()).expect("update execution succeeds");
+
+    let AnyClientState::Tendermint(client_state) = ctx
+        .ibc_store
+        .client_state(&client_id)
+        .expect("client state exists")
+    else {
+        panic!("Tendermint client state");
+    };
+    let options = client_state
+        .inner()
+        .as_light_client_options()
+        .expect("valid light client options");
+    let header = TmHeader::from(block);
+
+    let mut group = c.benchmark_group("redundant_update_client");
+
+    group.bench_function("with_fast_path", |b| {
+        b.iter(|| {
+            validate(&ctx.ibc_store, &router, msg.clone())
+                .expect("redundant update validation succeeds")
+        })
+    });
+
+    group.bench_function("without_fast_path", |b| {
+        b.iter(|| {
+            verify_header::<_, Sha256>(
+                &ctx.ibc_store,
+                &header,
+                &client_id,
+                &chain_id_b,
+                &options,
+                &ProdVerifier::default(),
+            )
+            .expect("header verification succeeds")
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_redundant_update_client);
+criterion_main!(benches);
@@ -1,3 +1,4 @@
+use alloc::collections::{BTreeSet, VecDeque};
 use core::fmt::Debug;
 use core::time::Duration;
 
@@ -19,16 +20,17 @@ use ibc::core::host::types::path::{
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::router::types::module::ModuleId;
 use ibc::primitives::prelude::*;
 use ibc::primitives::Timestamp;
 
 use super::testapp::ibc::core::types::{LightClientState, MockIbcStore};
 use crate::fixtures::core::context::TestContextConfig;
+use crate::hosts::error::HostError;
 use crate::hosts::{HostClientState, MockHost, TendermintHost, TestBlock, TestHeader, TestHost};
 use crate::relayer::error::RelayerError;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::router::MockRouter;
-use crate::testapp::ibc::core::types::DEFAULT_BLOCK_TIME_SECS;
 
 /// A context implementing the dependencies necessary for testing any IBC module.
 #[derive(Debug)]
@@ -50,6 +52,16 @@ where
 
     /// A router that can route messages to the appropriate IBC application.
     pub ibc_router: MockRouter,
+
+    /// Duration between the blocks produced by
+    /// [`Self::advance_block_height`], once [`Self::block_intervals`] is
+    /// exhausted.
+    pub block_time: Duration,
+
+    /// Durations between the next blocks produced by
+    /// [`Self::advance_block_height`], consumed in order. Once exhausted,
+    /// blocks are [`Self::block_time`] apart.
+    pub block_intervals: VecDeque<Duration>,
 }
 
 /// A mock store type using basecoin-storage implementations.
@@ -170,12 +182,7 @@ where
     /// and consensus, and prepares the context for the next block. This includes
     /// the latest consensus state and the latest IBC commitment proof.
     pub fn begin_block(&mut self) {
-        let consensus_state = self
-            .host
-            .latest_block()
-            .into_header()
-            .into_consensus_state()
-            .into();
+        let consensus_state = self.host.latest_consensus_state().into();
 
         let ibc_commitment_proof = self
             .multi_store
@@ -222,11 +229,22 @@ where
     /// - Generating a new block with the commitment.
     /// - Adding the generated block to the host's block history.
     pub fn commit_state_to_host(&mut self, block_time: Duration, params: &H::BlockParams) {
+        self.try_commit_state_to_host(block_time, params)
+            .expect("block generation succeeds")
+    }
+
+    /// Fallible version of [`Self::commit_state_to_host`], returning the
+    /// error of the host if it fails to generate the new block.
+    pub fn try_commit_state_to_host(
+        &mut self,
+        block_time: Duration,
+        params: &H::BlockParams,
+    ) -> Result<(), HostError> {
         // commit the multi store
         let multi_store_commitment = self.multi_store.commit().expect("no error");
         // generate a new block and add it to the block history
         self.host
-            .commit_block(multi_store_commitment, block_time, params);
+            .try_commit_block(multi_store_commitment, block_time, params)
     }
 
     /// Advances the host chain height by ending the current block, producing a new block, and
@@ -236,17 +254,40 @@ where
         block_time: Duration,
         params: &H::BlockParams,
     ) {
+        self.try_advance_block_height_with_params(block_time, params)
+            .expect("block generation succeeds")
+    }
+
+    /// Fallible version of [`Self::advance_block_height_with_params`],
+    /// returning the error of the host if it fails to produce the new block.
+    ///
+    /// A block timestamp overflow is detected before anything is committed,
+    /// leaving the context at its current height. Any other error is returned
+    /// after the current block has been ended, so the context should not be
+    /// used to produce further blocks.
+    pub fn try_advance_block_height_with_params(
+        &mut self,
+        block_time: Duration,
+        params: &H::BlockParams,
+    ) -> Result<(), HostError> {
+        self.host.next_block_timestamp(block_time)?;
+
         self.end_block();
-        self.commit_state_to_host(block_time, params);
+        self.try_commit_state_to_host(block_time, params)?;
         self.begin_block();
+        Ok(())
     }
 
     /// Convenience method to advance the host chain height using default parameters.
+    ///
+    /// The block time is taken from [`Self::block_intervals`], if any are
+    /// left, and is [`Self::block_time`] otherwise.
     pub fn advance_block_height(&mut self) {
-        self.advance_block_height_with_params(
-            Duration::from_secs(DEFAULT_BLOCK_TIME_SECS),
-            &Default::default(),
-        )
+        let block_time = self.block_intervals.pop_front().unwrap_or(self.block_time);
+
+        let params = self.host.default_block_params();
+
+        self.advance_block_height_with_params(block_time, &params)
     }
 
     /// Returns the latest height of the host chain.
@@ -265,6 +306,20 @@ where
         self.host.latest_block().timestamp()
     }
 
+    /// Sets the host time seen by the IBC handlers without producing a block,
+    /// as if a message were submitted well after the latest block.
+    ///
+    /// Blocks produced afterwards take over once their timestamp exceeds the
+    /// given one. Panics if `timestamp` is earlier than the current host time.
+    pub fn set_host_timestamp(&mut self, timestamp: Timestamp) {
+        let current = self.ibc_store.host_timestamp().expect("Never fails");
+        assert!(
+            timestamp >= current,
+            "Cannot move the host time backward from {current} to {timestamp}"
+        );
+        self.ibc_store.host_timestamp_override = Some(timestamp);
+    }
+
     /// Returns the timestamp at the given height.
     pub fn timestamp_at(&self, height: Height) -> Timestamp {
         self.host
@@ -366,6 +421,32 @@ where
         self
     }
 
+    /// Bootstraps several light clients, each with its ClientState and
+    /// ConsensusState(s), to this context.
+    ///
+    /// The light clients may track hosts with distinct chain identifiers.
+    /// Panics if a client identifier is given more than once.
+    pub fn with_light_clients<RH>(
+        mut self,
+        light_clients: impl IntoIterator<Item = (ClientId, LightClientState<RH>)>,
+    ) -> Self
+    where
+        RH: TestHost,
+    {
+        let mut client_ids = BTreeSet::new();
+
+        for (client_id, light_client) in light_clients {
+            assert!(
+                client_ids.insert(client_id.clone()),
+                "duplicate client identifier {client_id}"
+            );
+
+            self = self.with_light_client(&client_id, light_client);
+        }
+
+        self
+    }
+
     /// Bootstraps a IBC connection to this context.
     ///
     /// This does not bootstrap any light client.
@@ -397,6 +478,12 @@ where
         self
     }
 
+    /// Binds a port to the given module in this context.
+    pub fn with_port_owner(mut self, port_id: PortId, module_id: ModuleId) -> Self {
+        self.ibc_store.port_owners.insert(port_id, module_id);
+        self
+    }
+
     /// Bootstraps a send sequence to this context.
     ///
     /// This does not bootstrap any corresponding IBC channel, connection or light client.
@@ -502,10 +589,13 @@ where
 #[cfg(test)]
 mod tests {
     use ibc::core::client::context::consensus_state::ConsensusState;
+    use ibc::core::host::types::identifiers::ChainId;
 
     use super::*;
     use crate::hosts::{HostConsensusState, MockHost, TendermintHost};
-    use crate::testapp::ibc::core::types::DefaultIbcStore;
+    use crate::testapp::ibc::core::types::{
+        DefaultIbcStore, LightClientBuilder, DEFAULT_BLOCK_TIME_SECS,
+    };
 
     #[test]
     fn test_mock_history_validation() {
@@ -609,4 +699,225 @@ mod tests {
         run_tests::<MockHost>("Mock Host");
         run_tests::<TendermintHost>("Synthetic TM Host");
     }
+
+    #[test]
+    fn test_get_block_out_of_range() {
+        let ctx = MockContext::default();
+
+        let genesis_height = ctx
+            .host
+            .history()
+            .first()
+            .expect("history is not empty")
+            .height();
+        let latest_height = ctx.latest_height();
+
+        assert_eq!(
+            ctx.host_block(&genesis_height).map(|block| block.height()),
+            Some(genesis_height)
+        );
+        assert_eq!(
+            ctx.host_block(&latest_height).map(|block| block.height()),
+            Some(latest_height)
+        );
+        assert!(ctx.host_block(&latest_height.increment()).is_none());
+
+        let other_revision_height = Height::new(
+            latest_height.revision_number() + 1,
+            latest_height.revision_height(),
+        )
+        .expect("Never fails");
+        assert!(ctx.host_block(&other_revision_height).is_none());
+    }
+
+    #[test]
+    fn test_latest_consensus_state() {
+        fn run_test<H>()
+        where
+            H: TestHost,
+            HostConsensusState<H>: ConsensusState,
+            HostClientState<H>: ClientStateValidation<DefaultIbcStore>,
+        {
+            let mut ctx = TestContext::<H>::default();
+            ctx.advance_block_height();
+
+            let latest_consensus_state: AnyConsensusState =
+                ctx.host.latest_consensus_state().into();
+
+            assert_eq!(
+                ctx.ibc_store
+                    .host_consensus_state(&ctx.latest_height())
+                    .expect("no error"),
+                latest_consensus_state
+            );
+        }
+
+        run_test::<MockHost>();
+        run_test::<TendermintHost>();
+    }
+
+    #[test]
+    fn test_with_light_clients_distinct_chain_ids() {
+        let chain_ids = [
+            ChainId::new("chainb-0").expect("no error"),
+            ChainId::new("chainc-0").expect("no error"),
+        ];
+
+        let light_clients = chain_ids.iter().enumerate().map(|(index, chain_id)| {
+            let ctx = TestContextConfig::builder()
+                .host(TendermintHost::builder().chain_id(chain_id.clone()).build())
+                .build::<TendermintContext>();
+
+            (
+                ClientId::new("07-tendermint", index as u64).expect("no error"),
+                LightClientBuilder::init().context(&ctx).build(),
+            )
+        });
+
+        let ctx = MockContext::default().with_light_clients(light_clients);
+
+        for (index, chain_id) in chain_ids.iter().enumerate() {
+            let client_id = ClientId::new("07-tendermint", index as u64).expect("no error");
+
+            let AnyClientState::Tendermint(client_state) = ctx
+                .ibc_store
+                .client_state(&client_id)
+                .expect("client state exists")
+            else {
+                panic!("unexpected client state type");
+            };
+
+            assert_eq!(client_state.inner().chain_id(), chain_id);
+
+            let latest_height = client_state.inner().latest_height;
+            assert!(ctx
+                .ibc_store
+                .consensus_state(&ClientConsensusStatePath::new(
+                    client_id,
+                    latest_height.revision_number(),
+                    latest_height.revision_height(),
+                ))
+                .is_ok());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate client identifier")]
+    fn test_with_light_clients_duplicate_client_id() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+        MockContext::default().with_light_clients([
+            (client_id.clone(), LightClientState::<MockHost>::default()),
+            (client_id, LightClientState::<MockHost>::default()),
+        ]);
+    }
+
+    #[test]
+    fn test_advance_block_height_host_error() {
+        use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+        use crate::testapp::ibc::clients::mock::header::MockHeader;
+
+        /// A mock host that fails to generate blocks from height `fail_from_height`.
+        #[derive(Debug, Default)]
+        struct FailingHost {
+            inner: MockHost,
+            fail_from_height: u64,
+        }
+
+        impl TestHost for FailingHost {
+            type Block = MockHeader;
+            type ClientState = MockClientState;
+            type BlockParams = ();
+            type LightClientParams = ();
+
+            fn history(&self) -> &Vec<Self::Block> {
+                self.inner.history()
+            }
+
+            fn push_block(&mut self, block: Self::Block) {
+                self.inner.push_block(block)
+            }
+
+            fn generate_block(
+                &self,
+                commitment_root: Vec<u8>,
+                height: u64,
+                timestamp: Timestamp,
+                params: &Self::BlockParams,
+            ) -> Self::Block {
+                self.inner
+                    .generate_block(commitment_root, height, timestamp, params)
+            }
+
+            fn try_generate_block(
+                &self,
+                commitment_root: Vec<u8>,
+                height: u64,
+                timestamp: Timestamp,
+                params: &Self::BlockParams,
+            ) -> Result<Self::Block, HostError> {
+                if height >= self.fail_from_height {
+                    return Err(HostError::BlockGeneration {
+                        height,
+                        description: "no validators".to_string(),
+                    });
+                }
+
+                Ok(self.generate_block(commitment_root, height, timestamp, params))
+            }
+
+            fn generate_client_state(
+                &self,
+                latest_height: &Height,
+                params: &Self::LightClientParams,
+            ) -> Self::ClientState {
+                self.inner.generate_client_state(latest_height, params)
+            }
+        }
+
+        let mut ctx = TestContextConfig::builder()
+            .host(FailingHost {
+                inner: MockHost::default(),
+                fail_from_height: 7,
+            })
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build::<TestContext<FailingHost>>();
+
+        let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
+
+        let res = ctx.try_advance_block_height_with_params(block_time, &());
+        assert!(res.is_ok(), "{res:?}");
+        assert_eq!(ctx.host.latest_height().revision_height(), 6);
+
+        let res = ctx.try_advance_block_height_with_params(block_time, &());
+        assert!(
+            matches!(res, Err(HostError::BlockGeneration { height: 7, .. })),
+            "{res:?}"
+        );
+        assert_eq!(ctx.host.latest_height().revision_height(), 6);
+    }
+
+    #[test]
+    fn test_advance_block_height_timestamp_overflow() {
+        let latest_timestamp = Timestamp::from_nanoseconds(u64::MAX - 1).expect("Never fails");
+
+        let mut ctx = TestContextConfig::builder()
+            .latest_timestamp(latest_timestamp)
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build::<MockContext>();
+
+        let block_time = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
+
+        let res = ctx.try_advance_block_height_with_params(block_time, &Default::default());
+        assert!(
+            matches!(res, Err(HostError::TimestampOverflow { height: 6 })),
+            "{res:?}"
+        );
+        assert_eq!(ctx.latest_height().revision_height(), 5);
+        assert_eq!(ctx.latest_timestamp(), latest_timestamp);
+
+        // The context is left untouched and can still produce blocks.
+        ctx.advance_block_height_with_params(Duration::ZERO, &Default::default());
+        assert_eq!(ctx.latest_height().revision_height(), 6);
+    }
 }
@@ -1,2 +1,49 @@
 pub mod mock;
 pub mod tendermint;
+
+use core::fmt::Debug;
+
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+
+/// Asserts that the client `client_id` has been updated to `expected_height`:
+/// its latest height is `expected_height`, the consensus state stored at that
+/// height equals `expected_cs`, and the update metadata has been recorded.
+pub fn assert_client_updated_to<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    expected_height: Height,
+    expected_cs: &Ctx::ConsensusStateRef,
+) where
+    Ctx: ClientValidationContext,
+    Ctx::ConsensusStateRef: PartialEq + Debug,
+{
+    let client_state = ctx
+        .client_state(client_id)
+        .expect("client state should exist");
+    assert_eq!(
+        client_state.latest_height(),
+        expected_height,
+        "unexpected latest height for client {client_id}"
+    );
+
+    let consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            expected_height.revision_number(),
+            expected_height.revision_height(),
+        ))
+        .expect("consensus state should be stored at the expected height");
+    assert_eq!(
+        &consensus_state, expected_cs,
+        "unexpected consensus state for client {client_id} at height {expected_height}"
+    );
+
+    assert!(
+        ctx.client_update_meta(client_id, &expected_height).is_ok(),
+        "update metadata should be stored for client {client_id} at height {expected_height}"
+    );
+}
@@ -23,9 +23,12 @@ pub fn dummy_raw_msg_chan_close_confirm(proof_height: u64) -> RawMsgChannelClose
 
 #[cfg(test)]
 mod tests {
+    use ibc::core::channel::types::error::ChannelError;
     use ibc::core::channel::types::msgs::MsgChannelCloseConfirm;
+    use ibc::core::commitment_types::error::CommitmentError;
 
     use super::*;
+    use crate::utils::assert_any_round_trip;
 
     #[test]
     fn parse_channel_close_confirm_msg() {
@@ -103,6 +106,14 @@ mod tests {
                         revision_number: 0,
                         revision_height: 0,
                     }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty signer".to_string(),
+                raw: RawMsgChannelCloseConfirm {
+                    signer: String::new(),
                     ..default_raw_msg
                 },
                 want_pass: false,
@@ -125,6 +136,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_channel_close_confirm_msg_empty_proof() {
+        let raw = RawMsgChannelCloseConfirm {
+            proof_init: vec![],
+            ..dummy_raw_msg_chan_close_confirm(10)
+        };
+
+        let err = MsgChannelCloseConfirm::try_from(raw).expect_err("empty proof is rejected");
+
+        assert!(matches!(
+            err,
+            ChannelError::InvalidCommitmentProof(CommitmentError::EmptyMerkleProof)
+        ));
+        assert_eq!(
+            err.to_string(),
+            "invalid commitment proof: `empty merkle proof`"
+        );
+    }
+
     #[test]
     fn to_and_from() {
         let raw = dummy_raw_msg_chan_close_confirm(19);
@@ -133,5 +163,6 @@ mod tests {
         let msg_back = MsgChannelCloseConfirm::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+        assert_any_round_trip(msg);
     }
 }
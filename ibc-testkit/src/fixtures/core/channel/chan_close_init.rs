@@ -18,6 +18,7 @@ mod tests {
     use ibc::core::channel::types::msgs::MsgChannelCloseInit;
 
     use super::*;
+    use crate::utils::assert_any_round_trip;
 
     #[test]
     fn parse_channel_close_init_msg() {
@@ -109,5 +110,6 @@ mod tests {
         let msg_back = MsgChannelCloseInit::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+        assert_any_round_trip(msg);
     }
 }
@@ -1,10 +1,62 @@
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::msgs::MsgChannelOpenTry;
 use ibc::core::channel::types::proto::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
+use ibc::core::channel::types::Version;
 use ibc::core::client::types::proto::v1::Height;
-use ibc::core::host::types::identifiers::PortId;
+use ibc::core::client::types::Height as DomainHeight;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use typed_builder::TypedBuilder;
 
 use super::{dummy_proof, dummy_raw_channel_end};
-use crate::fixtures::core::signer::dummy_bech32_account;
+use crate::fixtures::core::signer::{dummy_account_id, dummy_bech32_account};
+
+/// Configuration of the `MsgChannelOpenTry` type for building dummy messages.
+///
+/// The proof fields default to dummy values, so tests only need to set the
+/// fields they care about.
+#[derive(TypedBuilder, Debug)]
+#[builder(build_method(into = MsgChannelOpenTry))]
+pub struct MsgChannelOpenTryConfig {
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_b: PortId,
+    #[builder(default = vec![ConnectionId::zero()])]
+    pub connection_hops_on_b: Vec<ConnectionId>,
+    #[builder(default = PortId::transfer())]
+    pub port_id_on_a: PortId,
+    #[builder(default = ChannelId::zero())]
+    pub chan_id_on_a: ChannelId,
+    #[builder(default = Version::empty())]
+    pub version_supported_on_a: Version,
+    #[builder(default = dummy_proof().try_into().expect("dummy proof is not empty"))]
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    #[builder(default = DomainHeight::new(0, 10).expect("valid height"))]
+    pub proof_height_on_a: DomainHeight,
+    #[builder(default = Order::Unordered)]
+    pub ordering: Order,
+    #[builder(default = dummy_account_id())]
+    pub signer: Signer,
+}
+
+impl From<MsgChannelOpenTryConfig> for MsgChannelOpenTry {
+    fn from(config: MsgChannelOpenTryConfig) -> Self {
+        #[allow(deprecated)]
+        Self {
+            port_id_on_b: config.port_id_on_b,
+            connection_hops_on_b: config.connection_hops_on_b,
+            port_id_on_a: config.port_id_on_a,
+            chan_id_on_a: config.chan_id_on_a,
+            version_supported_on_a: config.version_supported_on_a,
+            proof_chan_end_on_a: config.proof_chan_end_on_a,
+            proof_height_on_a: config.proof_height_on_a,
+            ordering: config.ordering,
+            signer: config.signer,
+            version_proposal: Version::empty(),
+        }
+    }
+}
 
 /// Returns a dummy `RawMsgChannelOpenTry`, for testing purposes only!
 pub fn dummy_raw_msg_chan_open_try(proof_height: u64) -> RawMsgChannelOpenTry {
@@ -25,8 +77,6 @@ pub fn dummy_raw_msg_chan_open_try(proof_height: u64) -> RawMsgChannelOpenTry {
 
 #[cfg(test)]
 mod tests {
-    use ibc::core::channel::types::msgs::MsgChannelOpenTry;
-
     use super::*;
 
     #[test]
@@ -131,6 +181,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn msg_chan_open_try_builder() {
+        let port_id = PortId::new("customport".to_string()).unwrap();
+        let chan_id = ChannelId::new(7);
+        let version = Version::new("ics20-1".to_string());
+
+        let msg: MsgChannelOpenTry = MsgChannelOpenTryConfig::builder()
+            .port_id_on_b(port_id.clone())
+            .chan_id_on_a(chan_id.clone())
+            .version_supported_on_a(version.clone())
+            .build();
+
+        assert_eq!(msg.port_id_on_b, port_id);
+        assert_eq!(msg.chan_id_on_a, chan_id);
+        assert_eq!(msg.version_supported_on_a, version);
+        assert_eq!(msg.connection_hops_on_b, vec![ConnectionId::zero()]);
+        assert_eq!(
+            Vec::<u8>::from(msg.proof_chan_end_on_a.clone()),
+            dummy_proof()
+        );
+
+        let raw = RawMsgChannelOpenTry::from(msg.clone());
+        assert_eq!(MsgChannelOpenTry::try_from(raw).unwrap(), msg);
+    }
+
     #[test]
     fn to_and_from() {
         let raw = dummy_raw_msg_chan_open_try(10);
@@ -0,0 +1,53 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeAck as RawMsgChannelUpgradeAck;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeAck`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_ack(proof_height: u64) -> RawMsgChannelUpgradeAck {
+    RawMsgChannelUpgradeAck {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_upgrade: Some(dummy_raw_upgrade(proof_height + 100)),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeAck;
+    use ibc::core::channel::types::proto::v1::Timeout as RawUpgradeTimeout;
+
+    use super::*;
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_ack(19);
+        let msg = MsgChannelUpgradeAck::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeAck::from(msg.clone());
+        let msg_back = MsgChannelUpgradeAck::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    #[test]
+    fn reject_upgrade_without_timeout() {
+        let mut raw = dummy_raw_msg_chan_upgrade_ack(19);
+        if let Some(upgrade) = raw.counterparty_upgrade.as_mut() {
+            upgrade.timeout = Some(RawUpgradeTimeout {
+                height: None,
+                timestamp: 0,
+            });
+        }
+        assert!(MsgChannelUpgradeAck::try_from(raw).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeConfirm`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_confirm(proof_height: u64) -> RawMsgChannelUpgradeConfirm {
+    RawMsgChannelUpgradeConfirm {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        // FLUSHING
+        counterparty_channel_state: 5,
+        counterparty_upgrade: Some(dummy_raw_upgrade(proof_height + 100)),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::error::ChannelError;
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeConfirm;
+    use ibc::core::channel::types::upgrade::FlushState;
+
+    use super::*;
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_confirm(19);
+        let msg = MsgChannelUpgradeConfirm::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeConfirm::from(msg.clone());
+        let msg_back = MsgChannelUpgradeConfirm::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    #[test]
+    fn counterparty_channel_state() {
+        let mut raw = dummy_raw_msg_chan_upgrade_confirm(19);
+
+        raw.counterparty_channel_state = 6;
+        let msg = MsgChannelUpgradeConfirm::try_from(raw.clone()).unwrap();
+        assert_eq!(msg.chan_state_on_a, FlushState::FlushComplete);
+
+        // The counterparty channel must be flushing.
+        raw.counterparty_channel_state = 3;
+        assert!(matches!(
+            MsgChannelUpgradeConfirm::try_from(raw),
+            Err(ChannelError::InvalidState { .. })
+        ));
+    }
+}
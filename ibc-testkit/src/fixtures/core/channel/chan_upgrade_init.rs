@@ -0,0 +1,127 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_raw_upgrade_fields;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeInit`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_init() -> RawMsgChannelUpgradeInit {
+    RawMsgChannelUpgradeInit {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        fields: Some(dummy_raw_upgrade_fields()),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::channel::{ChannelEnd, Order};
+    use ibc::core::channel::types::error::ChannelError;
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeInit;
+    use ibc::core::channel::types::proto::v1::UpgradeFields as RawUpgradeFields;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fixtures::core::channel::dummy_raw_channel_end;
+
+    #[rstest]
+    #[case::good(dummy_raw_msg_chan_upgrade_init(), true)]
+    #[case::missing_fields(
+        RawMsgChannelUpgradeInit {
+            fields: None,
+            ..dummy_raw_msg_chan_upgrade_init()
+        },
+        false
+    )]
+    #[case::no_ordering(
+        RawMsgChannelUpgradeInit {
+            fields: Some(RawUpgradeFields {
+                ordering: 0,
+                ..dummy_raw_upgrade_fields()
+            }),
+            ..dummy_raw_msg_chan_upgrade_init()
+        },
+        false
+    )]
+    #[case::no_connection_hops(
+        RawMsgChannelUpgradeInit {
+            fields: Some(RawUpgradeFields {
+                connection_hops: vec![],
+                ..dummy_raw_upgrade_fields()
+            }),
+            ..dummy_raw_msg_chan_upgrade_init()
+        },
+        false
+    )]
+    #[case::bad_channel(
+        RawMsgChannelUpgradeInit {
+            channel_id: "chshort".to_string(),
+            ..dummy_raw_msg_chan_upgrade_init()
+        },
+        false
+    )]
+    fn parse_channel_upgrade_init_msg(
+        #[case] raw: RawMsgChannelUpgradeInit,
+        #[case] want_pass: bool,
+    ) {
+        let msg = MsgChannelUpgradeInit::try_from(raw.clone());
+
+        assert_eq!(
+            want_pass,
+            msg.is_ok(),
+            "MsgChannelUpgradeInit::try_from failed for raw msg {raw:?} with error {:?}",
+            msg.err(),
+        );
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_init();
+        let msg = MsgChannelUpgradeInit::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeInit::from(msg.clone());
+        let msg_back = MsgChannelUpgradeInit::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    #[test]
+    fn verify_upgrade_of_channel_end() {
+        let msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init()).unwrap();
+
+        // An open, ordered channel may become unordered.
+        let chan_end = ChannelEnd::try_from(dummy_raw_channel_end(3, Some(0))).unwrap();
+        assert!(msg.verify_upgrade_of(&chan_end).is_ok());
+
+        // Only open channels can be upgraded.
+        let init_chan_end = ChannelEnd::try_from(dummy_raw_channel_end(1, Some(0))).unwrap();
+        assert!(matches!(
+            msg.verify_upgrade_of(&init_chan_end),
+            Err(ChannelError::InvalidState { .. })
+        ));
+
+        // An unordered channel cannot become ordered.
+        let mut ordered_msg = msg.clone();
+        ordered_msg.fields.ordering = Order::Ordered;
+        let unordered_chan_end = ChannelEnd {
+            ordering: Order::Unordered,
+            ..chan_end.clone()
+        };
+        assert!(matches!(
+            ordered_msg.verify_upgrade_of(&unordered_chan_end),
+            Err(ChannelError::InvalidOrderType { .. })
+        ));
+
+        // The upgrade must change something.
+        let unchanged_chan_end = ChannelEnd {
+            ordering: msg.fields.ordering,
+            version: msg.fields.version.clone(),
+            ..chan_end
+        };
+        assert!(matches!(
+            msg.verify_upgrade_of(&unchanged_chan_end),
+            Err(ChannelError::InvalidUpgrade { .. })
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade_fields};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeTry`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_try(proof_height: u64) -> RawMsgChannelUpgradeTry {
+    RawMsgChannelUpgradeTry {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        proposed_upgrade_connection_hops: vec![ConnectionId::zero().to_string()],
+        counterparty_upgrade_fields: Some(dummy_raw_upgrade_fields()),
+        counterparty_upgrade_sequence: 1,
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeTry;
+
+    use super::*;
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_try(19);
+        let msg = MsgChannelUpgradeTry::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTry::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTry::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    #[test]
+    fn reject_empty_upgrade_proof() {
+        let raw = RawMsgChannelUpgradeTry {
+            proof_upgrade: vec![],
+            ..dummy_raw_msg_chan_upgrade_try(19)
+        };
+        assert!(MsgChannelUpgradeTry::try_from(raw).is_err());
+    }
+}
@@ -5,14 +5,20 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_try;
 mod packet;
 mod recv_packet;
 mod timeout;
 mod timeout_on_close;
 
 use ibc::core::channel::types::proto::v1::{
-    Channel as RawChannel, Counterparty as RawCounterparty,
+    Channel as RawChannel, Counterparty as RawCounterparty, Timeout as RawUpgradeTimeout,
+    Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
 };
+use ibc::core::client::types::proto::v1::Height;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::primitives::prelude::*;
 
@@ -23,6 +29,10 @@ pub use self::chan_open_ack::*;
 pub use self::chan_open_confirm::*;
 pub use self::chan_open_init::*;
 pub use self::chan_open_try::*;
+pub use self::chan_upgrade_ack::*;
+pub use self::chan_upgrade_confirm::*;
+pub use self::chan_upgrade_init::*;
+pub use self::chan_upgrade_try::*;
 pub use self::packet::*;
 pub use self::recv_packet::*;
 pub use self::timeout::*;
@@ -53,6 +63,31 @@ pub fn dummy_raw_channel_end(state: i32, channel_id: Option<u64>) -> RawChannel
     }
 }
 
+/// Returns dummy `RawUpgradeFields` proposing to make the channel unordered,
+/// for testing purposes only!
+pub fn dummy_raw_upgrade_fields() -> RawUpgradeFields {
+    RawUpgradeFields {
+        ordering: 1,
+        connection_hops: vec![ConnectionId::zero().to_string()],
+        version: "ics20-1".to_string(),
+    }
+}
+
+/// Returns a dummy `RawUpgrade`, for testing purposes only!
+pub fn dummy_raw_upgrade(timeout_height: u64) -> RawUpgrade {
+    RawUpgrade {
+        fields: Some(dummy_raw_upgrade_fields()),
+        timeout: Some(RawUpgradeTimeout {
+            height: Some(Height {
+                revision_number: 0,
+                revision_height: timeout_height,
+            }),
+            timestamp: 0,
+        }),
+        next_sequence_send: 1,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
@@ -149,6 +184,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_end_app_version() {
+        let fee_wrapped = RawChannel {
+            version: r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        };
+        let channel_end = ChannelEnd::try_from(fee_wrapped).unwrap();
+        assert_eq!(channel_end.app_version(), "ics20-1");
+
+        let unwrapped = RawChannel {
+            version: "ics20-1".to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        };
+        let channel_end = ChannelEnd::try_from(unwrapped).unwrap();
+        assert_eq!(channel_end.app_version(), "ics20-1");
+
+        let spaced = RawChannel {
+            version: r#"{ "app_version": "ics20-1", "fee_version": "ics29-1" }"#.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        };
+        let channel_end = ChannelEnd::try_from(spaced).unwrap();
+        assert_eq!(channel_end.app_version(), "ics20-1");
+
+        // Not a fee-wrapped version: returned as-is
+        let other = r#"{"app_version":"ics20-1"}"#;
+        let channel_end = ChannelEnd::try_from(RawChannel {
+            version: other.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        })
+        .unwrap();
+        assert_eq!(channel_end.app_version(), other);
+    }
+
+    #[test]
+    fn channel_end_supported_features() {
+        use ibc::core::channel::types::channel::Order;
+
+        let unordered = ChannelEnd::try_from(RawChannel {
+            ordering: 1,
+            ..dummy_raw_channel_end(3, Some(0))
+        })
+        .unwrap();
+        assert!(unordered.supports_ordering(Order::Unordered));
+        assert!(!unordered.supports_ordering(Order::Ordered));
+        assert!(!unordered.supports_feature("fee"));
+
+        let fee_wrapped = ChannelEnd::try_from(RawChannel {
+            version: r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        })
+        .unwrap();
+        assert!(fee_wrapped.supports_feature("fee"));
+        assert!(!fee_wrapped.supports_feature("app"));
+
+        let spaced = ChannelEnd::try_from(RawChannel {
+            version: r#"{ "app_version": "ics20-1", "fee_version": "ics29-1" }"#.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        })
+        .unwrap();
+        assert!(spaced.supports_feature("fee"));
+
+        // Not valid JSON, hence not a wrapped version
+        let malformed = ChannelEnd::try_from(RawChannel {
+            version: r#"{fee_version:"ics29-1","app_version":"ics20-1"}"#.to_string(),
+            ..dummy_raw_channel_end(3, Some(0))
+        })
+        .unwrap();
+        assert!(!malformed.supports_feature("fee"));
+    }
+
     #[test]
     fn parse_channel_ordering_type() {
         use ibc::core::channel::types::channel::Order;
@@ -269,4 +269,27 @@ mod tests {
         ));
         let _ = tendermint::abci::Event::try_from(ibc_event);
     }
+
+    #[test]
+    fn packet_ids() {
+        let packet: Packet = PacketConfig::builder()
+            .seq_on_a(Sequence::from(7))
+            .port_id_on_a(PortId::new("src-port".to_string()).unwrap())
+            .chan_id_on_a(ChannelId::new(1))
+            .port_id_on_b(PortId::new("dst-port".to_string()).unwrap())
+            .chan_id_on_b(ChannelId::new(2))
+            .build();
+
+        let id_on_a = packet.id_on_a();
+        assert_eq!(id_on_a.port_id, packet.port_id_on_a);
+        assert_eq!(id_on_a.chan_id, packet.chan_id_on_a);
+        assert_eq!(id_on_a.seq, packet.seq_on_a);
+
+        let id_on_b = packet.id_on_b();
+        assert_eq!(id_on_b.port_id, packet.port_id_on_b);
+        assert_eq!(id_on_b.chan_id, packet.chan_id_on_b);
+        assert_eq!(id_on_b.seq, packet.seq_on_a);
+
+        assert_ne!(id_on_a, id_on_b);
+    }
 }
@@ -50,6 +50,7 @@ mod test {
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::utils::assert_any_round_trip;
 
     #[test]
     fn msg_recv_packet_try_from_raw() {
@@ -115,5 +116,6 @@ mod test {
         let msg_back = MsgRecvPacket::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+        assert_any_round_trip(msg);
     }
 }
@@ -30,6 +30,7 @@ mod test {
     use ibc::primitives::prelude::*;
 
     use super::*;
+    use crate::utils::assert_any_round_trip;
 
     #[test]
     fn msg_timeout_try_from_raw() {
@@ -108,5 +109,6 @@ mod test {
         let msg_back = MsgTimeout::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+        assert_any_round_trip(msg);
     }
 }
@@ -38,6 +38,7 @@ mod tests {
         let client_id = client_type.build_client_id(0);
         let consensus_height = Height::new(0, 5).unwrap();
         let consensus_heights = vec![Height::new(0, 5).unwrap(), Height::new(0, 7).unwrap()];
+        let misbehaviour_heights = vec![Height::new(0, 6).unwrap()];
         let header: Any = dummy_new_mock_header(5).into();
         let expected_keys = vec![
             "client_id",
@@ -85,9 +86,9 @@ mod tests {
             },
             Test {
                 event_kind: CLIENT_MISBEHAVIOUR_EVENT,
-                event: ClientMisbehaviour::new(client_id, client_type).into(),
-                expected_keys: expected_keys[0..2].to_vec(),
-                expected_values: expected_values[0..2].to_vec(),
+                event: ClientMisbehaviour::new(client_id, client_type, misbehaviour_heights).into(),
+                expected_keys: vec!["client_id", "client_type", "misbehaviour_heights"],
+                expected_values: vec!["07-tendermint-0", "07-tendermint", "0-6"],
             },
         ];
 
@@ -84,6 +84,7 @@ mod tests {
     use ibc::core::connection::types::proto::v1::Counterparty as RawCounterparty;
 
     use super::*;
+    use crate::utils::assert_any_round_trip;
 
     #[test]
     fn parse_connection_open_init_msg() {
@@ -149,6 +150,7 @@ mod tests {
         let msg_back = MsgConnectionOpenInit::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+        assert_any_round_trip(msg);
 
         // Check if handler sets counterparty connection id to `None`
         // in case relayer passes `MsgConnectionOpenInit` message with it set to `Some(_)`.
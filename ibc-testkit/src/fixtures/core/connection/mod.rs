@@ -50,3 +50,47 @@ pub fn dummy_raw_counterparty_conn(conn_id: Option<u64>) -> RawCounterparty {
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc::core::connection::types::version::Version;
+    use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
+    use ibc::core::host::types::identifiers::ClientId;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::exact(10, 2, 5)]
+    #[case::rounded_up(10, 3, 4)]
+    #[case::shorter_than_block(10, 11, 1)]
+    #[case::no_delay(0, 2, 0)]
+    #[case::no_block_time(10, 0, 0)]
+    fn connection_end_delay_period(
+        #[case] delay_period_secs: u64,
+        #[case] max_expected_time_per_block_secs: u64,
+        #[case] expected_blocks: u64,
+    ) {
+        let delay_period = Duration::from_secs(delay_period_secs);
+        let conn_end = ConnectionEnd::new(
+            State::Open,
+            ClientId::new("07-tendermint", 0).expect("no error"),
+            Counterparty::new(
+                ClientId::new("07-tendermint", 1).expect("no error"),
+                Some(ConnectionId::new(0)),
+                b"ibc".to_vec().try_into().expect("no error"),
+            ),
+            vec![Version::compatibles()[0].clone()],
+            delay_period,
+        )
+        .expect("no error");
+
+        assert_eq!(conn_end.delay_period_time(), delay_period);
+        assert_eq!(
+            conn_end.delay_period_blocks(Duration::from_secs(max_expected_time_per_block_secs)),
+            expected_blocks
+        );
+    }
+}
@@ -35,6 +35,11 @@ where
 
     #[builder(default = Height::new(0, 5).expect("Never fails"))]
     latest_height: Height,
+
+    /// Durations between the blocks produced after `latest_height`, e.g. to
+    /// simulate clock skew. Once exhausted, blocks are `block_time` apart.
+    #[builder(default)]
+    block_intervals: Vec<Duration>,
 }
 
 impl<S, H> From<TestContextConfig<H>> for StoreGenericTestContext<S, H>
@@ -64,11 +69,14 @@ where
                 Default::default(),
             ),
             ibc_router: MockRouter::new_with_transfer(),
+            block_time: params.block_time,
+            block_intervals: Default::default(),
         };
 
         // store is at height 0; no block
 
-        context.advance_genesis_height(genesis_timestamp, &Default::default());
+        let genesis_params = context.host.default_block_params();
+        context.advance_genesis_height(genesis_timestamp, &genesis_params);
 
         // store is at height 1; one block
 
@@ -95,6 +103,78 @@ where
             "The latest timestamp in the host must match the latest timestamp in the context"
         );
 
+        context.block_intervals = params.block_intervals.into();
+
         context
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::MockContext;
+    use crate::hosts::MockHost;
+
+    #[test]
+    fn test_history_pruning() {
+        let mut ctx = TestContextConfig::builder()
+            .host(MockHost::builder().max_history_size(3).build())
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build::<MockContext>();
+
+        let latest_height = ctx.latest_height();
+
+        assert_eq!(ctx.host.history().len(), 3);
+        assert!(ctx.host.get_block(&latest_height.sub(3).unwrap()).is_none());
+        assert!(ctx.host.get_block(&latest_height.sub(2).unwrap()).is_some());
+        assert!(ctx.host.validate().is_ok());
+
+        ctx.advance_block_height();
+
+        assert_eq!(ctx.latest_height(), latest_height.increment());
+        assert_eq!(ctx.host.history().len(), 3);
+        assert!(ctx.host.get_block(&latest_height.sub(2).unwrap()).is_none());
+        assert!(ctx.host.validate().is_ok());
+    }
+
+    #[test]
+    fn test_history_pruning_keeps_latest_block() {
+        let mut ctx = TestContextConfig::builder()
+            .host(MockHost::builder().max_history_size(0).build())
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build::<MockContext>();
+
+        assert_eq!(ctx.host.history().len(), 1);
+
+        ctx.advance_block_height();
+
+        assert_eq!(ctx.host.history().len(), 1);
+        assert_eq!(ctx.host.latest_block().height(), ctx.latest_height());
+    }
+
+    #[test]
+    fn test_block_intervals() {
+        let block_time = Duration::from_secs(7);
+        let block_intervals = vec![Duration::from_secs(1), Duration::from_secs(3600)];
+
+        let mut ctx = TestContextConfig::builder()
+            .block_time(block_time)
+            .block_intervals(block_intervals.clone())
+            .build::<MockContext>();
+
+        assert_eq!(ctx.latest_timestamp(), year_2023());
+
+        let expected_intervals = block_intervals.into_iter().chain([block_time, block_time]);
+
+        for expected_interval in expected_intervals {
+            let previous_timestamp = ctx.latest_timestamp();
+
+            ctx.advance_block_height();
+
+            assert_eq!(
+                ctx.latest_timestamp(),
+                (previous_timestamp + expected_interval).expect("no overflow")
+            );
+        }
+    }
+}
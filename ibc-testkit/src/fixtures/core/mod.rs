@@ -3,4 +3,5 @@ pub mod client;
 pub mod commitment;
 pub mod connection;
 pub mod context;
+pub mod relayer;
 pub mod signer;
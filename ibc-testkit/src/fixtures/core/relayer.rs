@@ -0,0 +1,117 @@
+use ibc::core::channel::types::channel::Order;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::Path;
+use ibc::core::primitives::Signer;
+use ibc_query::core::context::ProvableContext;
+
+use crate::context::TestContext;
+use crate::fixtures::core::signer::dummy_account_id;
+use crate::hosts::{HostClientState, TestHost};
+use crate::relayer::context::RelayerContext;
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+/// A [`RelayerContext`] between two default [`TestContext`]s, each holding a
+/// light client of the other.
+pub struct RelayerFixture<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    pub relayer: RelayerContext<A, B>,
+    pub client_id_on_a: ClientId,
+    pub client_id_on_b: ClientId,
+    pub signer: Signer,
+}
+
+/// Identifiers of the ends of a channel opened with
+/// [`RelayerFixture::open_transfer_channel`], and of their connection.
+#[derive(Clone, Debug)]
+pub struct ChannelFixture {
+    pub conn_id_on_a: ConnectionId,
+    pub conn_id_on_b: ConnectionId,
+    pub chan_id_on_a: ChannelId,
+    pub chan_id_on_b: ChannelId,
+}
+
+impl<A, B> Default for RelayerFixture<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    fn default() -> Self {
+        let signer = dummy_account_id();
+
+        let mut relayer =
+            RelayerContext::new(TestContext::<A>::default(), TestContext::<B>::default());
+
+        let client_id_on_a = relayer.create_client_on_a(signer.clone());
+        let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+        Self {
+            relayer,
+            client_id_on_a,
+            client_id_on_b,
+            signer,
+        }
+    }
+}
+
+impl<A, B> RelayerFixture<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    /// Opens a connection from the first context, and a channel with the
+    /// given ordering over it between the transfer ports of both contexts.
+    pub fn open_transfer_channel(&mut self, ordering: Order) -> ChannelFixture {
+        let (conn_id_on_a, conn_id_on_b) = self.relayer.create_connection_on_a(
+            self.client_id_on_a.clone(),
+            self.client_id_on_b.clone(),
+            self.signer.clone(),
+        );
+
+        let (chan_id_on_a, chan_id_on_b) = self.relayer.create_channel_on_a_with_ordering(
+            conn_id_on_a.clone(),
+            PortId::transfer(),
+            conn_id_on_b.clone(),
+            PortId::transfer(),
+            ordering,
+            self.signer.clone(),
+        );
+
+        ChannelFixture {
+            conn_id_on_a,
+            conn_id_on_b,
+            chan_id_on_a,
+            chan_id_on_b,
+        }
+    }
+
+    /// Updates the client of the first context on the second one, and returns
+    /// a proof of `path` on the first context, along with the height to
+    /// verify it at.
+    pub fn prove_on_a(&mut self, path: Path) -> (CommitmentProofBytes, Height) {
+        self.relayer
+            .update_client_on_b_with_sync(self.client_id_on_b.clone(), self.signer.clone());
+
+        let ctx_a = self.relayer.get_ctx_a();
+        let proof_height_on_a = ctx_a.latest_height();
+        let proof_on_a = ctx_a
+            .ibc_store()
+            .get_proof(proof_height_on_a, &path)
+            .expect("proof exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        (proof_on_a, proof_height_on_a)
+    }
+}
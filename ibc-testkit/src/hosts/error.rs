@@ -0,0 +1,14 @@
+use alloc::string::String;
+
+use displaydoc::Display;
+
+#[derive(Debug, Display)]
+pub enum HostError {
+    /// failed to generate block at height `{height}`: `{description}`
+    BlockGeneration { height: u64, description: String },
+    /// timestamp overflow when generating block at height `{height}`
+    TimestampOverflow { height: u64 },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostError {}
@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::time::Duration;
 
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ChainId;
@@ -18,6 +19,10 @@ pub struct MockHost {
     /// The chain of blocks underlying this context.
     #[builder(default)]
     pub history: Vec<MockHeader>,
+    /// Maximum number of blocks kept in the history, the oldest ones being
+    /// pruned beyond it. Unbounded by default.
+    #[builder(default, setter(strip_option))]
+    pub max_history_size: Option<u64>,
 }
 
 impl Default for MockHost {
@@ -40,6 +45,15 @@ impl TestHost for MockHost {
         self.history.push(block);
     }
 
+    fn max_history_size(&self) -> Option<u64> {
+        self.max_history_size
+    }
+
+    fn prune_block_till(&mut self, height: &Height) {
+        self.history
+            .retain(|block| TestBlock::height(block) > *height);
+    }
+
     fn generate_block(
         &self,
         _commitment_root: Vec<u8>,
@@ -53,6 +67,17 @@ impl TestHost for MockHost {
         }
     }
 
+    /// Mock headers carry no commitment root, so the forked block is told
+    /// apart from the original one by its timestamp instead.
+    fn fork_block(&self, height: &Height, _params: &Self::BlockParams) -> Self::Block {
+        let block = self.get_block(height).expect("height exists");
+
+        MockHeader {
+            height: block.height,
+            timestamp: (block.timestamp + Duration::from_nanos(1)).expect("Never fails"),
+        }
+    }
+
     fn generate_client_state(
         &self,
         latest_height: &Height,
@@ -1,8 +1,8 @@
+pub mod error;
 pub mod mock;
 pub mod tendermint;
 
 use core::fmt::Debug;
-use core::ops::Add;
 use core::time::Duration;
 
 use ibc::core::client::context::consensus_state::ConsensusState;
@@ -11,6 +11,7 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 
+use self::error::HostError;
 pub use self::mock::MockHost;
 pub use self::tendermint::TendermintHost;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
@@ -39,6 +40,11 @@ pub trait TestHost: Default + Debug + Sized {
     /// The history of blocks produced by the host chain.
     fn history(&self) -> &Vec<Self::Block>;
 
+    /// The block parameters used when none are given explicitly.
+    fn default_block_params(&self) -> Self::BlockParams {
+        Self::BlockParams::default()
+    }
+
     /// Returns true if the host chain has no blocks.
     fn is_empty(&self) -> bool {
         self.history().is_empty()
@@ -50,40 +56,122 @@ pub trait TestHost: Default + Debug + Sized {
     }
 
     /// The latest block of the host chain.
+    ///
+    /// Pruning never removes the latest block, so this is always available
+    /// once the host produced its genesis block.
     fn latest_block(&self) -> Self::Block {
         self.history().last().cloned().expect("no error")
     }
 
+    /// The consensus state of the latest block of the host chain, as a light
+    /// client tracking the host would store it.
+    fn latest_consensus_state(&self) -> HostConsensusState<Self> {
+        self.latest_block().into_header().into_consensus_state()
+    }
+
     /// Get the block at the given height.
+    ///
+    /// Returns `None` if the host has not reached that height yet, if the
+    /// block was pruned from the history, or if the height belongs to another
+    /// revision of the chain.
     fn get_block(&self, target_height: &Height) -> Option<Self::Block> {
-        self.history()
-            .get(target_height.revision_height() as usize - 1)
-            .cloned() // indexed from 1
+        let oldest_height = self.history().first()?.height();
+
+        if target_height.revision_number() != oldest_height.revision_number() {
+            return None;
+        }
+
+        let index = target_height
+            .revision_height()
+            .checked_sub(oldest_height.revision_height())?;
+
+        self.history().get(usize::try_from(index).ok()?).cloned()
     }
 
     /// Add a block to the host chain.
     fn push_block(&mut self, block: Self::Block);
 
+    /// The maximum number of blocks kept in the history of the host chain.
+    ///
+    /// Once exceeded, the oldest blocks are pruned upon committing a new
+    /// block. `None` keeps the whole history. This does not affect
+    /// [`TestHost::latest_height`], which is always the height of the latest
+    /// committed block, nor the latest block itself, which is never pruned.
+    ///
+    /// Defaults to `None`.
+    fn max_history_size(&self) -> Option<u64> {
+        None
+    }
+
+    /// Remove all the blocks up to and including the given height from the
+    /// history.
+    ///
+    /// Defaults to keeping every block, for hosts without a bounded history.
+    fn prune_block_till(&mut self, _height: &Height) {}
+
     /// Commit a block with commitment root to the blockchain, by extending the history of blocks.
+    ///
+    /// Panics if the host fails to generate the block; see
+    /// [`TestHost::try_commit_block`] for the fallible version.
     fn commit_block(
         &mut self,
         commitment_root: Vec<u8>,
         block_time: Duration,
         params: &Self::BlockParams,
     ) {
-        let latest_block = self.latest_block();
+        self.try_commit_block(commitment_root, block_time, params)
+            .expect("block generation succeeds")
+    }
 
-        let height = TestBlock::height(&latest_block)
-            .increment()
-            .revision_height();
-        let timestamp = TestBlock::timestamp(&latest_block)
-            .add(block_time)
-            .expect("Never fails");
+    /// Commit a block with commitment root to the blockchain, by extending the history of blocks.
+    ///
+    /// Returns an error, leaving the history untouched, if the host fails to
+    /// generate the block or if the block timestamp overflows.
+    fn try_commit_block(
+        &mut self,
+        commitment_root: Vec<u8>,
+        block_time: Duration,
+        params: &Self::BlockParams,
+    ) -> Result<(), HostError> {
+        let height = self.latest_height().increment().revision_height();
+        let timestamp = self.next_block_timestamp(block_time)?;
 
-        let new_block = self.generate_block(commitment_root, height, timestamp, params);
+        let new_block = self.try_generate_block(commitment_root, height, timestamp, params)?;
 
-        // History is not full yet.
         self.push_block(new_block);
+
+        if let Some(max_history_size) = self.max_history_size() {
+            // The latest block is always kept, even with a size of 0.
+            let max_history_size = max_history_size.max(1);
+            let history_size = self.history().len() as u64;
+
+            if history_size > max_history_size {
+                let pruned_height = self
+                    .history()
+                    .first()
+                    .expect("history is not empty")
+                    .height()
+                    .add(history_size - max_history_size - 1);
+
+                self.prune_block_till(&pruned_height);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The timestamp of the next block, produced `block_time` after the
+    /// latest one.
+    fn next_block_timestamp(&self, block_time: Duration) -> Result<Timestamp, HostError> {
+        let latest_block = self.latest_block();
+
+        TestBlock::timestamp(&latest_block)
+            .checked_add(block_time)
+            .map_err(|_| HostError::TimestampOverflow {
+                height: TestBlock::height(&latest_block)
+                    .increment()
+                    .revision_height(),
+            })
     }
 
     /// Generate a block at the given height and timestamp, using the provided parameters.
@@ -95,6 +183,38 @@ pub trait TestHost: Default + Debug + Sized {
         params: &Self::BlockParams,
     ) -> Self::Block;
 
+    /// Fallible version of [`TestHost::generate_block`], for hosts that may
+    /// fail to produce a block, e.g. because of an invalid validator set.
+    ///
+    /// Defaults to the infallible [`TestHost::generate_block`].
+    fn try_generate_block(
+        &self,
+        commitment_root: Vec<u8>,
+        height: u64,
+        timestamp: Timestamp,
+        params: &Self::BlockParams,
+    ) -> Result<Self::Block, HostError> {
+        Ok(self.generate_block(commitment_root, height, timestamp, params))
+    }
+
+    /// Generate a block conflicting with the one at the given height in the
+    /// history, e.g. to build equivocation evidence.
+    ///
+    /// The forked block keeps the height and timestamp of the original block
+    /// but commits to a different commitment root. The validator set is taken
+    /// from the provided parameters, which should match those of the original
+    /// block for the fork to pass verification.
+    fn fork_block(&self, height: &Height, params: &Self::BlockParams) -> Self::Block {
+        let block = self.get_block(height).expect("height exists");
+
+        self.generate_block(
+            b"fork".to_vec(),
+            height.revision_height(),
+            block.timestamp(),
+            params,
+        )
+    }
+
     /// Generate a client state using the block at the given height and the provided parameters.
     fn generate_client_state(
         &self,
@@ -103,13 +223,19 @@ pub trait TestHost: Default + Debug + Sized {
     ) -> Self::ClientState;
 
     fn validate(&self) -> Result<(), String> {
-        // Check that headers in the history are in sequential order.
+        // Check that headers in the history are in sequential order, starting
+        // from the oldest block that was not pruned.
+        let Some(oldest_block) = self.history().first() else {
+            return Err("history is empty".to_owned());
+        };
+
         let latest_height = self.latest_height();
-        let mut current_height = Height::min(latest_height.revision_number());
+        let mut current_height = oldest_block.height();
 
         while current_height <= latest_height {
-            if current_height != self.get_block(&current_height).expect("no error").height() {
-                return Err("block height does not match".to_owned());
+            match self.get_block(&current_height) {
+                Some(block) if block.height() == current_height => {}
+                _ => return Err("block height does not match".to_owned()),
             }
             current_height = current_height.increment();
         }
@@ -20,6 +20,7 @@ use tendermint_testgen::{
 use typed_builder::TypedBuilder;
 
 use crate::fixtures::clients::tendermint::ClientStateConfig;
+use crate::hosts::error::HostError;
 use crate::hosts::{TestBlock, TestHeader, TestHost};
 
 /// A host that produces Tendermint blocks and interfaces with Tendermint light clients.
@@ -31,6 +32,33 @@ pub struct TendermintHost {
     /// The chain of blocks underlying this context.
     #[builder(default)]
     pub history: Vec<TmLightBlock>,
+    /// Maximum number of blocks kept in the history, the oldest ones being
+    /// pruned beyond it. Unbounded by default.
+    #[builder(default, setter(strip_option))]
+    pub max_history_size: Option<u64>,
+    /// Optional seed from which validator keys are derived by
+    /// [`TendermintHost::seeded_validators`], so that validator sets and
+    /// addresses are reproducible across runs.
+    #[builder(default, setter(strip_option))]
+    pub validator_seed: Option<u64>,
+}
+
+impl TendermintHost {
+    /// Returns `count` validators with the given voting power, whose keys are
+    /// derived from the host's `validator_seed` and their index in the set.
+    ///
+    /// Without a seed, validators are identified by their 1-based index.
+    pub fn seeded_validators(&self, count: u64, voting_power: u64) -> Vec<TestgenValidator> {
+        (1..=count)
+            .map(|index| {
+                let id = match self.validator_seed {
+                    Some(seed) => format!("{seed}-{index}"),
+                    None => index.to_string(),
+                };
+                TestgenValidator::new(&id).voting_power(voting_power)
+            })
+            .collect()
+    }
 }
 
 impl Default for TendermintHost {
@@ -49,10 +77,30 @@ impl TestHost for TendermintHost {
         &self.history
     }
 
+    /// Two validators of equal voting power, derived from the host's
+    /// `validator_seed`.
+    fn default_block_params(&self) -> Self::BlockParams {
+        let validators = self.seeded_validators(2, 50);
+
+        BlockParams::builder()
+            .validators(validators.clone())
+            .next_validators(validators)
+            .build()
+    }
+
     fn push_block(&mut self, block: Self::Block) {
         self.history.push(block);
     }
 
+    fn max_history_size(&self) -> Option<u64> {
+        self.max_history_size
+    }
+
+    fn prune_block_till(&mut self, height: &Height) {
+        self.history
+            .retain(|block| TestBlock::height(block) > *height);
+    }
+
     fn generate_block(
         &self,
         commitment_root: Vec<u8>,
@@ -60,18 +108,39 @@ impl TestHost for TendermintHost {
         timestamp: Timestamp,
         params: &Self::BlockParams,
     ) -> Self::Block {
+        self.try_generate_block(commitment_root, height, timestamp, params)
+            .expect("Never fails")
+    }
+
+    fn try_generate_block(
+        &self,
+        commitment_root: Vec<u8>,
+        height: u64,
+        timestamp: Timestamp,
+        params: &Self::BlockParams,
+    ) -> Result<Self::Block, HostError> {
+        let time = timestamp
+            .into_tm_time()
+            .ok_or_else(|| HostError::BlockGeneration {
+                height,
+                description: "block timestamp is not set".to_string(),
+            })?;
+
         TestgenLightBlock::new_default_with_header(
             TestgenHeader::new(&params.validators)
                 .app_hash(commitment_root.try_into().expect("infallible"))
                 .height(height)
                 .chain_id(self.chain_id.as_str())
                 .next_validators(&params.next_validators)
-                .time(timestamp.into_tm_time().expect("Never fails")),
+                .time(time),
         )
         .validators(&params.validators)
         .next_validators(&params.next_validators)
         .generate()
-        .expect("Never fails")
+        .map_err(|e| HostError::BlockGeneration {
+            height,
+            description: e.to_string(),
+        })
     }
 
     fn generate_client_state(
@@ -146,16 +215,7 @@ impl BlockParams {
 
 impl Default for BlockParams {
     fn default() -> Self {
-        Self::builder()
-            .validators(vec![
-                TestgenValidator::new("1").voting_power(50),
-                TestgenValidator::new("2").voting_power(50),
-            ])
-            .next_validators(vec![
-                TestgenValidator::new("1").voting_power(50),
-                TestgenValidator::new("2").voting_power(50),
-            ])
-            .build()
+        TendermintHost::default().default_block_params()
     }
 }
 
@@ -238,3 +298,69 @@ impl From<TendermintHeader> for Any {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::TendermintContext;
+    use crate::fixtures::core::context::TestContextConfig;
+
+    #[test]
+    fn test_seeded_validators_are_reproducible() {
+        let addresses = |host: &TendermintHost| {
+            host.seeded_validators(4, 50)
+                .into_iter()
+                .map(|validator| validator.generate().expect("Never fails").address)
+                .collect::<Vec<_>>()
+        };
+
+        let host_a = TendermintHost::builder().validator_seed(7).build();
+        let host_b = TendermintHost::builder().validator_seed(7).build();
+        let host_c = TendermintHost::builder().validator_seed(8).build();
+
+        assert_eq!(addresses(&host_a), addresses(&host_b));
+        assert_ne!(addresses(&host_a), addresses(&host_c));
+    }
+
+    #[test]
+    fn test_default_blocks_use_seeded_validators() {
+        let sorted_addresses = |validators: Vec<TestgenValidator>| {
+            let mut addresses = validators
+                .into_iter()
+                .map(|validator| validator.generate().expect("Never fails").address)
+                .collect::<Vec<_>>();
+            addresses.sort();
+            addresses
+        };
+
+        let expected = sorted_addresses(
+            TendermintHost::builder()
+                .validator_seed(7)
+                .build()
+                .seeded_validators(2, 50),
+        );
+        let unseeded = sorted_addresses(TendermintHost::default().seeded_validators(2, 50));
+        assert_ne!(expected, unseeded);
+
+        let ctx = TestContextConfig::builder()
+            .host(TendermintHost::builder().validator_seed(7).build())
+            .latest_height(Height::new(0, 5).expect("Never fails"))
+            .build::<TendermintContext>();
+
+        for height in 1..=5 {
+            let block = ctx
+                .host_block(&Height::new(0, height).expect("Never fails"))
+                .expect("block exists");
+
+            let mut addresses = block
+                .validators
+                .validators()
+                .iter()
+                .map(|validator| validator.address)
+                .collect::<Vec<_>>();
+            addresses.sort();
+
+            assert_eq!(addresses, expected);
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+
+use ibc::core::channel::types::channel::Order;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
@@ -10,6 +13,16 @@ use crate::hosts::{HostClientState, TestHost};
 use crate::relayer::utils::TypedRelayerOps;
 use crate::testapp::ibc::core::types::DefaultIbcStore;
 
+/// The order in which [`RelayerContext::relay_packets`] relays pending packets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelayOrder {
+    /// Packets are relayed by ascending sequence number, as required by
+    /// ordered channels.
+    BySequence,
+    /// Packets are relayed in the order they were queued.
+    Fifo,
+}
+
 /// A relayer context that allows interaction between two [`TestContext`] instances.
 pub struct RelayerContext<A, B>
 where
@@ -20,6 +33,10 @@ where
 {
     ctx_a: TestContext<A>,
     ctx_b: TestContext<B>,
+    /// Packets sent from the first context, waiting to be relayed.
+    pending_packets: Vec<Packet>,
+    /// Overrides the order in which pending packets are relayed.
+    relay_order: Option<RelayOrder>,
 }
 
 impl<A, B> RelayerContext<A, B>
@@ -31,7 +48,23 @@ where
 {
     /// Creates a new relayer context with the given [`TestContext`] instances.
     pub fn new(ctx_a: TestContext<A>, ctx_b: TestContext<B>) -> Self {
-        Self { ctx_a, ctx_b }
+        Self {
+            ctx_a,
+            ctx_b,
+            pending_packets: Vec::new(),
+            relay_order: None,
+        }
+    }
+
+    /// Sets the order in which [`Self::relay_packets`] relays pending packets.
+    ///
+    /// If unset, packets are relayed by sequence when any of them belongs to
+    /// an ordered channel, and in insertion order otherwise.
+    pub fn with_relay_order(self, relay_order: RelayOrder) -> Self {
+        Self {
+            relay_order: Some(relay_order),
+            ..self
+        }
     }
 
     /// Returns immutable reference to the first context.
@@ -120,7 +153,7 @@ where
         )
     }
 
-    /// Creates a channel between the two contexts starting from the first context.
+    /// Creates an unordered channel between the two contexts starting from the first context.
     /// Returns the channel identifiers of the created channel ends.
     pub fn create_channel_on_a(
         &mut self,
@@ -129,6 +162,27 @@ where
         conn_id_on_b: ConnectionId,
         port_id_on_b: PortId,
         signer: Signer,
+    ) -> (ChannelId, ChannelId) {
+        self.create_channel_on_a_with_ordering(
+            conn_id_on_a,
+            port_id_on_a,
+            conn_id_on_b,
+            port_id_on_b,
+            Order::Unordered,
+            signer,
+        )
+    }
+
+    /// Creates a channel with the given ordering between the two contexts starting from the first context.
+    /// Returns the channel identifiers of the created channel ends.
+    pub fn create_channel_on_a_with_ordering(
+        &mut self,
+        conn_id_on_a: ConnectionId,
+        port_id_on_a: PortId,
+        conn_id_on_b: ConnectionId,
+        port_id_on_b: PortId,
+        ordering: Order,
+        signer: Signer,
     ) -> (ChannelId, ChannelId) {
         let client_id_on_a = self
             .ctx_a
@@ -155,11 +209,12 @@ where
             client_id_on_b,
             conn_id_on_b,
             port_id_on_b,
+            ordering,
             signer,
         )
     }
 
-    /// Creates a channel between the two contexts starting from the second context.
+    /// Creates an unordered channel between the two contexts starting from the second context.
     /// Returns the channel identifiers of the created channel ends.
     pub fn create_channel_on_b(
         &mut self,
@@ -194,6 +249,7 @@ where
             client_id_on_a,
             conn_id_on_a,
             port_id_on_a,
+            Order::Unordered,
             signer,
         )
     }
@@ -359,6 +415,50 @@ where
         )
     }
 
+    /// Queues a packet sent from the first context, to be relayed to the
+    /// second context by [`Self::relay_packets`].
+    pub fn queue_packet(&mut self, packet: Packet) {
+        self.pending_packets.push(packet);
+    }
+
+    /// Relays all pending packets from the first context to the second
+    /// context, following the configured [`RelayOrder`].
+    ///
+    /// Returns the packets in the order they were relayed.
+    pub fn relay_packets(&mut self, signer: Signer) -> Vec<Packet> {
+        let mut packets = core::mem::take(&mut self.pending_packets);
+
+        let relay_order = self.relay_order.unwrap_or_else(|| {
+            let has_ordered_channel = packets.iter().any(|packet| {
+                self.ctx_a
+                    .ibc_store()
+                    .channel_end(&ChannelEndPath::new(
+                        &packet.port_id_on_a,
+                        &packet.chan_id_on_a,
+                    ))
+                    .expect("channel exists")
+                    .ordering
+                    == Order::Ordered
+            });
+
+            if has_ordered_channel {
+                RelayOrder::BySequence
+            } else {
+                RelayOrder::Fifo
+            }
+        });
+
+        if relay_order == RelayOrder::BySequence {
+            packets.sort_by_key(|packet| packet.seq_on_a);
+        }
+
+        for packet in &packets {
+            self.submit_packet_on_b(packet.clone(), signer.clone());
+        }
+
+        packets
+    }
+
     /// Times out a packet from the first context to the second context by
     /// waiting for timeout period and then sending timeout packet on first context.
     ///
@@ -507,7 +607,7 @@ where
         // module creates the send_packet
         send_transfer(
             self.get_ctx_a_mut().ibc_store_mut(),
-            &mut DummyTransferModule,
+            &mut DummyTransferModule::new(),
             msg,
         )
         .expect("successfully created send_packet");
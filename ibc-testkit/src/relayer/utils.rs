@@ -472,13 +472,14 @@ where
         conn_id_on_a: ConnectionId,
         port_id_on_a: PortId,
         port_id_on_b: PortId,
+        ordering: Order,
         signer: Signer,
     ) -> ChannelId {
         let msg_for_a = MsgEnvelope::Channel(ChannelMsg::OpenInit(MsgChannelOpenInit {
             port_id_on_a,
             connection_hops_on_a: [conn_id_on_a].to_vec(),
             port_id_on_b,
-            ordering: Order::Unordered,
+            ordering,
             signer,
             version_proposal: ChannelVersion::empty(),
         }));
@@ -502,6 +503,7 @@ where
         conn_id_on_b: ConnectionId,
         chan_id_on_a: ChannelId,
         port_id_on_a: PortId,
+        ordering: Order,
         signer: Signer,
     ) -> ChannelId {
         let proof_height_on_a = ctx_a.latest_height();
@@ -525,7 +527,7 @@ where
             version_supported_on_a: ChannelVersion::empty(),
             proof_chan_end_on_a,
             proof_height_on_a,
-            ordering: Order::Unordered,
+            ordering,
             signer,
 
             version_proposal: ChannelVersion::empty(),
@@ -693,6 +695,7 @@ where
         client_id_on_b: ClientId,
         conn_id_on_b: ConnectionId,
         port_id_on_b: PortId,
+        ordering: Order,
         signer: Signer,
     ) -> (ChannelId, ChannelId) {
         let chan_id_on_a = TypedRelayerOps::<A, B>::channel_open_init_on_a(
@@ -700,6 +703,7 @@ where
             conn_id_on_a.clone(),
             port_id_on_a.clone(),
             port_id_on_b.clone(),
+            ordering,
             signer.clone(),
         );
 
@@ -716,6 +720,7 @@ where
             conn_id_on_b.clone(),
             chan_id_on_a.clone(),
             port_id_on_a.clone(),
+            ordering,
             signer.clone(),
         );
 
@@ -0,0 +1,131 @@
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::{Fee, PacketFee};
+use ibc::apps::transfer::types::{Amount, PrefixedCoin};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::types::DummyFeeModule;
+
+impl FeeValidationContext for DummyFeeModule {
+    type AccountId = Signer;
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.fee_enabled_channels
+            .contains(&(port_id.clone(), channel_id.clone()))
+    }
+
+    fn escrow_fee_validate(&self, _payer: &Self::AccountId, _fee: &Fee) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn packet_fees(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Vec<PacketFee>, FeeError> {
+        Ok(self
+            .packet_fees
+            .get(&(port_id.clone(), channel_id.clone(), sequence))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(self
+            .counterparty_payees
+            .get(&(channel_id.clone(), relayer.clone()))
+            .cloned())
+    }
+}
+
+impl FeeExecutionContext for DummyFeeModule {
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.fee_enabled_channels
+            .insert((port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    /// Adds the coins of the fee to the escrow. The payer's balance is not
+    /// tracked.
+    fn escrow_fee_execute(&mut self, _payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError> {
+        for coin in fee.coins() {
+            let escrowed = self
+                .escrows
+                .entry(coin.denom.clone())
+                .or_insert_with(|| Amount::from(0));
+            *escrowed = escrowed
+                .checked_add(coin.amount)
+                .ok_or_else(|| FeeError::Other("fee escrow overflow".to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn store_packet_fees(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        self.packet_fees
+            .insert((port_id.clone(), channel_id.clone(), sequence), packet_fees);
+        Ok(())
+    }
+
+    fn delete_packet_fees(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), FeeError> {
+        self.packet_fees
+            .remove(&(port_id.clone(), channel_id.clone(), sequence));
+        Ok(())
+    }
+
+    /// Moves the coins from the escrow to the receiver's tracked balance.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        for coin in coins {
+            let escrowed = self
+                .escrows
+                .get_mut(&coin.denom)
+                .ok_or_else(|| FeeError::Other(format!("no `{}` in fee escrow", coin.denom)))?;
+            *escrowed = escrowed.checked_sub(coin.amount).ok_or_else(|| {
+                FeeError::Other(format!("insufficient `{}` in fee escrow", coin.denom))
+            })?;
+
+            let distributed = self
+                .distributed
+                .entry((receiver.clone(), coin.denom.clone()))
+                .or_insert_with(|| Amount::from(0));
+            *distributed = distributed
+                .checked_add(coin.amount)
+                .ok_or_else(|| FeeError::Other("fee distribution overflow".to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        counterparty_payee: &Signer,
+    ) -> Result<(), FeeError> {
+        self.counterparty_payees.insert(
+            (channel_id.clone(), relayer.clone()),
+            counterparty_payee.clone(),
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::collections::BTreeSet;
+
+use ibc::apps::fee::types::PacketFee;
+use ibc::apps::transfer::types::{Amount, PrefixedDenom};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+#[derive(Debug, Default)]
+pub struct DummyFeeModule {
+    /// Channels on which the fee middleware is enabled.
+    pub fee_enabled_channels: BTreeSet<(PortId, ChannelId)>,
+    /// Fees recorded for each incentivized packet.
+    pub packet_fees: BTreeMap<(PortId, ChannelId, Sequence), Vec<PacketFee>>,
+    /// Counterparty payees registered by each relayer on each channel.
+    pub counterparty_payees: BTreeMap<(ChannelId, Signer), Signer>,
+    /// Amount of each denom held in the fee escrow.
+    pub escrows: BTreeMap<PrefixedDenom, Amount>,
+    /// Amount of each denom paid out of the fee escrow to each account.
+    pub distributed: BTreeMap<(Signer, PrefixedDenom), Amount>,
+}
+
+impl DummyFeeModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the fee middleware on the given channel.
+    pub fn with_fee_enabled(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.fee_enabled_channels.insert((port_id, channel_id));
+        self
+    }
+
+    /// Returns the amount of `denom` held in the fee escrow.
+    pub fn escrow(&self, denom: &PrefixedDenom) -> Amount {
+        self.escrows
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    /// Returns the amount of `denom` paid out of the fee escrow to `account`.
+    pub fn distributed(&self, account: &Signer, denom: &PrefixedDenom) -> Amount {
+        self.distributed
+            .get(&(account.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+}
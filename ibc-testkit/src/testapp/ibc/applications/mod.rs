@@ -1,2 +1,3 @@
+pub mod fee;
 pub mod nft_transfer;
 pub mod transfer;
@@ -1,7 +1,8 @@
 use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use ibc::apps::transfer::types::error::TokenTransferError;
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom, TransferFlow};
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
 use super::types::DummyTransferModule;
@@ -14,12 +15,23 @@ impl TokenTransferValidationContext for DummyTransferModule {
     }
 
     fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        if !self.send_enabled {
+            return Err(TokenTransferError::SendDisabled {
+                reason: "sending is disabled".to_string(),
+            });
+        }
         Ok(())
     }
 
     fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        if !self.receive_enabled {
+            return Err(TokenTransferError::ReceiveDisabled {
+                reason: "receiving is disabled".to_string(),
+            });
+        }
         Ok(())
     }
+
     fn escrow_coins_validate(
         &self,
         _from_account: &Self::AccountId,
@@ -57,27 +69,100 @@ impl TokenTransferValidationContext for DummyTransferModule {
     ) -> Result<(), TokenTransferError> {
         Ok(())
     }
+
+    fn address_hrp(&self) -> String {
+        self.address_hrp.clone()
+    }
+
+    fn check_send_rate_limit(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        match self.send_bucket(port_id, channel_id) {
+            Some(send_bucket) if coin.amount > send_bucket => {
+                Err(TokenTransferError::SendRateLimitExceeded {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn is_denom_allowed(&self, denom: &PrefixedDenom, flow: TransferFlow) -> bool {
+        !self.denied_denoms.contains(&(denom.clone(), flow))
+    }
+
+    fn escrowed_amount(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(self
+            .escrows
+            .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+            .copied())
+    }
 }
 
 impl TokenTransferExecutionContext for DummyTransferModule {
+    /// Adds the tokens to the channel's escrow and draws them from its token
+    /// bucket, if rate limited. Burnt tokens are not tied to a channel here,
+    /// hence are not accounted for.
     fn escrow_coins_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
+        if let Some(send_bucket) = self.send_bucket(port_id, channel_id) {
+            let send_bucket = send_bucket.checked_sub(coin.amount).ok_or(
+                TokenTransferError::SendRateLimitExceeded {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                },
+            )?;
+
+            self.send_buckets
+                .insert((port_id.clone(), channel_id.clone()), send_bucket);
+        }
+
+        let escrowed = self
+            .escrows
+            .entry((port_id.clone(), channel_id.clone(), coin.denom.clone()))
+            .or_insert_with(|| Amount::from(0));
+        *escrowed = escrowed
+            .checked_add(coin.amount)
+            .ok_or(TokenTransferError::InvalidToken)?;
+
         Ok(())
     }
 
+    /// Removes the tokens from the channel's escrow, if tracked.
     fn unescrow_coins_execute(
         &mut self,
         _to_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
+        if let Some(escrowed) =
+            self.escrows
+                .get_mut(&(port_id.clone(), channel_id.clone(), coin.denom.clone()))
+        {
+            *escrowed = escrowed.checked_sub(coin.amount).ok_or(
+                TokenTransferError::InsufficientEscrow {
+                    denom: coin.denom.clone(),
+                    requested: coin.amount,
+                    available: *escrowed,
+                },
+            )?;
+        }
         Ok(())
     }
 
@@ -1,3 +1,5 @@
+use ibc::apps::transfer::module::on_timeout_packet_execute;
+use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
@@ -79,12 +81,24 @@ impl Module for DummyTransferModule {
         Ok(())
     }
 
+    /// Refunds the sender of timed out ICS-20 packets. Packets carrying any
+    /// other data are timed out without further effect.
     fn on_timeout_packet_execute(
         &mut self,
-        _packet: &Packet,
-        _relayer: &Signer,
+        packet: &Packet,
+        relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>) {
-        (ModuleExtras::empty(), Ok(()))
+        match on_timeout_packet_execute(self, packet, relayer) {
+            (_, Err(TokenTransferError::PacketDataDeserialization)) => {
+                (ModuleExtras::empty(), Ok(()))
+            }
+            (extras, res) => (
+                extras,
+                res.map_err(|e| PacketError::AppModule {
+                    description: e.to_string(),
+                }),
+            ),
+        }
     }
 
     fn on_acknowledgement_packet_validate(
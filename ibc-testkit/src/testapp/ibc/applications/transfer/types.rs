@@ -1,9 +1,128 @@
+use alloc::collections::BTreeSet;
+
+use ibc::apps::transfer::types::{Amount, PrefixedDenom, TransferFlow};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
 #[derive(Debug)]
-pub struct DummyTransferModule;
+pub struct DummyTransferModule {
+    pub address_hrp: String,
+    pub send_enabled: bool,
+    pub receive_enabled: bool,
+    /// Capacity of the per-channel token buckets limiting outbound
+    /// transfers. Unlimited if `None`.
+    pub send_rate_limit: Option<Amount>,
+    /// Amount left in the token bucket of each channel. Channels without an
+    /// entry have a full bucket.
+    pub send_buckets: BTreeMap<(PortId, ChannelId), Amount>,
+    /// Amount of each denom held in escrow for each channel. Only denoms
+    /// escrowed by the module are tracked.
+    pub escrows: BTreeMap<(PortId, ChannelId, PrefixedDenom), Amount>,
+    /// Denoms that may not be sent or received, as traced on the host chain.
+    pub denied_denoms: BTreeSet<(PrefixedDenom, TransferFlow)>,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            address_hrp: "cosmos".to_string(),
+            send_enabled: true,
+            receive_enabled: true,
+            send_rate_limit: None,
+            send_buckets: BTreeMap::new(),
+            escrows: BTreeMap::new(),
+            denied_denoms: BTreeSet::new(),
+        }
+    }
+
+    /// Sets the bech32 prefix reported by the module for the host chain's
+    /// account addresses.
+    pub fn with_address_hrp(self, address_hrp: impl Into<String>) -> Self {
+        Self {
+            address_hrp: address_hrp.into(),
+            ..self
+        }
+    }
+
+    /// Enables or disables sending tokens from the host chain.
+    pub fn with_send_enabled(self, send_enabled: bool) -> Self {
+        Self {
+            send_enabled,
+            ..self
+        }
+    }
+
+    /// Limits the amount of tokens escrowed over each channel to
+    /// `send_rate_limit`, until the buckets are refilled.
+    pub fn with_send_rate_limit(self, send_rate_limit: Amount) -> Self {
+        Self {
+            send_rate_limit: Some(send_rate_limit),
+            ..self
+        }
+    }
+
+    /// Refills the token buckets of all channels, starting a new rate limit
+    /// window.
+    pub fn refill_send_buckets(&mut self) {
+        self.send_buckets.clear();
+    }
+
+    /// Returns the amount that can still be sent over the given channel, if
+    /// rate limited.
+    pub fn send_bucket(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Amount> {
+        let send_rate_limit = self.send_rate_limit?;
+
+        Some(
+            self.send_buckets
+                .get(&(port_id.clone(), channel_id.clone()))
+                .copied()
+                .unwrap_or(send_rate_limit),
+        )
+    }
+
+    /// Returns the amount of `denom` held in escrow for the given channel.
+    pub fn escrow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Amount {
+        self.escrows
+            .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    /// Asserts that the amount of `denom` held in escrow for the given
+    /// channel is `expected`.
+    #[track_caller]
+    pub fn assert_escrow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        expected: Amount,
+    ) {
+        let escrowed = self.escrow(port_id, channel_id, denom);
+
+        assert_eq!(
+            escrowed, expected,
+            "escrow of `{denom}` on `{port_id}/{channel_id}` is {escrowed}, expected {expected}"
+        );
+    }
+
+    /// Enables or disables receiving tokens on the host chain.
+    pub fn with_receive_enabled(self, receive_enabled: bool) -> Self {
+        Self {
+            receive_enabled,
+            ..self
+        }
+    }
+
+    /// Denies sending or receiving `denom`, depending on `flow`.
+    pub fn with_denied_denom(mut self, denom: PrefixedDenom, flow: TransferFlow) -> Self {
+        self.denied_denoms.insert((denom, flow));
+        self
     }
 }
 
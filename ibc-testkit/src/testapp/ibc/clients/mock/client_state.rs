@@ -7,12 +7,16 @@ use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::commitment_types::merkle::MerkleProof;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ClientType};
 use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::ics23::{calculate_existence_root, HostFunctionsManager};
 
 use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
@@ -176,9 +180,9 @@ impl ClientStateCommon for MockClientState {
 
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
         if self.latest_height() < proof_height {
-            return Err(ClientError::InvalidProofHeight {
-                latest_height: self.latest_height(),
-                proof_height,
+            return Err(ClientError::HeightTooNew {
+                got: proof_height,
+                latest: self.latest_height(),
             });
         }
         Ok(())
@@ -206,25 +210,83 @@ impl ClientStateCommon for MockClientState {
     fn verify_membership(
         &self,
         _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         _path: Path,
-        _value: Vec<u8>,
+        value: Vec<u8>,
     ) -> Result<(), ClientError> {
-        Ok(())
+        verify_proof_root(proof, root, Some(&value))
     }
 
     fn verify_non_membership(
         &self,
         _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         _path: Path,
     ) -> Result<(), ClientError> {
-        Ok(())
+        verify_proof_root(proof, root, None)
     }
 }
 
+/// Checks that the root claimed by the outermost existence proof in `proof`
+/// equals `root` and, if a `value` is given, that the innermost existence
+/// proof commits to it.
+///
+/// Mock consensus states carry the placeholder root `[0]` unless a test sets
+/// one explicitly. Any proof is accepted against that placeholder, so that
+/// tests relying on opaque dummy proofs keep working.
+fn verify_proof_root(
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    value: Option<&[u8]>,
+) -> Result<(), ClientError> {
+    if root.as_bytes() == [0] {
+        return Ok(());
+    }
+
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    let Some(Proof::Exist(existence_proof)) = merkle_proof
+        .proofs
+        .last()
+        .and_then(|proof| proof.proof.as_ref())
+    else {
+        return Err(ClientError::Ics23Verification(
+            CommitmentError::InvalidMerkleProof,
+        ));
+    };
+
+    let claimed_root = calculate_existence_root::<HostFunctionsManager>(existence_proof)
+        .map_err(|_| ClientError::Ics23Verification(CommitmentError::InvalidMerkleProof))?;
+
+    if claimed_root != root.as_bytes() {
+        return Err(ClientError::Ics23Verification(
+            CommitmentError::VerificationFailure,
+        ));
+    }
+
+    if let Some(value) = value {
+        let Some(Proof::Exist(existence_proof)) = merkle_proof
+            .proofs
+            .first()
+            .and_then(|proof| proof.proof.as_ref())
+        else {
+            return Err(ClientError::Ics23Verification(
+                CommitmentError::InvalidMerkleProof,
+            ));
+        };
+
+        if existence_proof.value != value {
+            return Err(ClientError::Ics23Verification(
+                CommitmentError::VerificationFailure,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 impl<V> ClientStateValidation<V> for MockClientState
 where
     V: ClientValidationContext + MockClientContext,
@@ -274,6 +336,32 @@ where
         }
     }
 
+    fn header_height(&self, client_message: Any) -> Result<Option<Height>, ClientError> {
+        match client_message.type_url.as_str() {
+            MOCK_HEADER_TYPE_URL => Ok(Some(MockHeader::try_from(client_message)?.height())),
+            MOCK_MISBEHAVIOUR_TYPE_URL => Ok(None),
+            header_type => Err(ClientError::UnknownHeaderType {
+                header_type: header_type.to_owned(),
+            }),
+        }
+    }
+
+    fn misbehaviour_heights(&self, client_message: Any) -> Result<Vec<Height>, ClientError> {
+        match client_message.type_url.as_str() {
+            MOCK_HEADER_TYPE_URL => Ok(vec![MockHeader::try_from(client_message)?.height()]),
+            MOCK_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                let mut heights =
+                    vec![misbehaviour.header1.height(), misbehaviour.header2.height()];
+                heights.dedup();
+                Ok(heights)
+            }
+            header_type => Err(ClientError::UnknownHeaderType {
+                header_type: header_type.to_owned(),
+            }),
+        }
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         if self.is_frozen() {
             return Ok(Status::Frozen);
@@ -326,16 +414,16 @@ where
     ) -> Result<(), ClientError> {
         let mock_consensus_state: MockConsensusState = consensus_state.try_into()?;
 
-        ctx.store_client_state(ClientStatePath::new(client_id.clone()), (*self).into())?;
-        ctx.store_consensus_state(
+        ctx.store_client_state_observed(ClientStatePath::new(client_id.clone()), *self)?;
+        ctx.store_consensus_state_observed(
             ClientConsensusStatePath::new(
                 client_id.clone(),
                 self.latest_height().revision_number(),
                 self.latest_height().revision_height(),
             ),
-            mock_consensus_state.into(),
+            mock_consensus_state,
         )?;
-        ctx.store_update_meta(
+        ctx.store_update_meta_observed(
             client_id.clone(),
             self.latest_height(),
             ctx.host_timestamp()?,
@@ -357,19 +445,16 @@ where
         let new_client_state = Self::new(header);
         let new_consensus_state = MockConsensusState::new(header);
 
-        ctx.store_consensus_state(
+        ctx.store_consensus_state_observed(
             ClientConsensusStatePath::new(
                 client_id.clone(),
                 new_client_state.latest_height().revision_number(),
                 new_client_state.latest_height().revision_height(),
             ),
-            new_consensus_state.into(),
-        )?;
-        ctx.store_client_state(
-            ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
+            new_consensus_state,
         )?;
-        ctx.store_update_meta(
+        ctx.store_client_state_observed(ClientStatePath::new(client_id.clone()), new_client_state)?;
+        ctx.store_update_meta_observed(
             client_id.clone(),
             header_height,
             ctx.host_timestamp()?,
@@ -387,9 +472,9 @@ where
     ) -> Result<(), ClientError> {
         let frozen_client_state = self.frozen();
 
-        ctx.store_client_state(
+        ctx.store_client_state_observed(
             ClientStatePath::new(client_id.clone()),
-            frozen_client_state.into(),
+            frozen_client_state,
         )?;
 
         Ok(())
@@ -407,23 +492,20 @@ where
 
         let latest_height = new_client_state.latest_height();
 
-        ctx.store_consensus_state(
+        ctx.store_consensus_state_observed(
             ClientConsensusStatePath::new(
                 client_id.clone(),
                 latest_height.revision_number(),
                 latest_height.revision_height(),
             ),
-            new_consensus_state.into(),
-        )?;
-        ctx.store_client_state(
-            ClientStatePath::new(client_id.clone()),
-            new_client_state.into(),
+            new_consensus_state,
         )?;
+        ctx.store_client_state_observed(ClientStatePath::new(client_id.clone()), new_client_state)?;
 
         let host_timestamp = ctx.host_timestamp()?;
         let host_height = ctx.host_height()?;
 
-        ctx.store_update_meta(
+        ctx.store_update_meta_observed(
             client_id.clone(),
             latest_height,
             host_timestamp,
@@ -454,21 +536,21 @@ where
 
         let mock_consensus_state: MockConsensusState = substitute_consensus_state.try_into()?;
 
-        ctx.store_consensus_state(
+        ctx.store_consensus_state_observed(
             ClientConsensusStatePath::new(
                 subject_client_id.clone(),
                 new_mock_client_state.latest_height().revision_number(),
                 new_mock_client_state.latest_height().revision_height(),
             ),
-            mock_consensus_state.into(),
+            mock_consensus_state,
         )?;
 
-        ctx.store_client_state(
+        ctx.store_client_state_observed(
             ClientStatePath::new(subject_client_id.clone()),
-            new_mock_client_state.into(),
+            new_mock_client_state,
         )?;
 
-        ctx.store_update_meta(
+        ctx.store_update_meta_observed(
             subject_client_id.clone(),
             latest_height,
             host_timestamp,
@@ -487,13 +569,105 @@ impl From<MockConsensusState> for MockClientState {
 
 #[cfg(test)]
 mod test {
+    use ibc::core::client::context::client_state::ClientStateCommon;
+    use ibc::core::client::types::error::ClientError;
+    use ibc::core::commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc::core::commitment_types::error::CommitmentError;
+    use ibc::core::commitment_types::merkle::MerkleProof;
+    use ibc::core::host::types::identifiers::ClientId;
+    use ibc::core::host::types::path::{ClientStatePath, Path};
+    use ibc_proto::ics23::commitment_proof::Proof;
+    use ibc_proto::ics23::{
+        calculate_existence_root, CommitmentProof, ExistenceProof, HashOp, HostFunctionsManager,
+        LeafOp, LengthOp,
+    };
+
+    use super::{MockClientState, MockHeader};
+
+    /// Returns a single-leaf proof along with the root it claims.
+    fn proof_with_root() -> (CommitmentProofBytes, CommitmentRoot) {
+        let existence_proof = ExistenceProof {
+            key: b"key".to_vec(),
+            value: b"value".to_vec(),
+            leaf: Some(LeafOp {
+                hash: HashOp::Sha256.into(),
+                prehash_key: HashOp::NoHash.into(),
+                prehash_value: HashOp::Sha256.into(),
+                length: LengthOp::VarProto.into(),
+                prefix: vec![0],
+            }),
+            path: vec![],
+        };
+        let root = calculate_existence_root::<HostFunctionsManager>(&existence_proof).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+
+        (
+            CommitmentProofBytes::try_from(proof).unwrap(),
+            CommitmentRoot::from(root),
+        )
+    }
+
+    #[test]
+    fn test_verify_membership_checks_root() {
+        let client_state = MockClientState::new(MockHeader::default());
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let path = Path::ClientState(ClientStatePath::new(ClientId::new("9999-mock", 0).unwrap()));
+        let (proof, root) = proof_with_root();
+
+        let res =
+            client_state.verify_membership(&prefix, &proof, &root, path.clone(), b"value".to_vec());
+        assert!(res.is_ok(), "matching root must verify. err: {res:?}");
+
+        let res = client_state.verify_membership(
+            &prefix,
+            &proof,
+            &CommitmentRoot::from(b"another root".to_vec()),
+            path,
+            b"value".to_vec(),
+        );
+        assert!(
+            matches!(
+                res,
+                Err(ClientError::Ics23Verification(
+                    CommitmentError::VerificationFailure
+                ))
+            ),
+            "mismatched root must fail. err: {res:?}"
+        );
+    }
+
+    #[test]
+    fn test_verify_membership_checks_value() {
+        let client_state = MockClientState::new(MockHeader::default());
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let path = Path::ClientState(ClientStatePath::new(ClientId::new("9999-mock", 0).unwrap()));
+        let (proof, root) = proof_with_root();
+
+        let res =
+            client_state.verify_membership(&prefix, &proof, &root, path, b"another value".to_vec());
+        assert!(
+            matches!(
+                res,
+                Err(ClientError::Ics23Verification(
+                    CommitmentError::VerificationFailure
+                ))
+            ),
+            "mismatched value must fail. err: {res:?}"
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_any_client_state_to_json() {
         use ibc::primitives::proto::Any;
 
-        use super::{MockClientState, MockHeader};
-
         let client_state = MockClientState::new(MockHeader::default());
         let expected = r#"{"typeUrl":"/ibc.mock.ClientState","value":"CgQKAhABEICAkMrSxg4="}"#;
         let json = serde_json::to_string(&Any::from(client_state)).unwrap();
@@ -94,3 +94,37 @@ impl ConsensusState for MockConsensusState {
         self.header.timestamp
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::client::context::consensus_state::ConsensusState;
+    use ibc::core::client::types::Height;
+    use ibc::core::commitment_types::commitment::CommitmentRoot;
+    use ibc::core::primitives::Timestamp;
+
+    use super::MockConsensusState;
+    use crate::testapp::ibc::clients::mock::header::MockHeader;
+
+    #[test]
+    fn security_equals_ignores_irrelevant_fields() {
+        let timestamp = Timestamp::from_nanoseconds(1).unwrap();
+        let consensus_state = MockConsensusState::new(MockHeader {
+            height: Height::new(0, 5).unwrap(),
+            timestamp,
+        });
+
+        // The header height is not part of the security-relevant fields.
+        let other_height = MockConsensusState::new(MockHeader {
+            height: Height::new(0, 6).unwrap(),
+            timestamp,
+        });
+        assert_ne!(consensus_state, other_height);
+        assert!(consensus_state.security_equals(&other_height));
+
+        let other_root = MockConsensusState {
+            root: CommitmentRoot::from(vec![1]),
+            ..consensus_state.clone()
+        };
+        assert!(!consensus_state.security_equals(&other_root));
+    }
+}
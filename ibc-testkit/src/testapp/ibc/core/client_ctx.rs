@@ -58,7 +58,8 @@ where
         ValidationContext::host_height(self)
     }
 
-    /// Returns the list of heights at which the consensus state of the given client was updated.
+    /// Returns the list of heights at which the consensus state of the given
+    /// client was updated, in ascending order.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
         let path = format!("clients/{}/consensusStates", client_id)
             .try_into()
@@ -66,7 +67,9 @@ where
                 description: "Invalid consensus state path".into(),
             })?;
 
-        self.consensus_state_store
+        // Store keys are ordered as strings, so heights are sorted explicitly.
+        let mut heights = self
+            .consensus_state_store
             .get_keys(&path)
             .into_iter()
             .filter_map(|path| {
@@ -82,7 +85,15 @@ where
                     consensus_path.revision_height,
                 )?)
             })
-            .collect::<Result<Vec<_>, _>>()
+            .collect::<Result<Vec<_>, ContextError>>()?;
+
+        heights.sort();
+
+        Ok(heights)
+    }
+
+    fn max_consensus_states(&self) -> Option<u64> {
+        self.max_consensus_states
     }
 
     fn next_consensus_state(
@@ -90,32 +101,23 @@ where
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
-        let path = format!("clients/{client_id}/consensusStates").into();
-
-        let keys = self.store.get_keys(&path);
-        let found_path = keys.into_iter().find_map(|path| {
-            if let Ok(Path::ClientConsensusState(path)) = path.try_into() {
-                if height
-                    < &Height::new(path.revision_number, path.revision_height).expect("no error")
-                {
-                    return Some(path);
-                }
-            }
-            None
-        });
-
-        let consensus_state = found_path
-            .map(|path| {
-                self.consensus_state_store
-                    .get(StoreHeight::Pending, &path)
-                    .ok_or_else(|| ClientError::ConsensusStateNotFound {
-                        client_id: client_id.clone(),
-                        height: *height,
-                    })
+        // Store keys are ordered as strings, so heights are compared
+        // explicitly to respect the ordering across revisions.
+        let next_height = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .filter(|h| h > height)
+            .min();
+
+        next_height
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
             })
-            .transpose()?;
-
-        Ok(consensus_state)
+            .transpose()
     }
 
     fn prev_consensus_state(
@@ -123,32 +125,21 @@ where
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
-        let path = format!("clients/{client_id}/consensusStates").into();
-
-        let keys = self.store.get_keys(&path);
-        let found_path = keys.into_iter().rev().find_map(|path| {
-            if let Ok(Path::ClientConsensusState(path)) = path.try_into() {
-                if height
-                    > &Height::new(path.revision_number, path.revision_height).expect("no error")
-                {
-                    return Some(path);
-                }
-            }
-            None
-        });
-
-        let consensus_state = found_path
-            .map(|path| {
-                self.consensus_state_store
-                    .get(StoreHeight::Pending, &path)
-                    .ok_or_else(|| ClientError::ConsensusStateNotFound {
-                        client_id: client_id.clone(),
-                        height: *height,
-                    })
+        let prev_height = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .filter(|h| h < height)
+            .max();
+
+        prev_height
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
             })
-            .transpose()?;
-
-        Ok(consensus_state)
+            .transpose()
     }
 }
 
@@ -322,4 +313,14 @@ where
             })?;
         Ok(())
     }
+
+    fn on_store_write(&mut self, path: &Path, value: &[u8]) {
+        self.store_writes
+            .lock()
+            .push((path.clone(), value.to_vec()));
+    }
+
+    fn on_store_delete(&mut self, path: &Path) {
+        self.store_deletes.lock().push(path.clone());
+    }
 }
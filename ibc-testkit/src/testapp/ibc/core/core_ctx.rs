@@ -10,15 +10,17 @@ use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCom
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::{PacketState, Receipt};
 use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::context::ExtClientValidationContext;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::merkle::MerkleProof;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::handler::types::msgs::MsgKind;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
@@ -27,6 +29,9 @@ use ibc::core::host::types::path::{
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
@@ -52,7 +57,13 @@ where
     fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
         let host_height = self.host_height()?;
         let host_cons_state = self.host_consensus_state(&host_height)?;
-        Ok(host_cons_state.timestamp())
+        let block_timestamp = host_cons_state.timestamp();
+
+        // Once later blocks catch up with the overridden time, they take over.
+        Ok(self
+            .host_timestamp_override
+            .filter(|timestamp| timestamp > &block_timestamp)
+            .unwrap_or(block_timestamp))
     }
 
     fn client_counter(&self) -> Result<u64, ContextError> {
@@ -147,6 +158,16 @@ where
             })?)
     }
 
+    fn client_connections(
+        &self,
+        client_connection_path: &ClientConnectionPath,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        Ok(self
+            .connection_ids_store
+            .get(StoreHeight::Pending, client_connection_path)
+            .unwrap_or_default())
+    }
+
     fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
         Ok(self
             .channel_end_store
@@ -242,6 +263,32 @@ where
             })?)
     }
 
+    fn last_recv_proof_height(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(self
+            .last_recv_proof_heights
+            .lock()
+            .get(seq_recv_path)
+            .copied())
+    }
+
+    fn last_client_status(&self, client_id: &ClientId) -> Result<Option<Status>, ContextError> {
+        Ok(self.client_statuses.lock().get(client_id).copied())
+    }
+
+    fn is_sequence_acknowledged(
+        &self,
+        seq_ack_path: &SeqAckPath,
+        sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        Ok(self
+            .acknowledged_sequences
+            .lock()
+            .contains(&(seq_ack_path.clone(), sequence)))
+    }
+
     /// Returns a counter of the number of channel ids that have been created thus far.
     /// The value of this counter should increase only via the
     /// `ChannelKeeper::increase_channel_counter` method.
@@ -259,10 +306,71 @@ where
         Duration::from_secs(DEFAULT_BLOCK_TIME_SECS)
     }
 
+    fn min_connection_delay(&self) -> Duration {
+        self.min_connection_delay
+    }
+
+    fn max_proof_staleness(&self) -> Option<u64> {
+        self.max_proof_staleness
+    }
+
     fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
         Ok(())
     }
 
+    fn authorize(&self, signer: &Signer, msg_kind: &MsgKind) -> Result<(), ContextError> {
+        if let Some(authorized_signer) = self.authorized_signers.get(msg_kind) {
+            if authorized_signer != signer {
+                return Err(RouterError::UnauthorizedSigner {
+                    signer: signer.clone(),
+                    msg_kind: msg_kind.to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn port_owner(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_owners.get(port_id).cloned()
+    }
+
+    fn bound_ports(&self) -> Vec<PortId> {
+        self.port_owners.keys().cloned().collect()
+    }
+
+    fn get_raw_value(&self, path: &Path) -> Option<Vec<u8>> {
+        self.store
+            .get(StoreHeight::Pending, &path.to_string().into())
+    }
+
+    fn find_matching_client(
+        &self,
+        client_state: &Any,
+        consensus_state: &Any,
+    ) -> Result<Option<ClientId>, ContextError> {
+        if !self.dedup_create_client {
+            return Ok(None);
+        }
+
+        for (client_id, stored_client_state) in self.client_states()? {
+            if &Any::from(stored_client_state) != client_state {
+                continue;
+            }
+
+            let has_matching_consensus_state = self.consensus_states(&client_id)?.into_iter().any(
+                |(_, stored_consensus_state)| &Any::from(stored_consensus_state) == consensus_state,
+            );
+
+            if has_matching_consensus_state {
+                return Ok(Some(client_id));
+            }
+        }
+
+        Ok(None)
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
@@ -364,31 +472,10 @@ where
             .collect()
     }
 
-    /// Returns the list of heights at which the consensus state of the given client was updated.
+    /// Returns the list of heights at which the consensus state of the given
+    /// client was updated, in ascending order.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
-        let path = format!("clients/{}/consensusStates", client_id)
-            .try_into()
-            .map_err(|_| ClientError::Other {
-                description: "Invalid consensus state path".into(),
-            })?;
-
-        self.consensus_state_store
-            .get_keys(&path)
-            .into_iter()
-            .filter_map(|path| {
-                if let Ok(Path::ClientConsensusState(consensus_path)) = path.try_into() {
-                    Some(consensus_path)
-                } else {
-                    None
-                }
-            })
-            .map(|consensus_path| {
-                Ok(Height::new(
-                    consensus_path.revision_number,
-                    consensus_path.revision_height,
-                )?)
-            })
-            .collect::<Result<Vec<_>, _>>()
+        ExtClientValidationContext::consensus_state_heights(self, client_id)
     }
 
     /// Returns all the IBC connection ends of a chain.
@@ -798,6 +885,39 @@ where
         Ok(())
     }
 
+    fn store_last_recv_proof_height(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.last_recv_proof_heights
+            .lock()
+            .insert(seq_recv_path.clone(), height);
+        Ok(())
+    }
+
+    fn store_acknowledged_sequence(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        sequence: Sequence,
+    ) -> Result<(), ContextError> {
+        self.acknowledged_sequences
+            .lock()
+            .insert((seq_ack_path.clone(), sequence));
+        Ok(())
+    }
+
+    fn store_client_status(
+        &mut self,
+        client_id: &ClientId,
+        status: Status,
+    ) -> Result<(), ContextError> {
+        self.client_statuses
+            .lock()
+            .insert(client_id.clone(), status);
+        Ok(())
+    }
+
     fn store_next_sequence_ack(
         &mut self,
         seq_ack_path: &SeqAckPath,
@@ -1,7 +1,9 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
+use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
@@ -9,18 +11,20 @@ use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedS
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::handler::types::msgs::MsgKind;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::types::module::ModuleId;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
@@ -93,6 +97,31 @@ where
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// Store writes reported to `on_store_write`, in order
+    pub store_writes: Arc<Mutex<Vec<(Path, Vec<u8>)>>>,
+    /// Store deletions reported to `on_store_delete`, in order
+    pub store_deletes: Arc<Mutex<Vec<Path>>>,
+    /// Maximum number of consensus states stored per client, if any
+    pub max_consensus_states: Option<u64>,
+    /// Map of message kinds to the only signer authorized to submit them
+    pub authorized_signers: BTreeMap<MsgKind, Signer>,
+    /// Map of bound ports to the module owning them
+    pub port_owners: BTreeMap<PortId, ModuleId>,
+    /// Whether create-client messages identical to an existing client reuse
+    /// its identifier
+    pub dedup_create_client: bool,
+    /// Host time set ahead of the latest block, if any
+    pub host_timestamp_override: Option<Timestamp>,
+    /// Proof height of the last packet received on each ordered channel
+    pub last_recv_proof_heights: Arc<Mutex<BTreeMap<SeqRecvPath, Height>>>,
+    /// Status of each client as of its last status check
+    pub client_statuses: Arc<Mutex<BTreeMap<ClientId, Status>>>,
+    /// Sequences of the packets acknowledged on each unordered channel
+    pub acknowledged_sequences: Arc<Mutex<BTreeSet<(SeqAckPath, Sequence)>>>,
+    /// Minimum delay period accepted for new connections
+    pub min_connection_delay: Duration,
+    /// Maximum lag, in blocks, of connection handshake proofs, if any
+    pub max_proof_staleness: Option<u64>,
 }
 
 impl<S> MockIbcStore<S>
@@ -140,6 +169,18 @@ where
             packet_ack_store: TypedStore::new(shared_store.clone()),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            store_writes: Arc::new(Mutex::new(Vec::new())),
+            store_deletes: Arc::new(Mutex::new(Vec::new())),
+            max_consensus_states: None,
+            authorized_signers: BTreeMap::new(),
+            port_owners: BTreeMap::new(),
+            dedup_create_client: false,
+            host_timestamp_override: None,
+            last_recv_proof_heights: Arc::new(Mutex::new(BTreeMap::new())),
+            client_statuses: Arc::new(Mutex::new(BTreeMap::new())),
+            acknowledged_sequences: Arc::new(Mutex::new(BTreeSet::new())),
+            min_connection_delay: Duration::ZERO,
+            max_proof_staleness: None,
             store: shared_store,
         }
     }
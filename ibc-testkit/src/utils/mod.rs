@@ -1,4 +1,7 @@
-use ibc::primitives::Timestamp;
+use core::fmt::{Debug, Display};
+
+use ibc::primitives::proto::Protobuf;
+use ibc::primitives::{Timestamp, ToProto};
 use tendermint::Time;
 
 /// Returns a `Timestamp` representation of beginning of year 2023.
@@ -15,3 +18,20 @@ pub fn year_2023() -> Timestamp {
         .expect("should be a valid time")
         .into()
 }
+
+/// Asserts that `msg` survives a round trip through its protobuf `Any`
+/// encoding: it must be encoded under the type URL of its raw type `P`, and
+/// decode back to an equal message.
+pub fn assert_any_round_trip<M, P>(msg: M)
+where
+    M: ToProto<P> + Clone + Debug + PartialEq,
+    P: From<M> + prost::Message + prost::Name + Default,
+    <M as TryFrom<P>>::Error: Display,
+{
+    let any = msg.clone().to_any();
+    assert_eq!(any.type_url, P::type_url());
+
+    let msg_back =
+        <M as Protobuf<P>>::decode_vec(&any.value).expect("message decodes from its `Any` value");
+    assert_eq!(msg_back, msg);
+}
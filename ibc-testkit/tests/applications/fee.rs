@@ -0,0 +1,309 @@
+use ibc::apps::fee::context::FeeValidationContext;
+use ibc::apps::fee::handler::{pay_packet_fee, pay_packet_fee_validate};
+use ibc::apps::fee::module::{
+    on_acknowledgement_packet_execute, on_recv_packet_execute, on_timeout_packet_execute,
+    FeeMiddleware,
+};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc::apps::fee::types::proto::fee::v1::{Fee as RawFee, MsgPayPacketFee as RawMsgPayPacketFee};
+use ibc::apps::fee::types::{Fee, IncentivizedAcknowledgement, PacketFee, VersionMetadata};
+use ibc::apps::transfer::types::{Amount, PrefixedDenom, VERSION};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::testapp::ibc::applications::fee::types::DummyFeeModule;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use rstest::*;
+
+fn fee_context() -> MockContext {
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION.to_string()),
+    )
+    .expect("no error");
+
+    MockContext::default()
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+}
+
+fn dummy_fee() -> Fee {
+    Fee {
+        recv_fee: vec!["10uatom".parse().expect("valid prefixed coin")],
+        ack_fee: vec!["20uatom".parse().expect("valid prefixed coin")],
+        timeout_fee: vec!["30uatom".parse().expect("valid prefixed coin")],
+    }
+}
+
+fn dummy_msg_pay_packet_fee(payer: &Signer) -> MsgPayPacketFee {
+    MsgPayPacketFee {
+        fee: dummy_fee(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        signer: payer.clone(),
+    }
+}
+
+fn dummy_packet() -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: b"packet data".to_vec(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+#[test]
+fn test_incentivized_acknowledgement_roundtrip() {
+    let incentivized_ack = IncentivizedAcknowledgement {
+        app_acknowledgement: Acknowledgement::try_from(b"app ack".to_vec()).expect("non-empty"),
+        forward_relayer_address: Signer::from("payee".to_string()),
+        underlying_app_success: true,
+    };
+
+    let ack = Acknowledgement::from(incentivized_ack.clone());
+
+    // Encoded as proto3 JSON, as ibc-go writes it.
+    assert_eq!(
+        ack.as_ref(),
+        br#"{"app_acknowledgement":"YXBwIGFjaw==","forward_relayer_address":"payee","underlying_app_success":true}"#
+    );
+
+    assert_eq!(
+        IncentivizedAcknowledgement::try_from(&ack).unwrap(),
+        incentivized_ack
+    );
+}
+
+#[rstest]
+#[case::missing_fee(RawMsgPayPacketFee { fee: None, ..raw_msg_pay_packet_fee() })]
+#[case::empty_fee(RawMsgPayPacketFee { fee: Some(RawFee::default()), ..raw_msg_pay_packet_fee() })]
+#[case::relayers(RawMsgPayPacketFee { relayers: vec!["relayer".to_string()], ..raw_msg_pay_packet_fee() })]
+#[case::empty_signer(RawMsgPayPacketFee { signer: String::new(), ..raw_msg_pay_packet_fee() })]
+fn test_msg_pay_packet_fee_invalid(#[case] raw_msg: RawMsgPayPacketFee) {
+    let res = MsgPayPacketFee::try_from(raw_msg);
+
+    assert!(res.is_err(), "{res:?}");
+}
+
+fn raw_msg_pay_packet_fee() -> RawMsgPayPacketFee {
+    dummy_msg_pay_packet_fee(&Signer::from("payer".to_string())).into()
+}
+
+#[test]
+fn test_msg_pay_packet_fee_roundtrip() {
+    let msg = dummy_msg_pay_packet_fee(&Signer::from("payer".to_string()));
+
+    assert_eq!(
+        MsgPayPacketFee::try_from(RawMsgPayPacketFee::from(msg.clone())).unwrap(),
+        msg
+    );
+}
+
+/// Paying a fee escrows it and records it for the next packet sent on the
+/// channel.
+#[test]
+fn test_pay_packet_fee_escrows_and_records_fee() {
+    let mut ctx = fee_context();
+    let mut fee_ctx = DummyFeeModule::new().with_fee_enabled(PortId::transfer(), ChannelId::zero());
+    let payer = Signer::from("payer".to_string());
+
+    let res = pay_packet_fee(
+        &mut ctx.ibc_store,
+        &mut fee_ctx,
+        dummy_msg_pay_packet_fee(&payer),
+    );
+    assert!(res.is_ok(), "{res:?}");
+
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    assert_eq!(fee_ctx.escrow(&denom), Amount::from(60));
+
+    assert_eq!(
+        fee_ctx.packet_fees[&(PortId::transfer(), ChannelId::zero(), Sequence::from(1))],
+        vec![PacketFee {
+            fee: dummy_fee(),
+            refund_address: payer,
+        }]
+    );
+
+    assert!(ctx.ibc_store.events.lock().iter().any(
+        |event| matches!(event, IbcEvent::Module(event) if event.kind == "incentivized_ibc_packet")
+    ));
+}
+
+#[test]
+fn test_pay_packet_fee_not_enabled() {
+    let ctx = fee_context();
+    let fee_ctx = DummyFeeModule::new();
+
+    let res = pay_packet_fee_validate(
+        &ctx.ibc_store,
+        &fee_ctx,
+        dummy_msg_pay_packet_fee(&Signer::from("payer".to_string())),
+    );
+
+    assert!(
+        matches!(res, Err(FeeError::FeeNotEnabled { .. })),
+        "{res:?}"
+    );
+}
+
+/// The receive fee goes to the payee forwarded in the acknowledgement, the
+/// acknowledgement fee to the relayer delivering it, and the timeout fee is
+/// refunded.
+#[test]
+fn test_on_acknowledgement_packet_distributes_fee() {
+    let mut ctx = fee_context();
+    let mut fee_ctx = DummyFeeModule::new().with_fee_enabled(PortId::transfer(), ChannelId::zero());
+    let payer = Signer::from("payer".to_string());
+    let relayer = Signer::from("relayer".to_string());
+    let payee = Signer::from("payee".to_string());
+
+    let res = pay_packet_fee(
+        &mut ctx.ibc_store,
+        &mut fee_ctx,
+        dummy_msg_pay_packet_fee(&payer),
+    );
+    assert!(res.is_ok(), "{res:?}");
+
+    fee_ctx
+        .counterparty_payees
+        .insert((ChannelId::zero(), relayer.clone()), payee.clone());
+
+    let packet = dummy_packet();
+    let app_ack = Acknowledgement::try_from(b"app ack".to_vec()).expect("non-empty");
+
+    let ack = on_recv_packet_execute(&fee_ctx, &packet, &relayer, app_ack.clone(), true)
+        .expect("no error");
+
+    let (extras, res) = on_acknowledgement_packet_execute(&mut fee_ctx, &packet, &ack, &relayer);
+    assert_eq!(res.unwrap(), app_ack);
+    assert_eq!(extras.events.len(), 3);
+
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    assert_eq!(fee_ctx.distributed(&payee, &denom), Amount::from(10));
+    assert_eq!(fee_ctx.distributed(&relayer, &denom), Amount::from(20));
+    assert_eq!(fee_ctx.distributed(&payer, &denom), Amount::from(30));
+    assert_eq!(fee_ctx.escrow(&denom), Amount::from(0));
+    assert!(fee_ctx.packet_fees.is_empty());
+}
+
+/// The timeout fee goes to the relayer delivering the timeout, and the
+/// receive and acknowledgement fees are refunded.
+#[test]
+fn test_on_timeout_packet_distributes_fee() {
+    let mut ctx = fee_context();
+    let mut fee_ctx = DummyFeeModule::new().with_fee_enabled(PortId::transfer(), ChannelId::zero());
+    let payer = Signer::from("payer".to_string());
+    let relayer = Signer::from("relayer".to_string());
+
+    let res = pay_packet_fee(
+        &mut ctx.ibc_store,
+        &mut fee_ctx,
+        dummy_msg_pay_packet_fee(&payer),
+    );
+    assert!(res.is_ok(), "{res:?}");
+
+    let (extras, res) = on_timeout_packet_execute(&mut fee_ctx, &dummy_packet(), &relayer);
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(extras.events.len(), 2);
+
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    assert_eq!(fee_ctx.distributed(&relayer, &denom), Amount::from(30));
+    assert_eq!(fee_ctx.distributed(&payer, &denom), Amount::from(30));
+    assert_eq!(fee_ctx.escrow(&denom), Amount::from(0));
+    assert!(fee_ctx.packet_fees.is_empty());
+}
+
+fn chan_open_init_execute(
+    module: &mut impl Module,
+    version: &Version,
+) -> Result<Version, ChannelError> {
+    module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &[ConnectionId::zero()],
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            &Counterparty::new(PortId::transfer(), None),
+            version,
+        )
+        .map(|(_, version)| version)
+}
+
+/// Opening a channel with a fee-wrapped version enables the fee middleware
+/// on it, while the application negotiates its own version.
+#[test]
+fn test_fee_middleware_chan_open_init_enables_fee() {
+    let mut module = FeeMiddleware::new(DummyFeeModule::new(), DummyTransferModule::new());
+    let version = Version::from(VersionMetadata::new(VERSION));
+
+    let res = chan_open_init_execute(&mut module, &version);
+
+    assert_eq!(res.expect("no error"), version);
+    assert!(module
+        .ctx()
+        .is_fee_enabled(&PortId::transfer(), &ChannelId::zero()));
+}
+
+#[test]
+fn test_fee_middleware_chan_open_init_without_fee() {
+    let mut module = FeeMiddleware::new(DummyFeeModule::new(), DummyTransferModule::new());
+    let version = Version::new(VERSION.to_string());
+
+    let res = chan_open_init_execute(&mut module, &version);
+
+    assert_eq!(res.expect("no error"), version);
+    assert!(!module
+        .ctx()
+        .is_fee_enabled(&PortId::transfer(), &ChannelId::zero()));
+}
+
+#[test]
+fn test_fee_middleware_chan_open_init_unsupported_fee_version() {
+    let mut module = FeeMiddleware::new(DummyFeeModule::new(), DummyTransferModule::new());
+    let version = Version::from(VersionMetadata {
+        fee_version: "ics29-2".to_string(),
+        app_version: VERSION.to_string(),
+    });
+
+    let res = chan_open_init_execute(&mut module, &version);
+
+    assert!(
+        matches!(res, Err(ChannelError::VersionNotSupported { .. })),
+        "{res:?}"
+    );
+}
+
+/// Packets received on fee-enabled channels are acknowledged with the
+/// application acknowledgement wrapped in an incentivized one.
+#[test]
+fn test_fee_middleware_on_recv_packet_wraps_ack() {
+    let fee_ctx = DummyFeeModule::new().with_fee_enabled(PortId::transfer(), ChannelId::zero());
+    let mut module = FeeMiddleware::new(fee_ctx, DummyTransferModule::new());
+
+    let (_, ack) =
+        module.on_recv_packet_execute(&dummy_packet(), &Signer::from("relayer".to_string()));
+
+    let incentivized_ack = IncentivizedAcknowledgement::try_from(&ack).expect("incentivized ack");
+    assert_eq!(incentivized_ack.app_acknowledgement.as_ref(), [1u8]);
+    assert!(incentivized_ack.underlying_app_success);
+}
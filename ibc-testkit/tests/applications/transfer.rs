@@ -1,14 +1,46 @@
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc::apps::transfer::handler::{
+    process_recv_packet_execute, send_transfer, send_transfer_validate,
+};
 use ibc::apps::transfer::module::{
-    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_acknowledgement_packet_execute, on_chan_open_ack_validate, on_chan_open_init_execute,
+    on_chan_open_init_validate, on_chan_open_try_execute, on_chan_open_try_validate,
+    on_recv_packet_execute, on_timeout_packet_execute,
+};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{
+    ack_success_b64, Amount, PrefixedDenom, TransferFlow, DEFAULT_MAX_MEMO_LENGTH,
+    DEFAULT_MAX_TRACE_HOPS, VERSION,
 };
-use ibc::apps::transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::execute;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -26,7 +58,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyTransferModule,
+        DummyTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -65,6 +97,31 @@ fn test_cosmos_escrow_address() {
     );
 }
 
+#[test]
+fn test_escrow_address_uses_address_hrp() {
+    let port_id = PortId::transfer();
+    let channel_id: ChannelId = "channel-141".parse().unwrap();
+
+    let derive_escrow_address = |ctx: &DummyTransferModule| {
+        bech32::encode(
+            ctx.address_hrp(),
+            cosmos_adr028_escrow_address(&port_id, &channel_id),
+        )
+    };
+
+    assert_eq!(
+        derive_escrow_address(&DummyTransferModule::new()),
+        "cosmos1x54ltnyg88k0ejmk8ytwrhd3ltm84xehrnlslf"
+    );
+
+    let escrow_address =
+        derive_escrow_address(&DummyTransferModule::new().with_address_hrp("osmo"));
+
+    let (hrp, addr) = bech32::decode(escrow_address).unwrap();
+    assert_eq!(hrp, "osmo");
+    assert_eq!(addr, cosmos_adr028_escrow_address(&port_id, &channel_id));
+}
+
 /// If the relayer passed "", indicating that it wants us to return the versions we support.
 /// We currently only support ics20
 #[test]
@@ -126,6 +183,28 @@ fn test_on_chan_open_init_incorrect_version() {
     assert!(res.is_err());
 }
 
+/// The fee middleware unwraps its version before calling into ics20, so a
+/// fee-wrapped version reaching ics20 directly is unsupported
+#[test]
+fn test_on_chan_open_init_fee_wrapped_version() {
+    let (ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let in_version = Version::new(format!(
+        r#"{{"fee_version":"ics29-1","app_version":"{VERSION}"}}"#
+    ));
+    let res = on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    );
+
+    assert!(res.is_err());
+}
+
 /// If the counterparty supports ics20, then return ics20
 #[test]
 fn test_on_chan_open_try_counterparty_correct_version() {
@@ -166,3 +245,639 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the counterparty acknowledges with ics20, then accept
+#[test]
+fn test_on_chan_open_ack_counterparty_correct_version() {
+    let (ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+    let counterparty_version = Version::new(VERSION.to_string());
+
+    let res = on_chan_open_ack_validate(&ctx, &port_id, &channel_id, &counterparty_version);
+
+    assert!(res.is_ok());
+}
+
+/// If the counterparty acknowledges with an incompatible version, then fail
+#[test]
+fn test_on_chan_open_ack_counterparty_incorrect_version() {
+    let (ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+    let counterparty_version = Version::new("some-unsupported-version".to_string());
+
+    let res = on_chan_open_ack_validate(&ctx, &port_id, &channel_id, &counterparty_version);
+
+    assert!(res.is_err());
+}
+
+/// Returns a packet received on `channel-1` carrying a token whose denom
+/// already went through `hops` other channels.
+fn recv_packet_with_trace_hops(hops: u64) -> Packet {
+    let trace_path: String = (0..hops)
+        .map(|i| format!("transfer/channel-{}/", i + 100))
+        .collect();
+    let packet_data = PacketData {
+        token: format!("1000{trace_path}uatom")
+            .parse()
+            .expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&packet_data).expect("infallible"),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+/// A received token may reach, but not exceed, the maximum number of trace
+/// hops once prefixed with the receiving channel.
+#[test]
+fn test_on_recv_packet_max_trace_hops() {
+    let mut ctx = DummyTransferModule::new();
+
+    let packet = recv_packet_with_trace_hops(DEFAULT_MAX_TRACE_HOPS - 1);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        AcknowledgementStatus::success(ack_success_b64()).into()
+    );
+
+    let packet = recv_packet_with_trace_hops(DEFAULT_MAX_TRACE_HOPS);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        Acknowledgement::from(TokenTransferError::TraceHopsExceeded {
+            hops: DEFAULT_MAX_TRACE_HOPS + 1,
+            max: DEFAULT_MAX_TRACE_HOPS,
+        })
+    );
+}
+
+#[test]
+fn test_on_recv_packet_receive_enabled() {
+    let packet = recv_packet_with_trace_hops(0);
+
+    let mut ctx = DummyTransferModule::new().with_receive_enabled(true);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        AcknowledgementStatus::success(ack_success_b64()).into()
+    );
+
+    let mut ctx = DummyTransferModule::new().with_receive_enabled(false);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        Acknowledgement::from(TokenTransferError::ReceiveDisabled {
+            reason: "receiving is disabled".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_on_recv_packet_denied_denom() {
+    let packet = recv_packet_with_trace_hops(0);
+    let denom_on_b: PrefixedDenom = "transfer/channel-1/uatom".parse().expect("valid denom");
+
+    // Only receiving the denom is checked on receive.
+    let mut ctx =
+        DummyTransferModule::new().with_denied_denom(denom_on_b.clone(), TransferFlow::Send);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        AcknowledgementStatus::success(ack_success_b64()).into()
+    );
+
+    let mut ctx =
+        DummyTransferModule::new().with_denied_denom(denom_on_b.clone(), TransferFlow::Receive);
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    assert_eq!(
+        ack,
+        Acknowledgement::from(TokenTransferError::DenomNotAllowed {
+            denom: denom_on_b,
+            flow: TransferFlow::Receive,
+        })
+    );
+}
+
+#[test]
+fn test_send_transfer_send_enabled() {
+    let ctx = MockContext::default();
+
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        packet_data: PacketData {
+            token: "1000uatom".parse().expect("valid prefixed coin"),
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: "".into(),
+        },
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    // An enabled module goes on with the transfer, which fails only because
+    // no channel was opened.
+    let token_ctx = DummyTransferModule::new().with_send_enabled(true);
+    let res = send_transfer_validate(&ctx.ibc_store, &token_ctx, msg.clone());
+    assert!(
+        matches!(res, Err(TokenTransferError::ContextError(_))),
+        "{res:?}"
+    );
+
+    let token_ctx = DummyTransferModule::new().with_send_enabled(false);
+    let res = send_transfer_validate(&ctx.ibc_store, &token_ctx, msg);
+    assert!(
+        matches!(res, Err(TokenTransferError::SendDisabled { .. })),
+        "{res:?}"
+    );
+}
+
+/// A refund that cannot be fully applied fails without mutating any balance.
+#[test]
+fn test_on_timeout_packet_refund_inconsistent() {
+    let packet_data = PacketData {
+        token: "1000uatom".parse().expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&packet_data).expect("infallible"),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let denom = packet_data.token.denom.clone();
+    let escrow_key = (PortId::transfer(), ChannelId::new(0), denom.clone());
+
+    let mut ctx = DummyTransferModule::new();
+    ctx.escrows.insert(escrow_key.clone(), Amount::from(999));
+
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &packet, &dummy_account_id());
+
+    match &res {
+        Err(TokenTransferError::RefundInconsistent(e)) => assert!(
+            matches!(
+                e.as_ref(),
+                TokenTransferError::InsufficientEscrow { requested, available, .. }
+                    if *requested == Amount::from(1000) && *available == Amount::from(999)
+            ),
+            "{e:?}"
+        ),
+        _ => panic!("expected an inconsistent refund error, got {res:?}"),
+    }
+    ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        &denom,
+        Amount::from(999),
+    );
+
+    ctx.escrows.insert(escrow_key, Amount::from(1000));
+
+    let (_, res) = on_timeout_packet_execute(&mut ctx, &packet, &dummy_account_id());
+
+    assert!(res.is_ok(), "{res:?}");
+    ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        &denom,
+        Amount::from(0),
+    );
+}
+
+#[test]
+fn test_recv_packet_unescrow_exceeds_escrow() {
+    let packet_data = PacketData {
+        token: "1000transfer/channel-0/uatom"
+            .parse()
+            .expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&packet_data).expect("infallible"),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+
+    let mut ctx = DummyTransferModule::new();
+    ctx.escrows.insert(
+        (PortId::transfer(), ChannelId::new(1), denom.clone()),
+        Amount::from(500),
+    );
+
+    let res = process_recv_packet_execute(&mut ctx, &packet, packet_data);
+
+    match res {
+        Err((
+            _,
+            TokenTransferError::InsufficientEscrow {
+                denom,
+                requested,
+                available,
+            },
+        )) => {
+            assert_eq!(denom, "uatom".parse().expect("valid denom"));
+            assert_eq!(requested, Amount::from(1000));
+            assert_eq!(available, Amount::from(500));
+        }
+        _ => panic!("expected an insufficient escrow error, got {res:?}"),
+    }
+    ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        &denom,
+        Amount::from(500),
+    );
+}
+
+#[test]
+fn test_on_timeout_packet_emits_refund_event() {
+    let ctx = send_transfer_context();
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let packet_data = PacketData {
+        token: "1000uatom".parse().expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+
+    // the packet timed out on the counterparty, as of the client's latest
+    // consensus state
+    let timeout_timestamp = ctx.latest_timestamp().nanoseconds() - 1;
+    let mut msg =
+        MsgTimeout::try_from(dummy_raw_msg_timeout(5, 10, timeout_timestamp)).expect("no error");
+    msg.packet.data = serde_json::to_vec(&packet_data).expect("infallible");
+
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+    let mut ctx = ctx.with_packet_commitment(
+        msg.packet.port_id_on_a.clone(),
+        msg.packet.chan_id_on_a.clone(),
+        msg.packet.seq_on_a,
+        packet_commitment,
+    );
+
+    let packet_sequence = msg.packet.seq_on_a.to_string();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "{res:?}");
+
+    let ibc_events = ctx.ibc_store.events.lock();
+    let event = ibc_events
+        .iter()
+        .find_map(|event| match event {
+            IbcEvent::Module(event) if event.kind == "timeout" => Some(event),
+            _ => None,
+        })
+        .expect("timeout event is emitted");
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    };
+
+    assert_eq!(
+        attribute("refund_receiver"),
+        Some(dummy_account_id().as_ref())
+    );
+    assert_eq!(attribute("refund_denom"), Some("uatom"));
+    assert_eq!(attribute("refund_amount"), Some("1000"));
+    assert_eq!(attribute("packet_sequence"), Some(packet_sequence.as_str()));
+}
+
+/// Returns a context with an open transfer channel `channel-0`, from which
+/// tokens can be sent.
+fn send_transfer_context() -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION.to_string()),
+    )
+    .expect("no error");
+
+    MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).expect("no error")),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+}
+
+/// Transfers draining the channel's token bucket are rejected until it is
+/// refilled.
+#[test]
+fn test_send_transfer_rate_limit() {
+    let mut ctx = send_transfer_context();
+
+    let mut token_ctx = DummyTransferModule::new().with_send_rate_limit(Amount::from(1500));
+
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: PacketData {
+            token: "1000uatom".parse().expect("valid prefixed coin"),
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: "".into(),
+        },
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg.clone());
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        token_ctx.send_bucket(&PortId::transfer(), &ChannelId::zero()),
+        Some(Amount::from(500))
+    );
+
+    let res = send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg.clone());
+    assert!(
+        matches!(
+            res,
+            Err(TokenTransferError::SendRateLimitExceeded { ref port_id, ref channel_id })
+                if port_id == &PortId::transfer() && channel_id == &ChannelId::zero()
+        ),
+        "{res:?}"
+    );
+    assert_eq!(
+        token_ctx.send_bucket(&PortId::transfer(), &ChannelId::zero()),
+        Some(Amount::from(500))
+    );
+
+    token_ctx.refill_send_buckets();
+
+    let res = send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg);
+    assert!(res.is_ok(), "{res:?}");
+}
+
+#[test]
+fn test_send_transfer_memo_too_long() {
+    let ctx = send_transfer_context();
+
+    let token_ctx = DummyTransferModule::new();
+
+    let msg_with_memo = |memo_len: usize| MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: PacketData {
+            token: "1000uatom".parse().expect("valid prefixed coin"),
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: "m".repeat(memo_len).into(),
+        },
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_transfer_validate(
+        &ctx.ibc_store,
+        &token_ctx,
+        msg_with_memo(DEFAULT_MAX_MEMO_LENGTH),
+    );
+    assert!(res.is_ok(), "{res:?}");
+
+    let res = send_transfer_validate(
+        &ctx.ibc_store,
+        &token_ctx,
+        msg_with_memo(DEFAULT_MAX_MEMO_LENGTH + 1),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(TokenTransferError::MemoTooLong { len, max })
+                if len == DEFAULT_MAX_MEMO_LENGTH + 1 && max == DEFAULT_MAX_MEMO_LENGTH
+        ),
+        "{res:?}"
+    );
+}
+
+#[test]
+fn test_send_transfer_denied_denom() {
+    let ctx = send_transfer_context();
+
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: PacketData {
+            token: "1000uatom".parse().expect("valid prefixed coin"),
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: "".into(),
+        },
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    // Only sending the denom is checked on send.
+    let token_ctx =
+        DummyTransferModule::new().with_denied_denom(denom.clone(), TransferFlow::Receive);
+    let res = send_transfer_validate(&ctx.ibc_store, &token_ctx, msg.clone());
+    assert!(res.is_ok(), "{res:?}");
+
+    let token_ctx = DummyTransferModule::new().with_denied_denom(denom.clone(), TransferFlow::Send);
+    let res = send_transfer_validate(&ctx.ibc_store, &token_ctx, msg);
+    assert!(
+        matches!(
+            res,
+            Err(TokenTransferError::DenomNotAllowed { denom: ref denied, flow: TransferFlow::Send })
+                if denied == &denom
+        ),
+        "{res:?}"
+    );
+}
+
+/// Sending tokens escrows them on the channel, and refunding the timed out
+/// packet releases them.
+#[test]
+fn test_send_transfer_escrow_refunded_on_timeout() {
+    let mut ctx = send_transfer_context();
+
+    let mut token_ctx = DummyTransferModule::new();
+
+    let packet_data = PacketData {
+        token: "1000uatom".parse().expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+    let denom = packet_data.token.denom.clone();
+
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: packet_data.clone(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    token_ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom,
+        Amount::from(0),
+    );
+
+    let res = send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg.clone());
+    assert!(res.is_ok(), "{res:?}");
+
+    token_ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom,
+        Amount::from(1000),
+    );
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: msg.port_id_on_a,
+        chan_id_on_a: msg.chan_id_on_a,
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&packet_data).expect("infallible"),
+        timeout_height_on_b: msg.timeout_height_on_b,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+    };
+
+    let (_, res) = on_timeout_packet_execute(&mut token_ctx, &packet, &dummy_account_id());
+    assert!(res.is_ok(), "{res:?}");
+
+    token_ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom,
+        Amount::from(0),
+    );
+}
+
+/// Sends tokens over `channel-0` and processes the given acknowledgement of
+/// the resulting packet, returning the transfer module and the sent denom.
+fn send_transfer_and_acknowledge(
+    acknowledgement: Acknowledgement,
+) -> (DummyTransferModule, PrefixedDenom) {
+    let mut ctx = send_transfer_context();
+
+    let mut token_ctx = DummyTransferModule::new();
+
+    let packet_data = PacketData {
+        token: "1000uatom".parse().expect("valid prefixed coin"),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+    let denom = packet_data.token.denom.clone();
+
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: packet_data.clone(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).expect("no error")),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg.clone());
+    assert!(res.is_ok(), "{res:?}");
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: msg.port_id_on_a,
+        chan_id_on_a: msg.chan_id_on_a,
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&packet_data).expect("infallible"),
+        timeout_height_on_b: msg.timeout_height_on_b,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+    };
+
+    let (_, res) = on_acknowledgement_packet_execute(
+        &mut token_ctx,
+        &packet,
+        &acknowledgement,
+        &dummy_account_id(),
+    );
+    assert!(res.is_ok(), "{res:?}");
+
+    (token_ctx, denom)
+}
+
+/// A successful acknowledgement leaves the sent tokens in escrow.
+#[test]
+fn test_on_acknowledgement_packet_success_keeps_escrow() {
+    let (token_ctx, denom) =
+        send_transfer_and_acknowledge(AcknowledgementStatus::success(ack_success_b64()).into());
+
+    token_ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom,
+        Amount::from(1000),
+    );
+}
+
+/// An error acknowledgement refunds the sent tokens out of escrow.
+#[test]
+fn test_on_acknowledgement_packet_error_refunds_escrow() {
+    let (token_ctx, denom) = send_transfer_and_acknowledge(Acknowledgement::from(
+        TokenTransferError::PacketDataDeserialization,
+    ));
+
+    token_ctx.assert_escrow(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom,
+        Amount::from(0),
+    );
+}
@@ -10,6 +10,7 @@ use ibc::core::client::types::Height;
 use ibc::core::commitment_types::error::CommitmentError;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath};
@@ -57,6 +58,17 @@ fn test_create_client_ok() {
 
     assert!(res.is_ok(), "execution happy path");
 
+    let ibc_events = ctx.events.lock();
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let IbcEvent::CreateClient(create_client_event) = &ibc_events[1] else {
+        panic!("expected a create client event");
+    };
+    assert_eq!(create_client_event.client_id(), &client_id);
+
     let expected_client_state =
         ClientStateRef::<DefaultIbcStore>::try_from(msg.client_state).unwrap();
     assert_eq!(expected_client_state.client_type(), client_type);
@@ -212,3 +224,44 @@ fn test_tm_create_client_proof_verification_ok() {
         ClientError::Ics23Verification(CommitmentError::VerificationFailure)
     ));
 }
+
+#[test]
+fn test_create_client_dedup_reuses_existing_id() {
+    let mut ctx = DefaultIbcStore::default();
+    ctx.dedup_create_client = true;
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    for _ in 0..2 {
+        let res = validate(&ctx, &router, msg_envelope.clone());
+        assert!(res.is_ok(), "validation happy path");
+
+        let res = execute(&mut ctx, &mut router, msg_envelope.clone());
+        assert!(res.is_ok(), "execution happy path");
+
+        let Some(IbcEvent::CreateClient(event)) = ctx.events.lock().last().cloned() else {
+            panic!("expected a create client event");
+        };
+        assert_eq!(event.client_id(), &client_id);
+    }
+
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+
+    // Without de-duplication, a new identifier is allocated.
+    ctx.dedup_create_client = false;
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path");
+
+    assert_eq!(ctx.client_counter().unwrap(), 2);
+}
@@ -5,14 +5,16 @@ use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::handler::recover_client;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgRecoverClient};
 use ibc::core::client::types::{Height, Status as ClientStatus};
-use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::msgs::{MsgEnvelope, MsgKind};
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::Signer;
+use ibc::core::router::types::error::RouterError;
 use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::core::context::TestContextConfig;
-use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::fixtures::core::signer::{dummy_account_id, dummy_bech32_account};
 use ibc_testkit::hosts::{TestBlock, TestHost};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
@@ -287,3 +289,57 @@ fn test_recover_client_with_matching_heights() {
     recover_client::validate(ctx.ibc_store(), msg)
         .expect_err("expected client recovery validation to fail");
 }
+
+#[rstest]
+fn test_recover_client_with_unauthorized_signer() {
+    let subject_trusting_period = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS);
+    let substitute_trusting_period = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS) * 10;
+    let subject_height = Height::new(0, 42).unwrap();
+    let substitute_height = Height::new(0, 43).unwrap();
+
+    let Fixture {
+        mut ctx,
+        subject_client_id,
+        substitute_client_id,
+        signer,
+    } = setup_client_recovery_fixture(
+        subject_trusting_period,
+        subject_height,
+        substitute_trusting_period,
+        substitute_height,
+    );
+
+    let authority: Signer = dummy_bech32_account().into();
+
+    // Only the governance authority may recover clients.
+    ctx.ibc_store_mut()
+        .authorized_signers
+        .insert(MsgKind::RecoverClient, authority.clone());
+
+    let msg = MsgRecoverClient {
+        subject_client_id: subject_client_id.clone(),
+        substitute_client_id: substitute_client_id.clone(),
+        signer,
+    };
+
+    let res = recover_client::validate(ctx.ibc_store(), msg);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::RouterError(
+                RouterError::UnauthorizedSigner { .. }
+            ))
+        ),
+        "expected client recovery validation to fail: {res:?}"
+    );
+
+    let msg = MsgRecoverClient {
+        subject_client_id,
+        substitute_client_id,
+        signer: authority,
+    };
+
+    recover_client::validate(ctx.ibc_store(), msg)
+        .expect("client recovery validation by the authority");
+}
@@ -6,25 +6,28 @@ use basecoin_store::context::ProvableStore;
 use ibc::clients::tendermint::client_state::ClientState;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
+    client_type as tm_client_type, AllowUpdate, ClientState as TmClientState, Header as TmHeader,
     Misbehaviour as TmMisbehaviour,
 };
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::context::ClientValidationContext;
-use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::context::{ClientValidationContext, ExtClientValidationContext};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient, MsgUpdateClientBatch};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
 use ibc::core::host::types::path::ClientConsensusStatePath;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::context::{MockContext, TendermintContext, TestContext};
+use ibc_testkit::fixtures::clients::assert_client_updated_to;
 use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
@@ -36,9 +39,10 @@ use ibc_testkit::relayer::error::RelayerError;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
     DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
@@ -118,6 +122,76 @@ fn test_update_client_ok(fixture: Fixture) {
     );
 }
 
+#[rstest]
+fn test_update_client_stores_consensus_state(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let height = Height::new(0, 46).unwrap();
+    let header = MockHeader::new(height).with_timestamp(Timestamp::now());
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path");
+
+    assert_client_updated_to(
+        &ctx.ibc_store,
+        &client_id,
+        height,
+        &MockConsensusState::new(header).into(),
+    );
+}
+
+/// Consensus state heights are listed in ascending order, even where the
+/// store orders their paths differently, e.g. `0-100` before `0-9`.
+#[rstest]
+fn test_consensus_state_heights_sorted() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let mut ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 9).unwrap()),
+    );
+    let mut router = MockRouter::new_with_transfer();
+
+    for revision_height in [10, 100] {
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(Height::new(0, revision_height).unwrap())
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        };
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+        let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+        assert!(res.is_ok(), "{res:?}");
+        let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    let heights = ExtClientValidationContext::consensus_state_heights(&ctx.ibc_store, &client_id)
+        .expect("no error");
+
+    assert_eq!(
+        heights,
+        [9, 10, 100]
+            .map(|revision_height| Height::new(0, revision_height).unwrap())
+            .to_vec()
+    );
+}
+
 #[rstest]
 // Tests successful submission of a header with a height below the latest
 // client's height and ensures that `ConsensusState` is stored at the correct
@@ -211,13 +285,15 @@ fn test_update_client_with_prev_header() {
 ///
 /// This test sets up a `TendermintContext` with host height 1 and a trusting
 /// period of 3 seconds. It then advances the state of the `TendermintContext`
-/// by 2 heights, and thus 6 seconds, due to the DEFAULT_BLOCK_TIME_SECS
-/// constant being set to 3 seconds. At this point, the chain is at height
-/// 3. Any consensus states associated with a block more than 3 seconds
-/// in the past should be expired and pruned from the IBC store. The test
-/// thus checks that the consensus state at height 1 is not contained in
-/// the store. It also checks that the consensus state at height 2 is
-/// contained in the store and has not expired.
+/// by 3 heights, and thus 9 seconds, due to the DEFAULT_BLOCK_TIME_SECS
+/// constant being set to 3 seconds, updating the client each time with a
+/// header trusting the previous one. At this point, the chain is at height
+/// 4. Any consensus states associated with a block more than 3 seconds
+/// in the past should be expired and pruned from the IBC store, except the
+/// one the last header was verified against. The test thus checks that the
+/// consensus state at height 1 is not contained in the store. It also checks
+/// that the consensus state at height 2, trusted by the last header, is
+/// still contained in the store although it has expired.
 #[rstest]
 fn test_consensus_state_pruning() {
     let chain_id = ChainId::new("mockgaiaA-1").unwrap();
@@ -252,8 +328,8 @@ fn test_consensus_state_pruning() {
 
     let start_host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
 
-    // Move the chain forward by 2 blocks to pass the trusting period.
-    for _ in 1..=2 {
+    // Move the chain forward by 3 blocks to pass the trusting period.
+    for _ in 1..=3 {
         let signer = dummy_account_id();
 
         let update_height = ctx.latest_height();
@@ -263,7 +339,12 @@ fn test_consensus_state_pruning() {
         let block = ctx.host_block(&update_height).unwrap().clone();
         let mut block = block.into_header();
 
-        block.set_trusted_height(client_height);
+        let trusted_height = ctx
+            .ibc_store
+            .client_state(&client_id)
+            .unwrap()
+            .latest_height();
+        block.set_trusted_height(trusted_height);
 
         let msg = MsgUpdateClient {
             client_id: client_id.clone(),
@@ -293,7 +374,8 @@ fn test_consensus_state_pruning() {
         .consensus_state(&client_cons_state_path)
         .is_err());
 
-    // Check that latest valid consensus state exists.
+    // Check that the expired consensus state trusted by the last header
+    // exists.
     let earliest_valid_height = Height::new(1, 2).unwrap();
     let client_cons_state_path = ClientConsensusStatePath::new(
         client_id.clone(),
@@ -314,39 +396,23 @@ fn test_consensus_state_pruning() {
 
     assert_eq!(
         end_host_timestamp,
-        (start_host_timestamp + Duration::from_secs(6)).unwrap()
+        (start_host_timestamp + Duration::from_secs(9)).unwrap()
     );
 }
 
+/// Tests that the oldest consensus states, along with their update metadata,
+/// are evicted once the number of consensus states stored for a client
+/// exceeds the host's `max_consensus_states` limit.
 #[rstest]
-fn test_update_nonexisting_client(fixture: Fixture) {
-    let Fixture { ctx, router } = fixture;
-
-    let signer = dummy_account_id();
-
-    let msg = MsgUpdateClient {
-        client_id: ClientId::from_str("nonexistingclient").unwrap(),
-        client_message: MockHeader::new(Height::new(0, 46).unwrap()).into(),
-        signer,
-    };
-
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
-
-    let res = validate(&ctx.ibc_store, &router, msg_envelope);
-
-    assert!(res.is_err());
-}
-
-#[rstest]
-fn test_update_synthetic_tendermint_client_adjacent_ok() {
+fn test_consensus_state_count_limit() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
-    let update_height = Height::new(1, 21).unwrap();
+    let latest_height = Height::new(1, 23).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
     let ctx_b = TestContextConfig::builder()
         .host(TendermintHost::builder().chain_id(chain_id_b).build())
-        .latest_height(update_height)
+        .latest_height(latest_height)
         .build::<TendermintContext>();
 
     let mut ctx = TestContextConfig::builder()
@@ -365,83 +431,86 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
                 .build(),
         );
 
+    ctx.ibc_store.max_consensus_states = Some(2);
+
     let mut router = MockRouter::new_with_transfer();
 
-    let signer = dummy_account_id();
+    let mut trusted_height = client_height;
 
-    let block = ctx_b.host_block(&update_height).unwrap();
-    let mut block = block.into_header();
-    block.set_trusted_height(client_height);
+    for _ in 0..3 {
+        let update_height = trusted_height.increment();
 
-    let latest_header_height = block.height();
-    let msg = MsgUpdateClient {
-        client_id,
-        client_message: block.into(),
-        signer,
-    };
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+        let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+        block.set_trusted_height(trusted_height);
 
-    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
-    assert!(res.is_ok());
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: block.into(),
+            signer: dummy_account_id(),
+        };
 
-    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
-    assert!(res.is_ok(), "result: {res:?}");
+        let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    let client_state = ctx.ibc_store.client_state(&msg.client_id).unwrap();
+        let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+        assert!(res.is_ok(), "result: {res:?}");
 
-    assert!(client_state
-        .status(&ctx.ibc_store, &msg.client_id)
-        .unwrap()
-        .is_active());
+        let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+        assert!(res.is_ok(), "result: {res:?}");
 
-    assert_eq!(client_state.latest_height(), latest_header_height);
+        trusted_height = update_height;
+    }
+
+    // Check that the oldest consensus states are evicted.
+    for evicted_height in [client_height, Height::new(1, 21).unwrap()] {
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            evicted_height.revision_number(),
+            evicted_height.revision_height(),
+        );
+        assert!(ctx
+            .ibc_store
+            .client_update_meta(&client_id, &evicted_height)
+            .is_err());
+        assert!(ctx
+            .ibc_store
+            .consensus_state(&client_cons_state_path)
+            .is_err());
+    }
+
+    // Check that the newest consensus states are retained.
+    for retained_height in [Height::new(1, 22).unwrap(), latest_height] {
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            retained_height.revision_number(),
+            retained_height.revision_height(),
+        );
+        assert!(ctx
+            .ibc_store
+            .client_update_meta(&client_id, &retained_height)
+            .is_ok());
+        assert!(ctx
+            .ibc_store
+            .consensus_state(&client_cons_state_path)
+            .is_ok());
+    }
 }
 
+/// Tests that a batch of headers is applied in order, each header trusting
+/// the previous one, and that a single `UpdateClient` event reports every
+/// applied height once.
 #[rstest]
-fn test_update_synthetic_tendermint_client_validator_change_ok() {
+fn test_update_client_batch_ok() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
+    let latest_height = Height::new(1, 23).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
-    let ctx_b_val_history = vec![
-        // validator set of height-20
-        vec![
-            TestgenValidator::new("1").voting_power(50),
-            TestgenValidator::new("2").voting_power(50),
-        ],
-        // next validator set of height-20
-        // validator set of height-21
-        vec![
-            TestgenValidator::new("1").voting_power(34),
-            TestgenValidator::new("2").voting_power(66),
-        ],
-        // next validator set of height-21
-        // validator set of height-22
-        // overlap maintains 1/3 power in older set
-        vec![
-            TestgenValidator::new("1").voting_power(1),
-            TestgenValidator::new("4").voting_power(99),
-        ],
-        // next validator set of height-22
-        vec![
-            TestgenValidator::new("1").voting_power(20),
-            TestgenValidator::new("2").voting_power(80),
-        ],
-    ];
-
-    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
-
-    let update_height = client_height.add(block_params.len() as u64 - 1);
-
-    assert_eq!(update_height.revision_height(), 22);
-
     let ctx_b = TestContextConfig::builder()
         .host(TendermintHost::builder().chain_id(chain_id_b).build())
-        .latest_height(update_height)
-        .block_params_history(block_params)
+        .latest_height(latest_height)
         .build::<TendermintContext>();
 
-    let mut ctx_a = TestContextConfig::builder()
+    let mut ctx = TestContextConfig::builder()
         .host(
             MockHost::builder()
                 .chain_id(ChainId::new("mockgaiaA-1").unwrap())
@@ -451,96 +520,80 @@ fn test_update_synthetic_tendermint_client_validator_change_ok() {
         .build::<MockContext>()
         .with_light_client(
             &client_id,
-            // remote light client initialized with client_height
             LightClientBuilder::init()
                 .context(&ctx_b)
                 .consensus_heights([client_height])
                 .build(),
         );
 
-    let mut router_a = MockRouter::new_with_transfer();
-
-    let signer = dummy_account_id();
+    let mut router = MockRouter::new_with_transfer();
 
-    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    let header_at = |update_height: Height, trusted_height: Height| -> Any {
+        let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+        block.set_trusted_height(trusted_height);
+        block.into()
+    };
 
-    let trusted_next_validator_set = ctx_b
-        .host_block(&client_height)
-        .expect("no error")
-        .next_validators
-        .clone();
+    let height_21 = Height::new(1, 21).unwrap();
+    let height_22 = Height::new(1, 22).unwrap();
 
-    block.set_trusted_height(client_height);
-    block.set_trusted_next_validators_set(trusted_next_validator_set);
+    let last_header = header_at(latest_height, height_22);
 
-    let latest_header_height = block.height();
-    let msg = MsgUpdateClient {
-        client_id,
-        client_message: block.into(),
-        signer,
+    // The header at height 21 is relayed twice, and applied once.
+    let msg = MsgUpdateClientBatch {
+        client_id: client_id.clone(),
+        client_messages: vec![
+            header_at(height_21, client_height),
+            header_at(height_22, height_21),
+            header_at(height_21, client_height),
+            last_header.clone(),
+        ],
+        signer: dummy_account_id(),
     };
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
 
-    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
-    assert!(res.is_ok());
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
     assert!(res.is_ok(), "result: {res:?}");
 
-    let client_state = ctx_a.ibc_store.client_state(&msg.client_id).unwrap();
-    assert!(client_state
-        .status(&ctx_a.ibc_store, &msg.client_id)
-        .unwrap()
-        .is_active());
-    assert_eq!(client_state.latest_height(), latest_header_height);
-}
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
 
-// TODO(rano): refactor the validator change tests to use a single test function
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), latest_height);
+
+    let ibc_events = ctx.ibc_store.events.lock();
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let IbcEvent::UpdateClient(update_client_event) = &ibc_events[1] else {
+        panic!("UpdateClient event is expected")
+    };
+    assert_eq!(update_client_event.consensus_height(), &height_21);
+    assert_eq!(
+        update_client_event.consensus_heights(),
+        &vec![height_21, height_22, latest_height]
+    );
+    assert_eq!(update_client_event.header(), &last_header.to_vec());
+}
 
+/// Tests that a header of a batch that does not advance the latest height is
+/// skipped: its consensus state is not stored, nor is its height reported.
 #[rstest]
-fn test_update_synthetic_tendermint_client_wrong_trusted_validator_change_fail() {
+fn test_update_client_batch_skips_stale_header() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
+    let latest_height = Height::new(1, 23).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
-    let ctx_b_val_history = vec![
-        // validator set of height-20
-        vec![
-            TestgenValidator::new("1").voting_power(50),
-            TestgenValidator::new("2").voting_power(50),
-        ],
-        // next validator set of height-20
-        // validator set of height-21
-        vec![
-            TestgenValidator::new("1").voting_power(45),
-            TestgenValidator::new("2").voting_power(55),
-        ],
-        // next validator set of height-21
-        // validator set of height-22
-        vec![
-            TestgenValidator::new("1").voting_power(30),
-            TestgenValidator::new("2").voting_power(70),
-        ],
-        // next validator set of height-22
-        vec![
-            TestgenValidator::new("1").voting_power(20),
-            TestgenValidator::new("2").voting_power(80),
-        ],
-    ];
-
-    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
-
-    let update_height = client_height.add(block_params.len() as u64 - 1);
-
-    assert_eq!(update_height.revision_height(), 22);
-
     let ctx_b = TestContextConfig::builder()
         .host(TendermintHost::builder().chain_id(chain_id_b).build())
-        .latest_height(update_height)
-        .block_params_history(block_params)
+        .latest_height(latest_height)
         .build::<TendermintContext>();
 
-    let ctx_a = TestContextConfig::builder()
+    let mut ctx = TestContextConfig::builder()
         .host(
             MockHost::builder()
                 .chain_id(ChainId::new("mockgaiaA-1").unwrap())
@@ -550,103 +603,172 @@ fn test_update_synthetic_tendermint_client_wrong_trusted_validator_change_fail()
         .build::<MockContext>()
         .with_light_client(
             &client_id,
-            // remote light client initialized with client_height
             LightClientBuilder::init()
                 .context(&ctx_b)
                 .consensus_heights([client_height])
                 .build(),
         );
 
-    let router = MockRouter::new_with_transfer();
+    let mut router = MockRouter::new_with_transfer();
 
-    let signer = dummy_account_id();
+    let header_at = |update_height: Height, trusted_height: Height| -> Any {
+        let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+        block.set_trusted_height(trusted_height);
+        block.into()
+    };
 
-    // next validator set from height-20
-    let trusted_next_validator_set = ctx_b
-        .host_block(&client_height)
-        .expect("no error")
-        .next_validators
-        .clone();
+    let height_21 = Height::new(1, 21).unwrap();
+    let height_22 = Height::new(1, 22).unwrap();
 
-    // next validator set from height-21
-    let mistrusted_next_validator_set = ctx_b
-        .host_block(&client_height.increment())
-        .expect("no error")
-        .next_validators
-        .clone();
+    // The header at height 21 comes after the one at height 22.
+    let msg = MsgUpdateClientBatch {
+        client_id: client_id.clone(),
+        client_messages: vec![
+            header_at(height_22, client_height),
+            header_at(height_21, client_height),
+            header_at(latest_height, height_22),
+        ],
+        signer: dummy_account_id(),
+    };
 
-    // ensure the next validator sets are different
-    assert_ne!(
-        mistrusted_next_validator_set.hash(),
-        trusted_next_validator_set.hash()
-    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
 
-    // set the trusted height to height-20
-    block.set_trusted_height(client_height);
-    // set the trusted next validator set from height-21, which is different than height-20
-    block.set_trusted_next_validators_set(mistrusted_next_validator_set);
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), latest_height);
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        height_21.revision_number(),
+        height_21.revision_height(),
+    );
+    assert!(ctx
+        .ibc_store
+        .consensus_state(&client_cons_state_path)
+        .is_err());
+
+    let ibc_events = ctx.ibc_store.events.lock();
+    assert_eq!(ibc_events.len(), 2);
+    let IbcEvent::UpdateClient(update_client_event) = &ibc_events[1] else {
+        panic!("UpdateClient event is expected")
+    };
+    assert_eq!(
+        update_client_event.consensus_heights(),
+        &vec![height_22, latest_height]
+    );
+}
+
+/// Tests that a batch of headers is rejected as a whole when any of its headers
+/// fails verification, so that none of them is applied.
+#[rstest]
+fn test_update_client_batch_verification_failure() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let latest_height = Height::new(1, 23).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(latest_height)
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let height_21 = Height::new(1, 21).unwrap();
+
+    let mut first_block = ctx_b.host_block(&height_21).unwrap().into_header();
+    first_block.set_trusted_height(client_height);
+
+    // No consensus state is stored at the trusted height of the second header.
+    let mut second_block = ctx_b.host_block(&latest_height).unwrap().into_header();
+    second_block.set_trusted_height(Height::new(1, 22).unwrap());
+
+    let msg = MsgUpdateClientBatch {
+        client_id: client_id.clone(),
+        client_messages: vec![first_block.into(), second_block.into()],
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::ConsensusStateNotFound { .. }
+            ))
+        ),
+        "result: {res:?}"
+    );
+
+    for height in [height_21, latest_height] {
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        assert!(ctx
+            .ibc_store
+            .consensus_state(&client_cons_state_path)
+            .is_err());
+    }
+    assert!(ctx.ibc_store.events.lock().is_empty());
+}
+
+#[rstest]
+fn test_update_nonexisting_client(fixture: Fixture) {
+    let Fixture { ctx, router } = fixture;
+
+    let signer = dummy_account_id();
 
     let msg = MsgUpdateClient {
-        client_id,
-        client_message: block.into(),
+        client_id: ClientId::from_str("nonexistingclient").unwrap(),
+        client_message: MockHeader::new(Height::new(0, 46).unwrap()).into(),
         signer,
     };
 
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    let res = validate(&ctx_a.ibc_store, &router, msg_envelope);
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
 
     assert!(res.is_err());
 }
 
 #[rstest]
-fn test_update_synthetic_tendermint_client_validator_change_fail() {
+fn test_update_synthetic_tendermint_client_adjacent_ok() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
-    let ctx_b_val_history = vec![
-        // validator set of height-20
-        vec![
-            TestgenValidator::new("1").voting_power(50),
-            TestgenValidator::new("2").voting_power(50),
-        ],
-        // next validator set of height-20
-        // validator set of height-21
-        vec![
-            TestgenValidator::new("1").voting_power(90),
-            TestgenValidator::new("2").voting_power(10),
-        ],
-        // next validator set of height-21
-        // validator set of height-22
-        // overlap doesn't maintain 1/3 power in older set
-        vec![
-            // TestgenValidator::new("1").voting_power(0),
-            TestgenValidator::new("4").voting_power(90),
-            TestgenValidator::new("2").voting_power(10),
-        ],
-        // validator set of height-23
-        vec![
-            TestgenValidator::new("1").voting_power(20),
-            TestgenValidator::new("2").voting_power(80),
-        ],
-    ];
-
-    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
-
-    let update_height = client_height.add(block_params.len() as u64 - 1);
-
-    assert_eq!(update_height.revision_height(), 22);
-
     let ctx_b = TestContextConfig::builder()
         .host(TendermintHost::builder().chain_id(chain_id_b).build())
         .latest_height(update_height)
-        .block_params_history(block_params)
         .build::<TendermintContext>();
 
-    let ctx_a = TestContextConfig::builder()
+    let mut ctx = TestContextConfig::builder()
         .host(
             MockHost::builder()
                 .chain_id(ChainId::new("mockgaiaA-1").unwrap())
@@ -656,65 +778,68 @@ fn test_update_synthetic_tendermint_client_validator_change_fail() {
         .build::<MockContext>()
         .with_light_client(
             &client_id,
-            // remote light client initialized with client_height
             LightClientBuilder::init()
                 .context(&ctx_b)
                 .consensus_heights([client_height])
                 .build(),
         );
 
-    let router_a = MockRouter::new_with_transfer();
+    let mut router = MockRouter::new_with_transfer();
 
     let signer = dummy_account_id();
 
-    let trusted_next_validator_set = ctx_b
-        .host_block(&client_height)
-        .expect("no error")
-        .next_validators
-        .clone();
-
-    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
-
+    let block = ctx_b.host_block(&update_height).unwrap();
+    let mut block = block.into_header();
     block.set_trusted_height(client_height);
-    block.set_trusted_next_validators_set(trusted_next_validator_set);
 
+    let latest_header_height = block.height();
     let msg = MsgUpdateClient {
         client_id,
         client_message: block.into(),
         signer,
     };
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
 
-    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok());
 
-    assert!(res.is_err());
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&msg.client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &msg.client_id)
+        .unwrap()
+        .is_active());
+
+    assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
 #[rstest]
-fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
+fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
     let ctx_b_val_history = vec![
-        // First two validator sets are default at client creation
-        //
         // validator set of height-20
         vec![
             TestgenValidator::new("1").voting_power(50),
             TestgenValidator::new("2").voting_power(50),
         ],
-        // validator set of height-21
         // next validator set of height-20
+        // validator set of height-21
         vec![
             TestgenValidator::new("1").voting_power(34),
             TestgenValidator::new("2").voting_power(66),
         ],
-        // validator set of height-22
         // next validator set of height-21
+        // validator set of height-22
+        // overlap maintains 1/3 power in older set
         vec![
-            TestgenValidator::new("4").voting_power(90),
-            TestgenValidator::new("2").voting_power(10),
+            TestgenValidator::new("1").voting_power(1),
+            TestgenValidator::new("4").voting_power(99),
         ],
         // next validator set of height-22
         vec![
@@ -723,12 +848,7 @@ fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
         ],
     ];
 
-    let mut block_params = BlockParams::from_validator_history(ctx_b_val_history);
-
-    if let Some(block_param) = block_params.last_mut() {
-        // forged validator set of height-22
-        block_param.validators = vec![TestgenValidator::new("1").voting_power(100)];
-    }
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
 
     let update_height = client_height.add(block_params.len() as u64 - 1);
 
@@ -794,23 +914,31 @@ fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+// TODO(rano): refactor the validator change tests to use a single test function
+
 #[rstest]
-fn test_update_synthetic_tendermint_client_adjacent_malicious_validator_change_fail() {
+fn test_update_synthetic_tendermint_client_wrong_trusted_validator_change_fail() {
     let client_id = tm_client_type().build_client_id(0);
-    let client_height = Height::new(1, 21).unwrap();
+    let client_height = Height::new(1, 20).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
     let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
         // validator set of height-21
         vec![
-            TestgenValidator::new("1").voting_power(34),
-            TestgenValidator::new("2").voting_power(66),
+            TestgenValidator::new("1").voting_power(45),
+            TestgenValidator::new("2").voting_power(55),
         ],
         // next validator set of height-21
         // validator set of height-22
         vec![
-            TestgenValidator::new("4").voting_power(90),
-            TestgenValidator::new("2").voting_power(10),
+            TestgenValidator::new("1").voting_power(30),
+            TestgenValidator::new("2").voting_power(70),
         ],
         // next validator set of height-22
         vec![
@@ -819,12 +947,7 @@ fn test_update_synthetic_tendermint_client_adjacent_malicious_validator_change_f
         ],
     ];
 
-    let mut block_params = BlockParams::from_validator_history(ctx_b_val_history);
-
-    if let Some(block_param) = block_params.last_mut() {
-        // forged validator set of height-22
-        block_param.validators = vec![TestgenValidator::new("1").voting_power(100)];
-    }
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
 
     let update_height = client_height.add(block_params.len() as u64 - 1);
 
@@ -853,64 +976,482 @@ fn test_update_synthetic_tendermint_client_adjacent_malicious_validator_change_f
                 .build(),
         );
 
-    let router_a = MockRouter::new_with_transfer();
+    let router = MockRouter::new_with_transfer();
 
     let signer = dummy_account_id();
 
-    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
-
+    // next validator set from height-20
     let trusted_next_validator_set = ctx_b
         .host_block(&client_height)
         .expect("no error")
         .next_validators
         .clone();
 
+    // next validator set from height-21
+    let mistrusted_next_validator_set = ctx_b
+        .host_block(&client_height.increment())
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    // ensure the next validator sets are different
+    assert_ne!(
+        mistrusted_next_validator_set.hash(),
+        trusted_next_validator_set.hash()
+    );
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+    // set the trusted height to height-20
     block.set_trusted_height(client_height);
-    block.set_trusted_next_validators_set(trusted_next_validator_set);
+    // set the trusted next validator set from height-21, which is different than height-20
+    block.set_trusted_next_validators_set(mistrusted_next_validator_set);
 
     let msg = MsgUpdateClient {
         client_id,
         client_message: block.into(),
         signer,
     };
+
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
-    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+    let res = validate(&ctx_a.ibc_store, &router, msg_envelope);
 
     assert!(res.is_err());
 }
 
+/// A non-adjacent update must check the header's trusted validators against
+/// the consensus state stored at `header.trusted_height`, not against the
+/// latest consensus state of the client.
 #[rstest]
-fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
+fn test_update_synthetic_tendermint_client_non_adjacent_misaligned_trusted_validators_fail() {
     let client_id = tm_client_type().build_client_id(0);
-    let client_height = Height::new(1, 20).unwrap();
-    let update_height = Height::new(1, 21).unwrap();
+    let trusted_height = Height::new(1, 20).unwrap();
+    let client_height = trusted_height.increment();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
-    let ctx_b = TestContextConfig::builder()
-        .host(TendermintHost::builder().chain_id(chain_id_b).build())
-        .latest_height(update_height)
-        .build::<TendermintContext>();
-
-    let mut ctx = TestContextConfig::builder()
-        .host(
-            MockHost::builder()
-                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
-                .build(),
-        )
-        .latest_height(Height::new(1, 1).unwrap())
-        .build::<MockContext>()
-        .with_light_client(
-            &client_id,
-            LightClientBuilder::init()
-                .context(&ctx_b)
-                .consensus_heights([client_height.sub(1).expect("no error"), client_height])
-                .build(),
-        );
-
-    let mut router = MockRouter::new_with_transfer();
-
-    let signer = dummy_account_id();
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(45),
+            TestgenValidator::new("2").voting_power(55),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        vec![
+            TestgenValidator::new("1").voting_power(30),
+            TestgenValidator::new("2").voting_power(70),
+        ],
+        // next validator set of height-22
+        vec![
+            TestgenValidator::new("1").voting_power(20),
+            TestgenValidator::new("2").voting_power(80),
+        ],
+    ];
+
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    let update_height = trusted_height.add(block_params.len() as u64 - 1);
+
+    assert_eq!(update_height.revision_height(), 22);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            // remote light client with height-21 as its latest height
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([trusted_height, client_height])
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let build_msg = |trusted_next_validator_set| {
+        let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+        // trust the historical height-20 rather than the latest height-21
+        block.set_trusted_height(trusted_height);
+        block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+        MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: block.into(),
+            signer: dummy_account_id(),
+        }))
+    };
+
+    // next validator set from height-20
+    let trusted_next_validator_set = ctx_b
+        .host_block(&trusted_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    // next validator set from height-21, the latest consensus state
+    let latest_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    assert_ne!(
+        trusted_next_validator_set.hash(),
+        latest_next_validator_set.hash()
+    );
+
+    let res = validate(
+        &ctx_a.ibc_store,
+        &router,
+        build_msg(trusted_next_validator_set),
+    );
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = validate(
+        &ctx_a.ibc_store,
+        &router,
+        build_msg(latest_next_validator_set),
+    );
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::MisalignedTrustedValidators { trusted_height: height, .. }
+            )) if height == trusted_height
+        ),
+        "result: {res:?}"
+    );
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_validator_change_fail() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        // overlap doesn't maintain 1/3 power in older set
+        vec![
+            // TestgenValidator::new("1").voting_power(0),
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("1").voting_power(20),
+            TestgenValidator::new("2").voting_power(80),
+        ],
+    ];
+
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    let update_height = client_height.add(block_params.len() as u64 - 1);
+
+    assert_eq!(update_height.revision_height(), 22);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            // remote light client initialized with client_height
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let signer = dummy_account_id();
+
+    let trusted_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+    block.set_trusted_height(client_height);
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer,
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b_val_history = vec![
+        // First two validator sets are default at client creation
+        //
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // validator set of height-21
+        // next validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(34),
+            TestgenValidator::new("2").voting_power(66),
+        ],
+        // validator set of height-22
+        // next validator set of height-21
+        vec![
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // next validator set of height-22
+        vec![
+            TestgenValidator::new("1").voting_power(20),
+            TestgenValidator::new("2").voting_power(80),
+        ],
+    ];
+
+    let mut block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    if let Some(block_param) = block_params.last_mut() {
+        // forged validator set of height-22
+        block_param.validators = vec![TestgenValidator::new("1").voting_power(100)];
+    }
+
+    let update_height = client_height.add(block_params.len() as u64 - 1);
+
+    assert_eq!(update_height.revision_height(), 22);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            // remote light client initialized with client_height
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let signer = dummy_account_id();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+    let trusted_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    block.set_trusted_height(client_height);
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    let latest_header_height = block.height();
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer,
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok());
+
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx_a.ibc_store.client_state(&msg.client_id).unwrap();
+    assert!(client_state
+        .status(&ctx_a.ibc_store, &msg.client_id)
+        .unwrap()
+        .is_active());
+    assert_eq!(client_state.latest_height(), latest_header_height);
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_adjacent_malicious_validator_change_fail() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b_val_history = vec![
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(34),
+            TestgenValidator::new("2").voting_power(66),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        vec![
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // next validator set of height-22
+        vec![
+            TestgenValidator::new("1").voting_power(20),
+            TestgenValidator::new("2").voting_power(80),
+        ],
+    ];
+
+    let mut block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    if let Some(block_param) = block_params.last_mut() {
+        // forged validator set of height-22
+        block_param.validators = vec![TestgenValidator::new("1").voting_power(100)];
+    }
+
+    let update_height = client_height.add(block_params.len() as u64 - 1);
+
+    assert_eq!(update_height.revision_height(), 22);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            // remote light client initialized with client_height
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let signer = dummy_account_id();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+    let trusted_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    block.set_trusted_height(client_height);
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer,
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height.sub(1).expect("no error"), client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let signer = dummy_account_id();
 
     let block = ctx_b.host_block(&update_height).unwrap();
     let mut block = block.into_header();
@@ -994,7 +1535,7 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
     {
         // FIXME: idea: we need to update the light client with the latest block from
         // chain B
-        let consensus_state: AnyConsensusState = block.clone().into_consensus_state().into();
+        let consensus_state: AnyConsensusState = ctx_b.host.latest_consensus_state().into();
 
         let tm_block = &block;
 
@@ -1029,37 +1570,123 @@ fn test_update_synthetic_tendermint_client_duplicate_ok() {
                 allow_update_after_misbehaviour: false,
             };
 
-            let client_state = TmClientState::try_from(raw_client_state).unwrap();
+            let client_state = TmClientState::try_from(raw_client_state).unwrap();
+
+            ClientState::from(client_state).into()
+        };
+
+        ctx_a = ctx_a.with_client_state(&client_id, client_state);
+
+        ctx_a = ctx_a.with_consensus_state(&client_id, client_height, consensus_state);
+    }
+
+    let latest_header_height = block.height();
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer,
+    };
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx_a.ibc_store.client_state(&msg.client_id).unwrap();
+    assert!(client_state
+        .status(&ctx_a.ibc_store, &msg.client_id)
+        .unwrap()
+        .is_active());
+    assert_eq!(client_state.latest_height(), latest_header_height);
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_already_processed_header() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
 
-            ClientState::from(client_state).into()
-        };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.clone().into(),
+        signer: dummy_account_id(),
+    }));
 
-        ctx_a = ctx_a.with_client_state(&client_id, client_state);
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
 
-        ctx_a = ctx_a.with_consensus_state(&client_id, client_height, consensus_state);
-    }
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
 
-    let latest_header_height = block.height();
-    let msg = MsgUpdateClient {
-        client_id,
-        client_message: block.into(),
-        signer,
-    };
+    let consensus_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        update_height.revision_number(),
+        update_height.revision_height(),
+    );
+    let stored_consensus_state = ctx
+        .ibc_store
+        .consensus_state(&consensus_state_path)
+        .unwrap();
 
-    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+    // Point the re-submitted header to a height without any consensus state,
+    // which would fail a full verification.
+    block.set_trusted_height(Height::new(1, 5).unwrap());
 
-    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
     assert!(res.is_ok(), "result: {res:?}");
 
-    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    let event_count = ctx.ibc_store.events.lock().len();
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
     assert!(res.is_ok(), "result: {res:?}");
 
-    let client_state = ctx_a.ibc_store.client_state(&msg.client_id).unwrap();
-    assert!(client_state
-        .status(&ctx_a.ibc_store, &msg.client_id)
-        .unwrap()
-        .is_active());
-    assert_eq!(client_state.latest_height(), latest_header_height);
+    // No redundant `UpdateClient` event is emitted.
+    assert_eq!(ctx.ibc_store.events.lock().len(), event_count);
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+    assert_eq!(
+        ctx.ibc_store
+            .consensus_state(&consensus_state_path)
+            .unwrap(),
+        stored_consensus_state
+    );
 }
 
 #[rstest]
@@ -1111,6 +1738,141 @@ fn test_update_synthetic_tendermint_client_lower_height() {
     assert!(res.is_err());
 }
 
+#[rstest]
+fn test_would_advance_synthetic_tendermint_client() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(client_height.increment())
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let AnyClientState::Tendermint(client_state) = ctx.ibc_store.client_state(&client_id).unwrap()
+    else {
+        panic!("client state is a Tendermint client state")
+    };
+
+    let would_advance = |height: Height| {
+        client_state
+            .would_advance_client(
+                &ctx.ibc_store,
+                &client_id,
+                ctx_b.host_block(&height).unwrap().into_header().into(),
+            )
+            .unwrap()
+    };
+
+    assert!(would_advance(client_height.increment()));
+    assert!(!would_advance(client_height));
+    assert!(!would_advance(client_height.sub(1).unwrap()));
+}
+
+/// Builds a Tendermint light client with a consensus state at height 20 on
+/// a mock host, and returns the result of validating an update with the
+/// header at `update_height`, trusting `trusted_height`.
+fn validate_synthetic_tendermint_update(
+    update_height: Height,
+    trusted_height: Height,
+) -> Result<(), ContextError> {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(trusted_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_trusted_height_not_below_header_fail() {
+    let update_height = Height::new(1, 21).unwrap();
+
+    let res = validate_synthetic_tendermint_update(update_height, update_height);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::InvalidTrustedHeight {
+                trusted_height,
+                height,
+            })) if trusted_height == update_height && height == update_height
+        ),
+        "result: {res:?}"
+    );
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_unknown_trusted_height_fail() {
+    let update_height = Height::new(1, 22).unwrap();
+    // no consensus state is stored at height-21
+    let trusted_height = Height::new(1, 21).unwrap();
+
+    let res = validate_synthetic_tendermint_update(update_height, trusted_height);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ConsensusStateNotFound {
+                height,
+                ..
+            })) if height == trusted_height
+        ),
+        "result: {res:?}"
+    );
+}
+
 #[rstest]
 fn test_update_client_events(fixture: Fixture) {
     let Fixture {
@@ -1156,12 +1918,21 @@ fn ensure_misbehaviour<S: ProvableStore + Debug>(
     ctx: &MockIbcStore<S>,
     client_id: &ClientId,
     client_type: &ClientType,
+    frozen_height: Option<Height>,
 ) {
     let client_state = ctx.client_state(client_id).unwrap();
 
     let status = client_state.status(ctx, client_id).unwrap();
     assert!(status.is_frozen(), "client_state status: {status}");
 
+    // The mock client only records that it is frozen, not at which height.
+    if let Some(frozen_height) = frozen_height {
+        let AnyClientState::Tendermint(tm_client_state) = &client_state else {
+            panic!("expected a Tendermint client state")
+        };
+        assert_eq!(tm_client_state.inner().frozen_height, Some(frozen_height));
+    }
+
     // check events
     let ibc_events = ctx.events.lock();
     assert_eq!(ibc_events.len(), 2);
@@ -1176,6 +1947,20 @@ fn ensure_misbehaviour<S: ProvableStore + Debug>(
     assert_eq!(misbehaviour_client_event.client_type(), client_type);
 }
 
+fn ensure_misbehaviour_heights<S: ProvableStore + Debug>(
+    ctx: &MockIbcStore<S>,
+    expected_heights: &[Height],
+) {
+    let ibc_events = ctx.events.lock();
+    let Some(IbcEvent::ClientMisbehaviour(misbehaviour_client_event)) = ibc_events.last() else {
+        panic!("ClientMisbehaviour event is expected")
+    };
+    assert_eq!(
+        misbehaviour_client_event.misbehaviour_heights(),
+        expected_heights
+    );
+}
+
 /// Tests misbehaviour handling for the mock client.
 ///
 /// Misbehaviour evidence consists of identical headers - mock misbehaviour handler
@@ -1196,7 +1981,7 @@ fn test_misbehaviour_client_ok(fixture: Fixture) {
     let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
     assert!(res.is_ok());
 
-    ensure_misbehaviour(&ctx.ibc_store, &client_id, &mock_client_type());
+    ensure_misbehaviour(&ctx.ibc_store, &client_id, &mock_client_type(), None);
 }
 
 #[rstest]
@@ -1223,31 +2008,196 @@ fn test_client_update_misbehaviour_nonexisting_client(fixture: Fixture) {
 
     let msg_envelope = msg_update_client(&ClientId::from_str("nonexistingclient").unwrap());
 
-    let ctx = MockContext::default().with_light_client(
-        &client_id,
-        LightClientState::<MockHost>::with_latest_height(Height::new(0, 42).unwrap()),
+    let ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 42).unwrap()),
+    );
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(res.is_err());
+}
+
+/// Tests misbehaviour handling for the synthetic Tendermint client.
+/// Misbehaviour evidence consists of equivocal headers.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_equivocation() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    // Create a mock context for chain-B
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(misbehaviour_height)
+        .build::<TendermintContext>();
+
+    // Create a mock context for chain-A with a synthetic tendermint light client for chain-B
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    // Get chain-B's header at `misbehaviour_height`
+    let header1: TmHeader = {
+        let block = ctx_b.host_block(&misbehaviour_height).unwrap();
+        let mut block = block.into_header();
+        block.set_trusted_height(client_height);
+        block.into()
+    };
+
+    // Fork chain-B at `misbehaviour_height` to get an equivocal header
+    let header2 = {
+        let mut tm_block = ctx_b
+            .host
+            .fork_block(&misbehaviour_height, &Default::default())
+            .into_header();
+        tm_block.set_trusted_height(client_height);
+        tm_block.into()
+    };
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok());
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok());
+    ensure_misbehaviour(
+        &ctx_a.ibc_store,
+        &client_id,
+        &tm_client_type(),
+        Some(misbehaviour_height),
+    );
+    ensure_misbehaviour_heights(&ctx_a.ibc_store, &[misbehaviour_height]);
+}
+
+/// Tests misbehaviour handling for the synthetic Tendermint client.
+/// Misbehaviour evidence consists of equivocal headers, one of which is signed
+/// by a validator set the client never trusted. Such evidence is invalid and
+/// must not freeze the client.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_invalid_signers() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(misbehaviour_height)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    // next validator set trusted by the client at `client_height`
+    let trusted_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    let header1: TmHeader = {
+        let mut block = ctx_b
+            .host_block(&misbehaviour_height)
+            .unwrap()
+            .into_header();
+        block.set_trusted_height(client_height);
+        block.set_trusted_next_validators_set(trusted_next_validator_set.clone());
+        block.into()
+    };
+
+    // Fork chain-B at `misbehaviour_height` with a validator set that shares no
+    // validator with the trusted one.
+    let header2: TmHeader = {
+        let invalid_validators = vec![
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ];
+        let mut block = ctx_b
+            .host
+            .fork_block(
+                &misbehaviour_height,
+                &BlockParams::builder()
+                    .validators(invalid_validators.clone())
+                    .next_validators(invalid_validators)
+                    .build(),
+            )
+            .into_header();
+        block.set_trusted_height(client_height);
+        block.set_trusted_next_validators_set(trusted_next_validator_set);
+        block.into()
+    };
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    // Submits the evidence as a host would, i.e. executes it only if it is
+    // valid, and checks the stored client state afterwards.
+    let res = ctx_a.dispatch(msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::InvalidMisbehaviour { .. }
+            ))
+        ),
+        "{res:?}"
     );
-    let res = validate(&ctx.ibc_store, &router, msg_envelope);
-    assert!(res.is_err());
+
+    let client_state = ctx_a.ibc_store.client_state(&client_id).unwrap();
+    let status = client_state.status(&ctx_a.ibc_store, &client_id).unwrap();
+    assert!(!status.is_frozen(), "client_state status: {status}");
 }
 
-/// Tests misbehaviour handling for the synthetic Tendermint client.
-/// Misbehaviour evidence consists of equivocal headers.
 #[rstest]
-fn test_misbehaviour_synthetic_tendermint_equivocation() {
+fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
     let misbehaviour_height = Height::new(1, 21).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
-    // Create a mock context for chain-B
     let ctx_b = TestContextConfig::builder()
         .host(
             TendermintHost::builder()
                 .chain_id(chain_id_b.clone())
                 .build(),
         )
-        .latest_height(misbehaviour_height)
+        .latest_height(client_height)
         .build::<TendermintContext>();
 
     // Create a mock context for chain-A with a synthetic tendermint light client for chain-B
@@ -1261,57 +2211,77 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
         .build::<MockContext>()
         .with_light_client(
             &client_id,
-            LightClientBuilder::init()
-                .context(&ctx_b)
-                .consensus_heights([client_height])
-                .build(),
+            LightClientBuilder::init().context(&ctx_b).build(),
         );
 
     let mut router_a = MockRouter::new_with_transfer();
 
-    // Get chain-B's header at `misbehaviour_height`
-    let header1: TmHeader = {
-        let block = ctx_b.host_block(&misbehaviour_height).unwrap();
-        let mut block = block.into_header();
-        block.set_trusted_height(client_height);
-        block.into()
+    // Generate `header1` for chain-B
+    let header1 = {
+        let mut tm_block = TendermintHost::builder()
+            .chain_id(chain_id_b.clone())
+            .build()
+            .generate_block(
+                Vec::new(),
+                misbehaviour_height.revision_height(),
+                Timestamp::now(),
+                &Default::default(),
+            )
+            .into_header();
+        tm_block.set_trusted_height(client_height);
+        tm_block
     };
 
-    // Generate an equivocal header for chain-B at `misbehaviour_height`
+    // Generate `header2` for chain-B which is identical to `header1` but with a conflicting
+    // timestamp
     let header2 = {
+        let timestamp =
+            Timestamp::from_nanoseconds(Timestamp::now().nanoseconds() + 1_000_000_000).unwrap();
         let mut tm_block = TendermintHost::builder()
             .chain_id(chain_id_b)
             .build()
             .generate_block(
                 Vec::new(),
                 misbehaviour_height.revision_height(),
-                Timestamp::now(),
+                timestamp,
                 &Default::default(),
             )
             .into_header();
         tm_block.set_trusted_height(client_height);
-        tm_block.into()
+        tm_block
     };
 
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
-        client_message: TmMisbehaviour::new(client_id.clone(), header1, header2).into(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1.into(), header2.into())
+            .into(),
         signer: dummy_account_id(),
     };
+
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
     let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
     assert!(res.is_ok());
     let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
     assert!(res.is_ok());
-    ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
+    ensure_misbehaviour(
+        &ctx_a.ibc_store,
+        &client_id,
+        &tm_client_type(),
+        Some(misbehaviour_height),
+    );
 }
 
+/// Tests misbehaviour handling for the synthetic Tendermint client.
+/// Misbehaviour evidence consists of two headers at different heights, sharing
+/// the same trusted height, where the higher header has the earlier timestamp,
+/// violating monotonic time.
 #[rstest]
-fn test_misbehaviour_synthetic_tendermint_bft_time() {
+fn test_misbehaviour_synthetic_tendermint_monotonicity() {
     let client_id = tm_client_type().build_client_id(0);
     let client_height = Height::new(1, 20).unwrap();
-    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let lower_height = Height::new(1, 21).unwrap();
+    let higher_height = Height::new(1, 22).unwrap();
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
 
     let ctx_b = TestContextConfig::builder()
@@ -1339,15 +2309,17 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
 
     let mut router_a = MockRouter::new_with_transfer();
 
-    // Generate `header1` for chain-B
+    let timestamp = Timestamp::now();
+
+    // Generate `header1` for chain-B at the higher height
     let header1 = {
         let mut tm_block = TendermintHost::builder()
             .chain_id(chain_id_b.clone())
             .build()
             .generate_block(
                 Vec::new(),
-                misbehaviour_height.revision_height(),
-                Timestamp::now(),
+                higher_height.revision_height(),
+                timestamp,
                 &Default::default(),
             )
             .into_header();
@@ -1355,17 +2327,16 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
         tm_block
     };
 
-    // Generate `header2` for chain-B which is identical to `header1` but with a conflicting
-    // timestamp
+    // Generate `header2` for chain-B at the lower height, but with a later timestamp
     let header2 = {
         let timestamp =
-            Timestamp::from_nanoseconds(Timestamp::now().nanoseconds() + 1_000_000_000).unwrap();
+            Timestamp::from_nanoseconds(timestamp.nanoseconds() + 1_000_000_000).unwrap();
         let mut tm_block = TendermintHost::builder()
             .chain_id(chain_id_b)
             .build()
             .generate_block(
                 Vec::new(),
-                misbehaviour_height.revision_height(),
+                lower_height.revision_height(),
                 timestamp,
                 &Default::default(),
             )
@@ -1384,10 +2355,16 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
     let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
-    assert!(res.is_ok());
+    assert!(res.is_ok(), "{res:?}");
     let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
-    assert!(res.is_ok());
-    ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
+    assert!(res.is_ok(), "{res:?}");
+    ensure_misbehaviour(
+        &ctx_a.ibc_store,
+        &client_id,
+        &tm_client_type(),
+        Some(lower_height),
+    );
+    ensure_misbehaviour_heights(&ctx_a.ibc_store, &[higher_height, lower_height]);
 }
 
 #[rstest]
@@ -1444,6 +2421,227 @@ fn test_expired_client() {
         .is_expired());
 }
 
+#[rstest]
+fn test_update_rejected_after_host_time_travel() {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 20).unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let trusting_period = Duration::from_secs(64);
+
+    let mut ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(client_height)
+        .latest_timestamp(timestamp)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(trusting_period)
+                        .build(),
+                )
+                .build(),
+        );
+
+    let router_a = MockRouter::new_with_transfer();
+
+    ctx_b.advance_block_height();
+
+    let mut block = ctx_b.query_latest_block().unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    // Submit the update well after the latest block on chain A, past the
+    // client's trusting period.
+    let host_height = ctx_a.latest_height();
+    ctx_a.set_host_timestamp((timestamp + (trusting_period + Duration::from_secs(1))).unwrap());
+    assert_eq!(ctx_a.latest_height(), host_height);
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientNotActive {
+                status: Status::Expired
+            }))
+        ),
+        "update should be rejected as client expired. err: {res:?}"
+    );
+}
+
+/// A regular update never stretches the trusting period of an expired client,
+/// even if it allows updates after expiry: such clients are only revived
+/// through client recovery.
+#[rstest]
+#[case::allow_update_after_expiry(true)]
+#[case::disallow_update_after_expiry(false)]
+fn test_update_after_expiry_rejected(#[case] allow_update_after_expiry: bool) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 20).unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let trusting_period = Duration::from_secs(64);
+
+    let mut ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(client_height)
+        .latest_timestamp(timestamp)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(trusting_period)
+                        .allow_update(AllowUpdate {
+                            after_expiry: allow_update_after_expiry,
+                            after_misbehaviour: false,
+                        })
+                        .build(),
+                )
+                .build(),
+        );
+
+    let router_a = MockRouter::new_with_transfer();
+
+    ctx_b.advance_block_height();
+
+    let mut block = ctx_b.query_latest_block().unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    ctx_a.set_host_timestamp((timestamp + (trusting_period + Duration::from_secs(1))).unwrap());
+
+    let client_state = ctx_a.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(
+        client_state.status(&ctx_a.ibc_store, &client_id).unwrap(),
+        Status::Expired
+    );
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientNotActive {
+                status: Status::Expired
+            }))
+        ),
+        "update should be rejected as client expired. err: {res:?}"
+    );
+}
+
+#[test]
+fn test_client_expired_event_emitted_once() {
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let trusting_period = Duration::from_secs(64);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 20).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(trusting_period)
+                        .build(),
+                )
+                .build(),
+        );
+
+    let expired_events = |ctx: &MockContext| {
+        ctx.get_events()
+            .into_iter()
+            .filter(|event| matches!(event, IbcEvent::ClientExpired(_)))
+            .count()
+    };
+
+    let status = ctx_a
+        .ibc_store
+        .check_and_emit_client_status(&client_id)
+        .unwrap();
+    assert_eq!(status, Status::Active);
+    assert_eq!(expired_events(&ctx_a), 0);
+
+    ctx_a.set_host_timestamp((timestamp + (trusting_period + Duration::from_secs(1))).unwrap());
+
+    for _ in 0..2 {
+        let status = ctx_a
+            .ibc_store
+            .check_and_emit_client_status(&client_id)
+            .unwrap();
+        assert_eq!(status, Status::Expired);
+    }
+
+    assert_eq!(expired_events(&ctx_a), 1);
+}
+
 #[rstest]
 fn test_client_update_max_clock_drift() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1681,3 +2879,55 @@ fn client_update_ping_pong() {
         );
     }
 }
+
+#[rstest]
+fn test_latest_consensus_state_height_across_revisions() {
+    let client_id = mock_client_type().build_client_id(0);
+
+    let rev_1_low = Height::new(1, 5).unwrap();
+    let rev_1_high = Height::new(1, 10).unwrap();
+    let rev_2 = Height::new(2, 3).unwrap();
+
+    let mut ctx = MockContext::default().with_client_state(
+        &client_id,
+        MockClientState::new(MockHeader::new(rev_2)).into(),
+    );
+    for height in [rev_1_low, rev_1_high, rev_2] {
+        ctx = ctx.with_consensus_state(
+            &client_id,
+            height,
+            MockConsensusState::new(MockHeader::new(height)).into(),
+        );
+    }
+    let ibc_store = ctx.ibc_store();
+
+    assert_eq!(
+        ibc_store.latest_consensus_state_height(&client_id).unwrap(),
+        Some(rev_2)
+    );
+
+    // Pre-upgrade consensus states remain reachable.
+    for height in [rev_1_low, rev_1_high] {
+        let path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        assert_eq!(
+            ibc_store.consensus_state(&path).unwrap(),
+            MockConsensusState::new(MockHeader::new(height)).into()
+        );
+    }
+
+    // Neighbouring consensus states are found across the revision boundary.
+    assert_eq!(
+        ibc_store
+            .next_consensus_state(&client_id, &rev_1_high)
+            .unwrap(),
+        Some(MockConsensusState::new(MockHeader::new(rev_2)).into())
+    );
+    assert_eq!(
+        ibc_store.prev_consensus_state(&client_id, &rev_2).unwrap(),
+        Some(MockConsensusState::new(MockHeader::new(rev_1_high)).into())
+    );
+}
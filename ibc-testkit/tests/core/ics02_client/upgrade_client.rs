@@ -1,8 +1,9 @@
 use ibc::clients::tendermint::types::client_type;
+use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::{ClientValidationContext, ExtClientValidationContext};
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpgradeClient};
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
@@ -23,10 +24,12 @@ use ibc_testkit::testapp::ibc::core::types::LightClientState;
 enum Ctx {
     Default,
     WithClient,
+    WithFrozenClient,
 }
 
 enum Msg {
     Default,
+    AdjacentRevision,
     LowUpgradeHeight,
     UnknownUpgradedClientStateType,
 }
@@ -39,14 +42,25 @@ fn msg_upgrade_client_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<Msg
         &client_id,
         LightClientState::<MockHost>::with_latest_height(Height::new(0, 42).unwrap()),
     );
+    let mut frozen_light_client =
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 42).unwrap());
+    frozen_light_client.client_state = frozen_light_client.client_state.frozen();
+    let ctx_with_frozen_client =
+        MockContext::default().with_light_client(&client_id, frozen_light_client);
     let ctx = match ctx_variant {
         Ctx::Default => ctx_default.ibc_store,
         Ctx::WithClient => ctx_with_client.ibc_store,
+        Ctx::WithFrozenClient => ctx_with_frozen_client.ibc_store,
     };
 
     let upgrade_height = Height::new(1, 26).unwrap();
     let msg_default = dummy_msg_upgrade_client(client_id.clone(), upgrade_height);
 
+    // The first height of the next revision.
+    let adjacent_revision_height = Height::new(1, 1).unwrap();
+    let msg_with_adjacent_revision =
+        dummy_msg_upgrade_client(client_id.clone(), adjacent_revision_height);
+
     let low_upgrade_height = Height::new(0, 26).unwrap();
     let msg_with_low_upgrade_height = dummy_msg_upgrade_client(client_id, low_upgrade_height);
 
@@ -57,6 +71,7 @@ fn msg_upgrade_client_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<Msg
 
     let msg = match msg_variant {
         Msg::Default => msg_default,
+        Msg::AdjacentRevision => msg_with_adjacent_revision,
         Msg::LowUpgradeHeight => msg_with_low_upgrade_height,
         Msg::UnknownUpgradedClientStateType => msg_with_unknown_upgraded_cs,
     };
@@ -105,15 +120,15 @@ fn upgrade_client_execute(fxt: &mut Fixture<MsgUpgradeClient>, expect: Expect) {
             let IbcEvent::UpgradeClient(upgrade_client_event) = &ibc_events[1] else {
                 panic!("UpgradeClient event is expected")
             };
-            let plan_height = Height::new(1, 26).unwrap();
+            let msg_client_state: AnyClientState =
+                fxt.msg.upgraded_client_state.clone().try_into().unwrap();
+            let plan_height = msg_client_state.latest_height();
 
             assert_eq!(upgrade_client_event.client_id(), &fxt.msg.client_id);
             assert_eq!(upgrade_client_event.client_type(), &mock_client_type());
             assert_eq!(upgrade_client_event.consensus_height(), &plan_height);
 
             let client_state = fxt.ctx.client_state(&fxt.msg.client_id).unwrap();
-            let msg_client_state: AnyClientState =
-                fxt.msg.upgraded_client_state.clone().try_into().unwrap();
             assert_eq!(client_state, msg_client_state);
 
             let consensus_state = fxt
@@ -138,6 +153,35 @@ fn msg_upgrade_client_healthy() {
     upgrade_client_execute(&mut fxt, Expect::Success);
 }
 
+#[test]
+fn msg_upgrade_client_adjacent_revision() {
+    let mut fxt = msg_upgrade_client_fixture(Ctx::WithClient, Msg::AdjacentRevision);
+    upgrade_client_validate(&fxt, Expect::Success);
+    upgrade_client_execute(&mut fxt, Expect::Success);
+
+    let client_state = fxt.ctx.client_state(&fxt.msg.client_id).unwrap();
+    assert_eq!(client_state.latest_height(), Height::new(1, 1).unwrap());
+
+    // The consensus state of the previous revision is kept.
+    assert!(fxt
+        .ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            fxt.msg.client_id.clone(),
+            0,
+            42
+        ))
+        .is_ok());
+}
+
+#[test]
+fn upgrade_client_fail_frozen_client() {
+    let fxt = msg_upgrade_client_fixture(Ctx::WithFrozenClient, Msg::Default);
+    let expected_err = ContextError::ClientError(ClientError::ClientNotActive {
+        status: Status::Frozen,
+    });
+    upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err)));
+}
+
 #[test]
 fn upgrade_client_fail_nonexisting_client() {
     let fxt = msg_upgrade_client_fixture(Ctx::Default, Msg::Default);
@@ -1,23 +1,32 @@
 use core::str::FromStr;
 
+use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenAck};
+use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
-use ibc_testkit::context::MockContext;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_ack;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::fixtures::{Expect, Fixture};
-use ibc_testkit::hosts::MockHost;
+use ibc_testkit::hosts::{MockHost, TendermintHost};
+use ibc_testkit::relayer::error::RelayerError;
+use ibc_testkit::relayer::utils::TypedRelayerOps;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
 use test_log::test;
@@ -212,3 +221,121 @@ fn conn_open_ack_connection_mismatch() {
     });
     conn_open_ack_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+#[test]
+fn conn_open_ack_mismatched_self_consensus_state() {
+    let signer = dummy_account_id();
+
+    let mut ctx_a = TendermintContext::default();
+    let mut ctx_b = TendermintContext::default();
+
+    let client_id_on_a = TypedRelayerOps::<TendermintHost, TendermintHost>::create_client_on_a(
+        &mut ctx_a,
+        &ctx_b,
+        signer.clone(),
+    );
+    let client_id_on_b = TypedRelayerOps::<TendermintHost, TendermintHost>::create_client_on_a(
+        &mut ctx_b,
+        &ctx_a,
+        signer.clone(),
+    );
+
+    let conn_id_on_a = TypedRelayerOps::<TendermintHost, TendermintHost>::connection_open_init_on_a(
+        &mut ctx_a,
+        &ctx_b,
+        client_id_on_a.clone(),
+        client_id_on_b.clone(),
+        signer.clone(),
+    );
+
+    TypedRelayerOps::<TendermintHost, TendermintHost>::update_client_on_a_with_sync(
+        &mut ctx_b,
+        &mut ctx_a,
+        client_id_on_b.clone(),
+        signer.clone(),
+    );
+
+    let conn_id_on_b = TypedRelayerOps::<TendermintHost, TendermintHost>::connection_open_try_on_b(
+        &mut ctx_b,
+        &ctx_a,
+        conn_id_on_a.clone(),
+        client_id_on_a.clone(),
+        client_id_on_b.clone(),
+        signer.clone(),
+    );
+
+    // `B` commits a consensus state of `A` that does not match the one of
+    // `A` at the claimed height.
+    let consensus_height_of_a_on_b = ctx_b.light_client_latest_height(&client_id_on_b);
+    let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+        client_id_on_b.clone(),
+        consensus_height_of_a_on_b.revision_number(),
+        consensus_height_of_a_on_b.revision_height(),
+    );
+    let mismatched_consensus_state = ctx_a
+        .ibc_store()
+        .host_consensus_state(
+            &consensus_height_of_a_on_b
+                .decrement()
+                .expect("height is not the first"),
+        )
+        .expect("host consensus state exists");
+
+    ctx_b
+        .ibc_store_mut()
+        .store_consensus_state(
+            client_cons_state_path_on_b.clone(),
+            mismatched_consensus_state,
+        )
+        .expect("no error");
+    ctx_b.advance_block_height();
+
+    TypedRelayerOps::<TendermintHost, TendermintHost>::update_client_on_a_with_sync(
+        &mut ctx_a,
+        &mut ctx_b,
+        client_id_on_a,
+        signer.clone(),
+    );
+
+    let proofs_height_on_b = ctx_b.latest_height();
+    let proof_at = |path: Path| -> CommitmentProofBytes {
+        ctx_b
+            .ibc_store()
+            .get_proof(proofs_height_on_b, &path)
+            .expect("value exists")
+            .try_into()
+            .expect("value merkle proof")
+    };
+
+    let msg = MsgEnvelope::Connection(ConnectionMsg::OpenAck(MsgConnectionOpenAck {
+        conn_id_on_a: conn_id_on_a.clone(),
+        conn_id_on_b: conn_id_on_b.clone(),
+        client_state_of_a_on_b: ctx_b
+            .ibc_store()
+            .client_state(&client_id_on_b)
+            .expect("client state exists")
+            .into(),
+        proof_conn_end_on_b: proof_at(ConnectionPath::new(&conn_id_on_b).into()),
+        proof_client_state_of_a_on_b: proof_at(ClientStatePath::new(client_id_on_b).into()),
+        proof_consensus_state_of_a_on_b: proof_at(client_cons_state_path_on_b.into()),
+        proofs_height_on_b,
+        consensus_height_of_a_on_b,
+        version: ConnectionVersion::compatibles()[0].clone(),
+        signer,
+        proof_consensus_state_of_a: None,
+    }));
+
+    let res = ctx_a.deliver(msg);
+
+    assert!(
+        matches!(
+            res,
+            Err(RelayerError::TransactionFailed(
+                ContextError::ConnectionError(
+                    ConnectionError::ConsensusStateVerificationFailure { .. }
+                )
+            ))
+        ),
+        "{res:?}"
+    );
+}
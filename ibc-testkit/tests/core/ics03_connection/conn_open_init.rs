@@ -1,8 +1,12 @@
+use core::time::Duration;
+
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
 use ibc::core::connection::types::version::Version;
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::ValidationContext;
@@ -147,3 +151,30 @@ fn conn_open_init_with_counterparty_conn_id() {
     let expected_version = vec![fxt.msg.version.clone().unwrap()];
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
+
+#[test]
+fn conn_open_init_delay_period_too_short() {
+    let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx.min_connection_delay = Duration::from_secs(3600);
+    fxt.msg.delay_period = Duration::ZERO;
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+    let res = validate(&fxt.ctx, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::DelayPeriodTooShort {
+                    delay_period,
+                    min_delay_period,
+                }
+            )) if delay_period == Duration::ZERO && min_delay_period == Duration::from_secs(3600)
+        ),
+        "{res:?}"
+    );
+
+    fxt.msg.delay_period = Duration::from_secs(3600);
+    conn_open_init_validate(&fxt, Expect::Success);
+}
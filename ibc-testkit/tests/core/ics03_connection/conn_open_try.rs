@@ -1,7 +1,9 @@
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::ValidationContext;
@@ -153,3 +155,32 @@ fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+#[test]
+fn conn_open_try_stale_proof() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx.max_proof_staleness = Some(2);
+
+    // The proof height matches the latest height of the client.
+    conn_open_try_validate(&fxt, Expect::Success);
+
+    let latest_height = fxt.msg.proofs_height_on_a;
+    let proof_height = latest_height.sub(3).unwrap();
+    fxt.msg.proofs_height_on_a = proof_height;
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+    let res = validate(&fxt.ctx, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(ConnectionError::StaleProof {
+                proof_height: stale_height,
+                latest_height: client_height,
+                max_staleness: 2,
+            })) if stale_height == proof_height && client_height == latest_height
+        ),
+        "{res:?}"
+    );
+}
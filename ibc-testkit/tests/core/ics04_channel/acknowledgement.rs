@@ -1,7 +1,10 @@
+use ibc::core::channel::handler::ack_packets_ordered;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
 use ibc::core::channel::types::Version;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -9,13 +12,17 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{CommitmentPath, SeqAckPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -184,6 +191,58 @@ fn ack_success_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn ack_fail_proof_height_too_new(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        client_height,
+        ..
+    } = fixture;
+
+    let proof_height = client_height.increment();
+
+    let msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
+        proof_height.revision_height(),
+    ))
+    .unwrap();
+
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::HeightTooNew { got, latest }))
+                if got == proof_height && latest == client_height
+        ),
+        "Validation should fail with `HeightTooNew` when the proof height is above the client's latest height. err: {res:?}"
+    )
+}
+
 #[rstest]
 fn ack_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
@@ -225,6 +284,65 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
 
+/// A second acknowledgement for the same packet on an unordered channel is
+/// rejected rather than treated as a no-op.
+#[rstest]
+fn ack_unordered_chan_duplicate_fail(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let sequence = msg.packet.seq_on_a;
+    let seq_ack_path = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope.clone());
+    assert!(res.is_ok(), "{res:?}");
+    assert!(ctx
+        .ibc_store
+        .is_sequence_acknowledged(&seq_ack_path, sequence)
+        .unwrap());
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::AcknowledgementExists { sequence: seq }))
+                if seq == sequence
+        ),
+        "{res:?}"
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::AcknowledgementExists { sequence: seq }))
+                if seq == sequence
+        ),
+        "{res:?}"
+    );
+}
+
 #[rstest]
 fn ack_ordered_chan_execute(fixture: Fixture) {
     let Fixture {
@@ -261,3 +379,194 @@ fn ack_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
+
+#[rstest]
+fn ack_ordered_chan_in_order_advances_next_seq_ack(fixture: Fixture) {
+    let (mut ctx, msgs) = ordered_batch(fixture);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = msgs[0].clone();
+    let seq_ack_path = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "in-order ack must be accepted. err: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "in-order ack must be applied. err: {res:?}");
+
+    assert_eq!(
+        ctx.ibc_store.get_next_sequence_ack(&seq_ack_path).unwrap(),
+        Sequence::from(2)
+    );
+}
+
+#[rstest]
+fn ack_ordered_chan_out_of_order_fail(fixture: Fixture) {
+    let (ctx, msgs) = ordered_batch(fixture);
+    let router = MockRouter::new_with_transfer();
+
+    // Acknowledging sequence 2 while sequence 1 is still pending leaves a gap
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msgs[1].clone()));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::UnexpectedSequence {
+                expected,
+                actual,
+            })) if expected == Sequence::from(1) && actual == Sequence::from(2)
+        ),
+        "out-of-order ack must be rejected. err: {res:?}"
+    );
+}
+
+/// Builds a context with an ordered channel holding commitments for packets
+/// with sequences 1 and 2, along with the acknowledgements for them.
+fn ordered_batch(fixture: Fixture) -> (MockContext, Vec<MsgAcknowledgement>) {
+    let Fixture {
+        ctx,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        ..
+    } = fixture;
+
+    let mut msg_2 = msg.clone();
+    msg_2.packet.seq_on_a = Sequence::from(2);
+
+    let port_id = msg.packet.port_id_on_a.clone();
+    let chan_id = msg.packet.chan_id_on_a.clone();
+
+    let ctx = ctx
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end_on_a_ordered)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_ack_sequence(port_id.clone(), chan_id.clone(), Sequence::from(1))
+        .with_packet_commitment(
+            port_id.clone(),
+            chan_id.clone(),
+            Sequence::from(1),
+            packet_commitment.clone(),
+        )
+        .with_packet_commitment(port_id, chan_id, Sequence::from(2), packet_commitment);
+
+    (ctx, vec![msg, msg_2])
+}
+
+#[rstest]
+fn ack_packets_ordered_out_of_order_batch_rejected(fixture: Fixture) {
+    let (mut ctx, mut msgs) = ordered_batch(fixture);
+    msgs.reverse();
+
+    let port_id = msgs[0].packet.port_id_on_a.clone();
+    let chan_id = msgs[0].packet.chan_id_on_a.clone();
+
+    let mut module = DummyTransferModule::new();
+
+    let res = ack_packets_ordered(&mut ctx.ibc_store, &mut module, msgs);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::UnexpectedSequence {
+                expected,
+                actual,
+            })) if expected == Sequence::from(1) && actual == Sequence::from(2)
+        ),
+        "an out-of-order batch must be rejected. err: {res:?}"
+    );
+
+    // Nothing was applied
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+            .unwrap(),
+        Sequence::from(1)
+    );
+    for seq in [1, 2] {
+        assert!(ctx
+            .ibc_store
+            .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, seq.into()))
+            .is_ok());
+    }
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn ack_packets_ordered_invalid_last_ack_rejects_batch(fixture: Fixture) {
+    let (mut ctx, mut msgs) = ordered_batch(fixture);
+
+    // The second packet does not match its stored commitment
+    msgs[1].packet.data = b"tampered".to_vec();
+
+    let port_id = msgs[0].packet.port_id_on_a.clone();
+    let chan_id = msgs[0].packet.chan_id_on_a.clone();
+
+    let mut module = DummyTransferModule::new();
+
+    let res = ack_packets_ordered(&mut ctx.ibc_store, &mut module, msgs);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::IncorrectPacketCommitment {
+                sequence,
+            })) if sequence == Sequence::from(2)
+        ),
+        "a batch with an invalid ack must be rejected. err: {res:?}"
+    );
+
+    // The valid first ack was not applied either
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+            .unwrap(),
+        Sequence::from(1)
+    );
+    for seq in [1, 2] {
+        assert!(ctx
+            .ibc_store
+            .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, seq.into()))
+            .is_ok());
+    }
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn ack_packets_ordered_contiguous_batch_applied(fixture: Fixture) {
+    let (mut ctx, msgs) = ordered_batch(fixture);
+
+    let port_id = msgs[0].packet.port_id_on_a.clone();
+    let chan_id = msgs[0].packet.chan_id_on_a.clone();
+
+    let mut module = DummyTransferModule::new();
+
+    let res = ack_packets_ordered(&mut ctx.ibc_store, &mut module, msgs);
+
+    assert!(
+        res.is_ok(),
+        "a contiguous batch must be applied. err: {res:?}"
+    );
+
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_ack(&SeqAckPath::new(&port_id, &chan_id))
+            .unwrap(),
+        Sequence::from(3)
+    );
+    for seq in [1, 2] {
+        assert!(ctx
+            .ibc_store
+            .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, seq.into()))
+            .is_err());
+    }
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
+    assert!(matches!(ibc_events[3], IbcEvent::AcknowledgePacket(_)));
+}
@@ -1,4 +1,5 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseConfirm};
 use ibc::core::channel::types::Version;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -6,15 +7,19 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, SeqRecvPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_confirm;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
-use ibc_testkit::hosts::MockHost;
+use ibc_testkit::fixtures::core::relayer::{ChannelFixture, RelayerFixture};
+use ibc_testkit::hosts::{MockHost, TendermintHost};
+use ibc_testkit::relayer::utils::TypedRelayerOps;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
@@ -140,3 +145,129 @@ fn test_chan_close_confirm_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseConfirmChannel(_)));
 }
+
+#[test]
+fn test_chan_close_confirm_validate_stale_proof_height() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let default_context = MockContext::default();
+    let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
+        dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
+    )
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm.clone()));
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Ordered,
+        Counterparty::new(
+            msg_chan_close_confirm.port_id_on_b.clone(),
+            Some(msg_chan_close_confirm.chan_id_on_b.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let mut context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+        )
+        .with_connection(conn_id, conn_end)
+        .with_channel(
+            msg_chan_close_confirm.port_id_on_b.clone(),
+            msg_chan_close_confirm.chan_id_on_b.clone(),
+            chan_end,
+        );
+
+    // A packet was received with a proof above the one of the close message.
+    context
+        .ibc_store
+        .store_last_recv_proof_height(
+            &SeqRecvPath::new(
+                &msg_chan_close_confirm.port_id_on_b,
+                &msg_chan_close_confirm.chan_id_on_b,
+            ),
+            client_consensus_state_height.increment(),
+        )
+        .unwrap();
+
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::InvalidProof))
+        ),
+        "Validation expected to fail on a stale proof height. Error: {res:?}"
+    );
+}
+
+#[test]
+fn test_chan_close_confirm_validate_ordering_mismatch() {
+    let mut fixture = RelayerFixture::<TendermintHost, TendermintHost>::default();
+
+    let ChannelFixture {
+        chan_id_on_a,
+        chan_id_on_b,
+        ..
+    } = fixture.open_transfer_channel(Order::Unordered);
+
+    TypedRelayerOps::<TendermintHost, TendermintHost>::channel_close_init_on_a(
+        fixture.relayer.get_ctx_a_mut(),
+        chan_id_on_a.clone(),
+        PortId::transfer(),
+        fixture.signer.clone(),
+    );
+
+    // The unordered end closed on `A` is presented to an end on `B` that
+    // considers the channel ordered.
+    let chan_end_path_on_b = ChannelEndPath::new(&PortId::transfer(), &chan_id_on_b);
+    let ctx_b = fixture.relayer.get_ctx_b_mut();
+    let mut chan_end_on_b = ctx_b.ibc_store().channel_end(&chan_end_path_on_b).unwrap();
+    chan_end_on_b.ordering = Order::Ordered;
+    ctx_b
+        .ibc_store_mut()
+        .store_channel(&chan_end_path_on_b, chan_end_on_b)
+        .unwrap();
+
+    let (proof_chan_end_on_a, proof_height_on_a) =
+        fixture.prove_on_a(ChannelEndPath::new(&PortId::transfer(), &chan_id_on_a).into());
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(MsgChannelCloseConfirm {
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b,
+        proof_chan_end_on_a,
+        proof_height_on_a,
+        signer: fixture.signer,
+    }));
+
+    let ctx_b = fixture.relayer.get_ctx_b();
+    let res = validate(ctx_b.ibc_store(), &ctx_b.ibc_router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::OrderingMismatch {
+                expected: Order::Ordered,
+                actual: Order::Unordered,
+            }))
+        ),
+        "Validation expected to fail on an ordering mismatch. Error: {res:?}"
+    );
+}
@@ -9,6 +9,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
@@ -142,3 +143,83 @@ fn test_chan_close_init_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseInitChannel(_)));
 }
+
+#[test]
+fn test_chan_close_init_abandon_handshake() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    // The handshake got stuck before the connection was opened.
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Init,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(None)).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init.clone()));
+
+    // The counterparty channel was never created.
+    let chan_end = ChannelEnd::new(
+        ChannelState::Init,
+        Order::Unordered,
+        Counterparty::new(msg_chan_close_init.port_id_on_a.clone(), None),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+        default_context
+            .with_light_client(
+                &client_id,
+                LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(
+                msg_chan_close_init.port_id_on_a.clone(),
+                msg_chan_close_init.chan_id_on_a.clone(),
+                chan_end,
+            )
+    };
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed for a stuck handshake. Error: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution of abandoned handshake");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(
+            &msg_chan_close_init.port_id_on_a,
+            &msg_chan_close_init.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &ChannelState::Closed);
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        &ibc_events[1],
+        IbcEvent::CloseInitChannel(event) if event.chan_id_on_b().is_none()
+    ));
+}
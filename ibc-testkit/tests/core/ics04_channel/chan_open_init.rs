@@ -1,13 +1,20 @@
 use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{
+    ChannelEndPath, NextChannelSequencePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Protobuf;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
@@ -110,6 +117,43 @@ fn chan_open_init_execute_happy_path(fixture: Fixture) {
     assert!(matches!(ibc_events[1], IbcEvent::OpenInitChannel(_)));
 }
 
+#[rstest]
+fn chan_open_init_execute_reports_store_writes(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+        ..
+    } = fixture;
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg);
+
+    assert!(res.is_ok(), "Execution succeeds; good parameters");
+
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let chan_end_path = ChannelEndPath::new(&port_id, &chan_id);
+    let chan_end = ctx.ibc_store.channel_end(&chan_end_path).unwrap();
+    let initial_seq = 1u64.to_be_bytes().to_vec();
+
+    let expected_writes: Vec<(Path, Vec<u8>)> = vec![
+        (chan_end_path.into(), chan_end.encode_vec()),
+        (NextChannelSequencePath.into(), 1u64.to_be_bytes().to_vec()),
+        (
+            SeqSendPath::new(&port_id, &chan_id).into(),
+            initial_seq.clone(),
+        ),
+        (
+            SeqRecvPath::new(&port_id, &chan_id).into(),
+            initial_seq.clone(),
+        ),
+        (SeqAckPath::new(&port_id, &chan_id).into(), initial_seq),
+    ];
+
+    assert_eq!(*ctx.ibc_store.store_writes.lock(), expected_writes);
+}
+
 #[rstest]
 fn chan_open_init_fail_no_connection(fixture: Fixture) {
     let Fixture { router, msg, .. } = fixture;
@@ -121,3 +165,49 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_fail_empty_connection_hops(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.connection_hops_on_a.clear();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::MissingConnectionHops
+            ))
+        ),
+        "Validation fails because no connection hops are given"
+    )
+}
+
+#[rstest]
+fn chan_open_init_fail_dangling_connection_hop(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let dangling_conn_id = ConnectionId::new(7);
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.connection_hops_on_a = vec![dangling_conn_id.clone()];
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::ConnectionNotFound { connection_id }
+            )) if connection_id == dangling_conn_id
+        ),
+        "Validation fails because the connection hop does not exist"
+    )
+}
@@ -1,10 +1,12 @@
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenTry};
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
@@ -166,3 +168,39 @@ fn chan_open_try_fail_no_client_state(fixture: Fixture) {
         "Validation fails because the context has no client state"
     )
 }
+
+/// The consensus state used to verify the proofs of the message must be the
+/// one stored at the message's proof height, even if the client has a more
+/// recent one.
+#[rstest]
+fn chan_open_try_fail_no_consensus_state_at_proof_height(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        proof_height,
+    } = fixture;
+
+    let client_height = Height::new(0, proof_height + 2).unwrap();
+
+    let ctx = ctx
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b);
+
+    let res = validate(&ctx.ibc_store, &router, msg);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ConsensusStateNotFound { ref client_id, height }))
+                if client_id == &client_id_on_b && height == Height::new(0, proof_height).unwrap()
+        ),
+        "Validation fails because there is no consensus state at the proof height: {res:?}"
+    )
+}
@@ -1,4 +1,5 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
@@ -9,10 +10,14 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
+use ibc_query::core::context::QueryContext;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
@@ -151,6 +156,57 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+#[case::spoofed_channel(PortId::transfer(), ChannelId::new(5))]
+#[case::spoofed_port(PortId::new("spoofed".to_string()).unwrap(), ChannelId::zero())]
+fn recv_packet_fail_mismatched_source_channel(
+    fixture: Fixture,
+    #[case] port_id_on_a: PortId,
+    #[case] chan_id_on_a: ChannelId,
+) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    // The packet claims to come from a channel other than the destination
+    // channel's counterparty.
+    msg.packet.port_id_on_a = port_id_on_a;
+    msg.packet.chan_id_on_a = chan_id_on_a;
+
+    let packet = &msg.packet;
+    let context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::MismatchedChannel { .. }))
+        ),
+        "recv_packet validation should fail when the packet source does not match the channel counterparty. err: {res:?}"
+    )
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -229,15 +285,209 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
 
     let ibc_events = ctx.get_events();
 
-    assert_eq!(ibc_events.len(), 4);
+    assert_eq!(ibc_events.len(), 3);
     assert!(matches!(
         &ibc_events[0],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
     assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
-    assert!(matches!(
-        &ibc_events[2],
-        &IbcEvent::Message(MessageEvent::Channel)
-    ));
-    assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
+    assert!(matches!(&ibc_events[2], &IbcEvent::WriteAcknowledgement(_)));
+}
+
+#[rstest]
+fn recv_packet_execute_lists_acknowledgements(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        client_id,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let sequences: Vec<Sequence> = (1..=3).map(Sequence::from).collect();
+
+    for seq in &sequences {
+        let mut msg = msg.clone();
+        msg.packet.seq_on_a = *seq;
+
+        let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+        execute(&mut ctx.ibc_store, &mut router, msg_env).expect("recv_packet succeeds");
+    }
+
+    let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+
+    let list_acks = |filter: &[Sequence]| {
+        let mut acks = ctx
+            .ibc_store
+            .packet_acknowledgements(&chan_end_path, filter.iter().copied())
+            .expect("no error");
+        acks.sort_by_key(|ack| ack.seq);
+
+        for ack in &acks {
+            let ack_commitment = ctx
+                .ibc_store
+                .get_packet_acknowledgement(&AckPath::new(&ack.port_id, &ack.chan_id, ack.seq))
+                .expect("ack commitment exists");
+            assert_eq!(ack.data, ack_commitment.into_vec());
+        }
+
+        acks.into_iter().map(|ack| ack.seq).collect::<Vec<_>>()
+    };
+
+    // An empty filter lists every acknowledgement on the channel.
+    assert_eq!(list_acks(&[]), sequences);
+
+    let subset = [Sequence::from(1), Sequence::from(3)];
+    assert_eq!(list_acks(&subset), subset);
+
+    // Sequences without an acknowledgement are skipped.
+    assert_eq!(
+        list_acks(&[Sequence::from(2), Sequence::from(4)]),
+        [Sequence::from(2)]
+    );
+}
+
+/// Tests that a packet whose data was tampered with after being sent is
+/// rejected, as its commitment no longer matches the one proven at the
+/// packet's commitment path on the source chain.
+///
+/// Requires `serde` feature because of
+/// [`RelayerContext::send_dummy_transfer_packet_on_a`](ibc_testkit::relayer::context::RelayerContext::send_dummy_transfer_packet_on_a).
+#[cfg(feature = "serde")]
+#[rstest]
+fn recv_packet_fail_tampered_packet_data() {
+    use ibc::core::channel::types::error::PacketError;
+    use ibc::core::host::types::path::CommitmentPath;
+    use ibc_query::core::context::ProvableContext;
+    use ibc_testkit::context::TendermintContext;
+    use ibc_testkit::relayer::context::RelayerContext;
+
+    let signer = dummy_account_id();
+
+    let mut relayer =
+        RelayerContext::new(TendermintContext::default(), TendermintContext::default());
+
+    let client_id_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_on_a, conn_id_on_b) =
+        relayer.create_connection_on_a(client_id_on_a, client_id_on_b.clone(), signer.clone());
+
+    let (chan_id_on_a, _) = relayer.create_channel_on_a(
+        conn_id_on_a,
+        PortId::transfer(),
+        conn_id_on_b,
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    let mut packet = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a, signer.clone());
+
+    relayer.update_client_on_b_with_sync(client_id_on_b, signer.clone());
+
+    let ctx_a = relayer.get_ctx_a();
+    let proof_height_on_a = ctx_a.latest_height();
+    let proof_commitment_on_a = ctx_a
+        .ibc_store()
+        .get_proof(
+            proof_height_on_a,
+            &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
+                .into(),
+        )
+        .expect("commitment proof exists")
+        .try_into()
+        .expect("value merkle proof");
+
+    packet.data = b"tampered".to_vec();
+    let seq_on_a = packet.seq_on_a;
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::Recv(MsgRecvPacket {
+        packet,
+        proof_commitment_on_a,
+        proof_height_on_a,
+        signer,
+    }));
+
+    let ctx_b = relayer.get_ctx_b();
+    let res = validate(ctx_b.ibc_store(), &ctx_b.ibc_router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::Channel(
+                ChannelError::PacketCommitmentVerificationFailed { sequence, .. }
+            ))) if sequence == seq_on_a
+        ),
+        "{res:?}"
+    );
+}
+
+/// Tests that packets queued on an ordered channel are relayed by sequence,
+/// regardless of the order in which they were queued.
+///
+/// Requires `serde` feature because of
+/// [`RelayerContext::send_dummy_transfer_packet_on_a`](ibc_testkit::relayer::context::RelayerContext::send_dummy_transfer_packet_on_a).
+#[cfg(feature = "serde")]
+#[rstest]
+fn relay_packets_by_sequence_on_ordered_channel() {
+    use ibc_testkit::context::TendermintContext;
+    use ibc_testkit::relayer::context::RelayerContext;
+
+    let signer = dummy_account_id();
+
+    let mut relayer =
+        RelayerContext::new(TendermintContext::default(), TendermintContext::default());
+
+    let client_id_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_on_a, conn_id_on_b) =
+        relayer.create_connection_on_a(client_id_on_a, client_id_on_b, signer.clone());
+
+    let (chan_id_on_a, _) = relayer.create_channel_on_a_with_ordering(
+        conn_id_on_a,
+        PortId::transfer(),
+        conn_id_on_b,
+        PortId::transfer(),
+        Order::Ordered,
+        signer.clone(),
+    );
+
+    let packet_1 = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a.clone(), signer.clone());
+    let packet_2 = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a, signer.clone());
+
+    relayer.queue_packet(packet_2);
+    relayer.queue_packet(packet_1);
+
+    let relayed = relayer.relay_packets(signer);
+
+    assert_eq!(
+        relayed
+            .iter()
+            .map(|packet| packet.seq_on_a)
+            .collect::<Vec<_>>(),
+        [Sequence::from(1), Sequence::from(2)]
+    );
+
+    let Some(IbcEvent::AcknowledgePacket(_)) = relayer
+        .get_ctx_a()
+        .ibc_store()
+        .events
+        .lock()
+        .last()
+        .cloned()
+    else {
+        panic!("unexpected event")
+    };
 }
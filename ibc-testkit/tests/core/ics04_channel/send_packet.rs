@@ -3,6 +3,7 @@ use core::time::Duration;
 
 use ibc::core::channel::handler::send_packet;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
@@ -14,6 +15,7 @@ use ibc::core::connection::types::{
 };
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
@@ -206,3 +208,66 @@ fn send_packet_processing() {
         }
     }
 }
+
+#[test]
+fn send_packet_with_timeout_after_client_height() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 5).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let latest_height = ctx.ibc_store.client_latest_height(&client_id).unwrap();
+    assert_eq!(latest_height, client_height);
+
+    let packet: Packet = dummy_raw_packet(1, 0).try_into().unwrap();
+
+    let res = packet.clone().with_timeout_after(latest_height, 0);
+    assert!(
+        matches!(res, Err(PacketError::ZeroTimeoutHeightDelta)),
+        "{res:?}"
+    );
+
+    let res = packet.clone().with_timeout_after(latest_height, u64::MAX);
+    assert!(
+        matches!(res, Err(PacketError::TimeoutHeightOverflow { .. })),
+        "{res:?}"
+    );
+
+    let packet = packet.with_timeout_after(latest_height, 10).unwrap();
+    assert_eq!(
+        packet.timeout_height_on_b,
+        TimeoutHeight::At(Height::new(0, 15).unwrap())
+    );
+
+    let res = send_packet(&mut ctx.ibc_store, packet);
+    assert!(res.is_ok(), "{res:?}");
+}
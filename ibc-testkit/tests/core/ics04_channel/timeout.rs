@@ -1,6 +1,14 @@
+use ibc::apps::fee::handler::pay_packet_fee;
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::events::DistributeFeeEvent;
+use ibc::apps::fee::types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc::apps::fee::types::Fee;
+use ibc::apps::transfer::types::{PrefixedCoin, MODULE_ID_STR};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
@@ -10,14 +18,19 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::path::{ClientConsensusStatePath, CommitmentPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
+use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::fee::types::DummyFeeModule;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -229,33 +242,192 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
         )
 }
 
-/// NO-OP case
+/// A packet times out once either its timeout height or its timeout
+/// timestamp is reached on the counterparty at the proof height, and not
+/// before.
 #[rstest]
-fn timeout_success_no_packet_commitment(fixture: Fixture) {
+#[case::height_only(true, false)]
+#[case::timestamp_only(false, true)]
+#[case::height_and_timestamp(true, true)]
+#[case::premature(false, false)]
+fn timeout_unordered_chan_validate_timeout_conditions(
+    fixture: Fixture,
+    #[case] height_reached: bool,
+    #[case] timestamp_reached: bool,
+) {
     let Fixture {
         ctx,
         router,
-        msg,
+        mut msg,
+        chan_end_on_a_unordered,
         conn_end_on_a,
+        ..
+    } = fixture;
+
+    msg.packet.timeout_height_on_b = if height_reached {
+        TimeoutHeight::At(msg.proof_height_on_b)
+    } else {
+        TimeoutHeight::At(msg.proof_height_on_b.add(3))
+    };
+
+    // the fixture's timeout timestamp is already reached
+    if !timestamp_reached {
+        msg.packet.timeout_timestamp_on_b =
+            (msg.packet.timeout_timestamp_on_b + core::time::Duration::new(10, 0)).unwrap();
+    }
+
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+
+    let packet = msg.packet.clone();
+
+    let ctx = ctx
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    if height_reached || timestamp_reached {
+        assert!(res.is_ok(), "{res:?}");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::PacketError(
+                    PacketError::PacketTimeoutNotReached { .. }
+                ))
+            ),
+            "{res:?}"
+        );
+    }
+}
+
+/// A packet whose timeout timestamp is the largest representable one is
+/// never considered timed out by timestamp.
+#[rstest]
+fn timeout_fail_max_timeout_timestamp(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
         chan_end_on_a_unordered,
+        conn_end_on_a,
         ..
     } = fixture;
+
+    msg.packet.timeout_height_on_b = TimeoutHeight::At(msg.proof_height_on_b.add(3));
+    msg.packet.timeout_timestamp_on_b = Timestamp::from_nanoseconds(u64::MAX).unwrap();
+
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+
+    let packet = msg.packet.clone();
+
     let ctx = ctx
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
         .with_channel(
             PortId::transfer(),
             ChannelId::zero(),
             chan_end_on_a_unordered,
         )
-        .with_connection(ConnectionId::zero(), conn_end_on_a);
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
 
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = validate(&ctx.ibc_store, &router, msg_envelope);
 
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketTimeoutNotReached { .. }
+            ))
+        ),
+        "{res:?}"
+    );
+}
+
+/// NO-OP case: timing out an already acknowledged packet, whose commitment
+/// was deleted, succeeds without changing any state.
+#[rstest]
+fn timeout_success_acknowledged_packet(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let commitment_path = CommitmentPath::new(
+        &msg.packet.port_id_on_a,
+        &msg.packet.chan_id_on_a,
+        msg.packet.seq_on_a,
+    );
+
+    // Acknowledging the packet deletes its commitment.
+    ctx.ibc_store
+        .delete_packet_commitment(&commitment_path)
+        .expect("no error");
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
     assert!(
         res.is_ok(),
         "Validation should succeed when no packet commitment is present"
-    )
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Execution should succeed when no packet commitment is present"
+    );
+    assert!(ctx.get_events().is_empty());
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_err());
 }
 
 #[rstest]
@@ -418,3 +590,227 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+/// Timing out an incentivized packet through a fee-enabled transfer route
+/// pays the timeout fee to the relayer and refunds the receive and
+/// acknowledgement fees to the payer.
+#[rstest]
+fn timeout_unordered_chan_execute_distributes_fee(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), msg.packet.seq_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let payer = Signer::from("payer".to_string());
+    let coin = |coin: &str| -> PrefixedCoin { coin.parse().expect("valid prefixed coin") };
+
+    let mut fee_ctx = DummyFeeModule::new().with_fee_enabled(PortId::transfer(), ChannelId::zero());
+    pay_packet_fee(
+        &mut ctx.ibc_store,
+        &mut fee_ctx,
+        MsgPayPacketFee {
+            fee: Fee {
+                recv_fee: vec![coin("10uatom")],
+                ack_fee: vec![coin("20uatom")],
+                timeout_fee: vec![coin("30uatom")],
+            },
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            signer: payer.clone(),
+        },
+    )
+    .expect("no error");
+
+    let mut router = MockRouter::default();
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(
+            module_id,
+            FeeMiddleware::new(fee_ctx, DummyTransferModule::new()),
+        )
+        .expect("no error");
+
+    let relayer = msg.signer.clone();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "{res:?}");
+
+    let distribute_fee_events: Vec<_> = ctx
+        .get_events()
+        .into_iter()
+        .filter(|event| matches!(event, IbcEvent::Module(event) if event.kind == "distribute_fee"))
+        .collect();
+
+    assert_eq!(
+        distribute_fee_events,
+        vec![
+            IbcEvent::Module(
+                DistributeFeeEvent {
+                    receiver: relayer,
+                    fee: vec![coin("30uatom")],
+                }
+                .into()
+            ),
+            IbcEvent::Module(
+                DistributeFeeEvent {
+                    receiver: payer,
+                    fee: vec![coin("10uatom"), coin("20uatom")],
+                }
+                .into()
+            ),
+        ]
+    );
+}
+
+/// Sends a packet from `A` to `B` on an unordered channel between two
+/// Tendermint hosts, optionally receives it on `B`, and waits for it to time
+/// out. Returns the result of validating, on `A`, the timeout of the packet
+/// with a proof of its receipt path on `B`.
+///
+/// Requires `serde` feature because of
+/// [`RelayerContext::send_dummy_transfer_packet_on_a`](ibc_testkit::relayer::context::RelayerContext::send_dummy_transfer_packet_on_a).
+#[cfg(feature = "serde")]
+fn validate_timeout_with_receipt_proof(recv_on_b: bool) -> Result<(), ContextError> {
+    use ibc::core::channel::types::msgs::MsgRecvPacket;
+    use ibc::core::host::types::path::ReceiptPath;
+    use ibc_query::core::context::ProvableContext;
+    use ibc_testkit::context::TendermintContext;
+    use ibc_testkit::fixtures::core::signer::dummy_account_id;
+    use ibc_testkit::relayer::context::RelayerContext;
+
+    let signer = dummy_account_id();
+
+    let mut relayer =
+        RelayerContext::new(TendermintContext::default(), TendermintContext::default());
+
+    let client_id_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_on_a, conn_id_on_b) = relayer.create_connection_on_a(
+        client_id_on_a.clone(),
+        client_id_on_b.clone(),
+        signer.clone(),
+    );
+
+    let (chan_id_on_a, _) = relayer.create_channel_on_a(
+        conn_id_on_a,
+        PortId::transfer(),
+        conn_id_on_b,
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    let packet = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a, signer.clone());
+
+    if recv_on_b {
+        relayer.update_client_on_b_with_sync(client_id_on_b, signer.clone());
+
+        let ctx_a = relayer.get_ctx_a();
+        let proof_height_on_a = ctx_a.latest_height();
+        let proof_commitment_on_a = ctx_a
+            .ibc_store()
+            .get_proof(
+                proof_height_on_a,
+                &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
+                    .into(),
+            )
+            .expect("commitment proof exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        relayer
+            .get_ctx_b_mut()
+            .deliver(MsgEnvelope::from(PacketMsg::Recv(MsgRecvPacket {
+                packet: packet.clone(),
+                proof_commitment_on_a,
+                proof_height_on_a,
+                signer: signer.clone(),
+            })))
+            .expect("packet is received");
+    }
+
+    let TimeoutHeight::At(timeout_height) = packet.timeout_height_on_b else {
+        panic!("timeout height is set")
+    };
+
+    while relayer.get_ctx_b().latest_height() <= timeout_height {
+        relayer.get_ctx_b_mut().advance_block_height();
+    }
+
+    relayer.update_client_on_a_with_sync(client_id_on_a, signer.clone());
+
+    let ctx_b = relayer.get_ctx_b();
+    let proof_height_on_b = ctx_b.latest_height();
+    let proof_unreceived_on_b = ctx_b
+        .ibc_store()
+        .get_proof(
+            proof_height_on_b,
+            &ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a).into(),
+        )
+        .expect("receipt proof exists")
+        .try_into()
+        .expect("value merkle proof");
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::Timeout(MsgTimeout {
+        next_seq_recv_on_b: packet.seq_on_a,
+        packet,
+        proof_unreceived_on_b,
+        proof_height_on_b,
+        signer,
+    }));
+
+    let ctx_a = relayer.get_ctx_a();
+    validate(ctx_a.ibc_store(), &ctx_a.ibc_router, msg_envelope)
+}
+
+/// Tests that a timeout is accepted with a proof of the absence of the
+/// packet receipt on the counterparty.
+#[cfg(feature = "serde")]
+#[rstest]
+fn timeout_unordered_chan_validate_absence_proof() {
+    let res = validate_timeout_with_receipt_proof(false);
+
+    assert!(res.is_ok(), "{res:?}");
+}
+
+/// Tests that a timeout is rejected when the counterparty received the
+/// packet, as the receipt path then holds a value and its proof shows
+/// presence rather than absence.
+#[cfg(feature = "serde")]
+#[rstest]
+fn timeout_fail_packet_received_on_counterparty() {
+    use ibc::core::channel::types::error::ChannelError;
+
+    let res = validate_timeout_with_receipt_proof(true);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::Channel(
+                ChannelError::PacketVerificationFailed { .. }
+            )))
+        ),
+        "{res:?}"
+    );
+}
@@ -0,0 +1,273 @@
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ConnectionPath, Path};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use test_log::test;
+
+#[test]
+fn test_get_raw_value() {
+    let msg_conn_init = dummy_msg_conn_open_init();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Init,
+        msg_conn_init.client_id_on_a,
+        msg_conn_init.counterparty,
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default().with_connection(ConnectionId::zero(), conn_end.clone());
+
+    // membership: the stored bytes equal the claimed value
+    let stored_path = Path::Connection(ConnectionPath::new(&ConnectionId::zero()));
+    assert_eq!(
+        ctx.ibc_store.get_raw_value(&stored_path),
+        Some(conn_end.encode_vec())
+    );
+
+    // non-membership: nothing is stored at the path
+    let absent_path = Path::Connection(ConnectionPath::new(&ConnectionId::new(1)));
+    assert_eq!(ctx.ibc_store.get_raw_value(&absent_path), None);
+}
+
+#[test]
+fn test_validate_connection_hops() {
+    let open_conn_end = |counterparty_conn_id: u64| {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+        ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                client_id,
+                Some(ConnectionId::new(counterparty_conn_id)),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).expect("no error"),
+            ),
+            ConnectionVersion::compatibles(),
+            ZERO_DURATION,
+        )
+        .unwrap()
+    };
+
+    // connection-1 does not lead to connection-2.
+    let ctx = MockContext::default()
+        .with_connection(ConnectionId::new(0), open_conn_end(1))
+        .with_connection(ConnectionId::new(1), open_conn_end(5))
+        .with_connection(ConnectionId::new(2), open_conn_end(3));
+
+    let valid_hops = [ConnectionId::new(0), ConnectionId::new(1)];
+    let res = ctx.ibc_store.validate_connection_hops(&valid_hops);
+    assert!(res.is_ok(), "two-hop path must be valid. err: {res:?}");
+
+    let broken_hops = [
+        ConnectionId::new(0),
+        ConnectionId::new(1),
+        ConnectionId::new(2),
+    ];
+    let res = ctx.ibc_store.validate_connection_hops(&broken_hops);
+    assert!(
+        matches!(
+            &res,
+            Err(ContextError::ConnectionError(ConnectionError::InconsistentHops {
+                connection_id,
+                next_hop,
+            })) if connection_id == &ConnectionId::new(1) && next_hop == &ConnectionId::new(2)
+        ),
+        "broken path must be rejected. err: {res:?}"
+    );
+
+    // The host's error for a missing hop is returned as is.
+    let missing_hops = [
+        ConnectionId::new(0),
+        ConnectionId::new(1),
+        ConnectionId::new(5),
+    ];
+    let res = ctx.ibc_store.validate_connection_hops(&missing_hops);
+    assert!(
+        matches!(
+            &res,
+            Err(ContextError::ConnectionError(ConnectionError::ConnectionNotFound {
+                connection_id,
+            })) if connection_id == &ConnectionId::new(5)
+        ),
+        "path with a missing hop must be rejected. err: {res:?}"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_store_writes_cover_full_packet_flow() {
+    use ibc::core::client::context::ExtClientValidationContext;
+    use ibc::core::connection::types::proto::v1::ClientPaths;
+    use ibc::core::host::types::identifiers::{ChannelId, PortId};
+    use ibc::core::host::types::path::{
+        AckPath, AckedSequencePath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath,
+        ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath,
+        NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
+        SeqAckPath, SeqRecvPath, SeqSendPath,
+    };
+    use ibc::primitives::ToVec;
+    use ibc_testkit::fixtures::core::signer::dummy_account_id;
+    use ibc_testkit::relayer::context::RelayerContext;
+    use ibc_testkit::testapp::ibc::core::types::DefaultIbcStore;
+
+    /// Paths written by the client, connection and channel handshakes on
+    /// either side.
+    fn handshake_paths(
+        ibc_store: &DefaultIbcStore,
+        client_id: &ClientId,
+        conn_id: &ConnectionId,
+        port_id: &PortId,
+        chan_id: &ChannelId,
+    ) -> Vec<Path> {
+        let mut paths: Vec<Path> = vec![
+            NextClientSequencePath.into(),
+            ClientStatePath::new(client_id.clone()).into(),
+            NextConnectionSequencePath.into(),
+            ClientConnectionPath::new(client_id.clone()).into(),
+            ConnectionPath::new(conn_id).into(),
+            NextChannelSequencePath.into(),
+            ChannelEndPath::new(port_id, chan_id).into(),
+            SeqSendPath::new(port_id, chan_id).into(),
+            SeqRecvPath::new(port_id, chan_id).into(),
+            SeqAckPath::new(port_id, chan_id).into(),
+        ];
+
+        for height in ibc_store.consensus_state_heights(client_id).unwrap() {
+            let (revision_number, revision_height) =
+                (height.revision_number(), height.revision_height());
+            paths.push(
+                ClientConsensusStatePath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            );
+            paths.push(
+                ClientUpdateTimePath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            );
+            paths.push(
+                ClientUpdateHeightPath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            );
+        }
+
+        paths
+    }
+
+    fn written_paths(ibc_store: &DefaultIbcStore) -> Vec<Path> {
+        ibc_store
+            .store_writes
+            .lock()
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    let signer = dummy_account_id();
+    let port_id = PortId::transfer();
+
+    let mut relayer = RelayerContext::new(MockContext::default(), MockContext::default());
+
+    let client_id_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_on_a, conn_id_on_b) = relayer.create_connection_on_a(
+        client_id_on_a.clone(),
+        client_id_on_b.clone(),
+        signer.clone(),
+    );
+
+    let (chan_id_on_a, chan_id_on_b) = relayer.create_channel_on_a(
+        conn_id_on_a.clone(),
+        port_id.clone(),
+        conn_id_on_b.clone(),
+        port_id.clone(),
+        signer.clone(),
+    );
+
+    let packet = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a.clone(), signer.clone());
+    let seq = packet.seq_on_a;
+
+    // receives the packet on B and acknowledges it on A
+    relayer.submit_packet_on_b(packet, signer);
+
+    let ibc_store_a = relayer.get_ctx_a().ibc_store();
+    let ibc_store_b = relayer.get_ctx_b().ibc_store();
+
+    let commitment_path_on_a = CommitmentPath::new(&port_id, &chan_id_on_a, seq);
+
+    let mut expected_paths_on_a = handshake_paths(
+        ibc_store_a,
+        &client_id_on_a,
+        &conn_id_on_a,
+        &port_id,
+        &chan_id_on_a,
+    );
+    expected_paths_on_a.push(commitment_path_on_a.clone().into());
+    expected_paths_on_a.push(AckedSequencePath::new(&port_id, &chan_id_on_a, seq).into());
+
+    let mut expected_paths_on_b = handshake_paths(
+        ibc_store_b,
+        &client_id_on_b,
+        &conn_id_on_b,
+        &port_id,
+        &chan_id_on_b,
+    );
+    expected_paths_on_b.push(ReceiptPath::new(&port_id, &chan_id_on_b, seq).into());
+    expected_paths_on_b.push(AckPath::new(&port_id, &chan_id_on_b, seq).into());
+
+    let written_paths_on_a = written_paths(ibc_store_a);
+    for path in expected_paths_on_a {
+        assert!(
+            written_paths_on_a.contains(&path),
+            "{path} not observed on A"
+        );
+    }
+
+    let written_paths_on_b = written_paths(ibc_store_b);
+    for path in expected_paths_on_b {
+        assert!(
+            written_paths_on_b.contains(&path),
+            "{path} not observed on B"
+        );
+    }
+
+    // the acknowledgement deletes the packet commitment on A
+    assert!(ibc_store_a
+        .store_deletes
+        .lock()
+        .contains(&commitment_path_on_a.into()));
+
+    // the client connections are reported as ibc-go commits them
+    let client_connections_on_a = ibc_store_a
+        .store_writes
+        .lock()
+        .iter()
+        .rev()
+        .find(|(path, _)| *path == ClientConnectionPath::new(client_id_on_a.clone()).into())
+        .map(|(_, value)| value.clone())
+        .unwrap();
+    assert_eq!(
+        client_connections_on_a,
+        ClientPaths {
+            paths: vec![conn_id_on_a.to_string()],
+        }
+        .to_vec()
+    );
+
+    // connection ends are reported with their protobuf encoding
+    let conn_end_on_a = ibc_store_a.connection_end(&conn_id_on_a).unwrap();
+    assert!(ibc_store_a.store_writes.lock().contains(&(
+        ConnectionPath::new(&conn_id_on_a).into(),
+        conn_end_on_a.encode_vec()
+    )));
+}
@@ -17,11 +17,12 @@ use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
@@ -418,11 +419,13 @@ fn routing_module_and_keepers() {
     for test in tests {
         let res = match test.msg.clone() {
             TestMsg::Ics26(msg) => dispatch(&mut ctx.ibc_store, &mut router, msg),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule, msg)
-                .map_err(|e: TokenTransferError| ChannelError::AppModule {
-                    description: e.to_string(),
-                })
-                .map_err(ContextError::from),
+            TestMsg::Ics20(msg) => {
+                send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule::new(), msg)
+                    .map_err(|e: TokenTransferError| ChannelError::AppModule {
+                        description: e.to_string(),
+                    })
+                    .map_err(ContextError::from)
+            }
         };
 
         assert_eq!(
@@ -446,3 +449,30 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn port_ownership_lookup() {
+    let transfer_port = PortId::transfer();
+    let transfer_module = ModuleId::new("transfer".to_string());
+    let oracle_port = PortId::new("oracle".to_string()).unwrap();
+    let oracle_module = ModuleId::new("oracle".to_string());
+
+    let ctx = MockContext::default()
+        .with_port_owner(transfer_port.clone(), transfer_module.clone())
+        .with_port_owner(oracle_port.clone(), oracle_module.clone());
+
+    assert_eq!(
+        ctx.ibc_store.port_owner(&transfer_port),
+        Some(transfer_module)
+    );
+    assert_eq!(ctx.ibc_store.port_owner(&oracle_port), Some(oracle_module));
+    assert_eq!(
+        ctx.ibc_store
+            .port_owner(&PortId::new("unbound".to_string()).unwrap()),
+        None
+    );
+
+    let mut bound_ports = ctx.ibc_store.bound_ports();
+    bound_ports.sort();
+    assert_eq!(bound_ports, vec![oracle_port, transfer_port]);
+}